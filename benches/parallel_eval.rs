@@ -0,0 +1,32 @@
+//! Compares evaluating many terms serially with `Term::parallel_eval`.
+//!
+//! Run using `cargo bench --features rayon` to use multiple threads.
+
+use std::{hint::black_box, time::Instant};
+
+use crem::Term;
+
+fn main() {
+    let terms: Vec<Term<i32>> = (1..=1000)
+        .map(|i| {
+            let x = || Term::var("x");
+            (Term::from(i) * x() * x() + Term::from(i % 7) * x() + Term::from(3))
+                / (x() + Term::from(i))
+        })
+        .collect();
+
+    let start = Instant::now();
+    let serial: Vec<f64> = terms
+        .iter()
+        .map(|term| term.use_var("x", &Term::from(5)))
+        .collect();
+    let serial_time = start.elapsed();
+
+    let start = Instant::now();
+    let parallel: Vec<f64> = Term::parallel_eval(black_box(&terms), "x", 5);
+    let parallel_time = start.elapsed();
+
+    assert_eq!(serial, parallel);
+    println!("serial:   {serial_time:?}");
+    println!("parallel: {parallel_time:?}");
+}
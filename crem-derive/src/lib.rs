@@ -0,0 +1,103 @@
+//! Derive macros for crem. Enable the `derive` feature of crem to use them.
+
+#![forbid(unsafe_code)]
+#![warn(missing_docs)]
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields, LitStr};
+
+/// Converts a struct into the sum of its fields as a `Term`.
+///
+/// A field marked with `#[term(coefficient_of = "x")]` is multiplied with the variable `x`.
+/// All other fields are added as constants.
+/// The number type of the term is the type of the fields, which all have to share it.
+///
+/// ```rust
+/// use crem::{IntoTerm, Term};
+///
+/// #[derive(IntoTerm)]
+/// struct Line {
+///     #[term(coefficient_of = "x")]
+///     slope: f64,
+///     offset: f64,
+/// }
+///
+/// let term = Term::from(Line {
+///     slope: 2.0,
+///     offset: 1.0,
+/// });
+/// assert_eq!(term.use_var::<f64>("x", &Term::from(3.0)), 7.0);
+/// ```
+#[proc_macro_derive(IntoTerm, attributes(term))]
+pub fn derive_into_term(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    into_term(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Generates `impl From<Struct> for Term<Num>`.
+fn into_term(input: DeriveInput) -> Result<TokenStream2, Error> {
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "IntoTerm can only be derived for structs",
+        ));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "IntoTerm can only be derived for structs with named fields",
+        ));
+    };
+    let Some(first) = fields.named.first() else {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "IntoTerm cannot be derived for structs without fields",
+        ));
+    };
+    let num = &first.ty;
+
+    let mut summands = Vec::new();
+    for field in &fields.named {
+        let name = &field.ident;
+
+        let mut coefficient_of = None;
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("term"))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("coefficient_of") {
+                    coefficient_of = Some(meta.value()?.parse::<LitStr>()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("expected `coefficient_of`"))
+                }
+            })?;
+        }
+
+        summands.push(match coefficient_of {
+            Some(var) => quote! {
+                ::crem::Term::from(value.#name) * ::crem::Term::var(#var)
+            },
+            None => quote! {
+                ::crem::Term::from(value.#name)
+            },
+        });
+    }
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::core::convert::From<#ident #ty_generics> for ::crem::Term<#num> #where_clause {
+            fn from(value: #ident #ty_generics) -> Self {
+                ::crem::Term::sum([#(#summands),*])
+            }
+        }
+    })
+}
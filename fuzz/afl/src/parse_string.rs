@@ -0,0 +1,9 @@
+use crem::{Term, TryFromStrError};
+
+fn main() {
+    afl::fuzz!(|data: &[u8]| {
+        if let Ok(string) = std::str::from_utf8(data) {
+            let _: Result<Term<u32>, TryFromStrError> = Term::try_from(string);
+        }
+    });
+}
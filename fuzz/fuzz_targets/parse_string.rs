@@ -0,0 +1,10 @@
+#![no_main]
+
+use crem::{Term, TryFromStrError};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(string) = std::str::from_utf8(data) {
+        let _: Result<Term<u32>, TryFromStrError> = Term::try_from(string);
+    }
+});
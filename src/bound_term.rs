@@ -0,0 +1,78 @@
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+use crate::Term;
+
+/// A term with some of its variables already bound.
+///
+/// Created using [`Term::bind_lazy`]. Useful when evaluating the same term many times with a single variable changing.
+///
+/// ```rust
+/// # use crem::Term;
+/// let term = Term::var("a") * Term::var("x") + Term::var("b");
+/// let bound = term.bind_lazy(&[("a", Term::from(2)), ("b", Term::from(1))]);
+///
+/// assert_eq!(bound.eval_with::<f64>("x", 3), 7.0);
+/// assert_eq!(bound.eval_with::<f64>("x", 4), 9.0);
+/// ```
+#[derive(Debug, PartialEq, PartialOrd, Clone)]
+pub struct BoundTerm<
+    Num: Add<Output = Num>
+        + Sub<Output = Num>
+        + Mul<Output = Num>
+        + Div<Output = Num>
+        + Rem<Output = Num>
+        + Clone
+        + Default
+        + PartialOrd,
+> {
+    term: Term<Num>,
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > BoundTerm<Num>
+{
+    /// Replaces the remaining variable with the given value, and calculates the result.
+    pub fn eval_with<
+        Output: Add<Output = Output>
+            + Sub<Output = Output>
+            + Mul<Output = Output>
+            + Div<Output = Output>
+            + Neg<Output = Output>
+            + From<Num>,
+    >(
+        &self,
+        var: &str,
+        value: Num,
+    ) -> Output {
+        self.term.use_var(var, &Term::from(value))
+    }
+
+    /// Returns the term with the fixed variables already replaced.
+    pub fn term(&self) -> &Term<Num> {
+        &self.term
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > From<Term<Num>> for BoundTerm<Num>
+{
+    fn from(term: Term<Num>) -> Self {
+        BoundTerm { term }
+    }
+}
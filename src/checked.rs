@@ -0,0 +1,122 @@
+use std::{
+    cmp::Ordering,
+    ops::{Add, Div, Mul, Rem, Sub},
+};
+
+use num_traits::{CheckedAdd, CheckedMul, CheckedSub};
+
+/// A number which remembers whether an overflow occurred while calculating it.
+///
+/// Used to detect overflows during the simplification of terms.
+#[derive(Debug, Clone, Default)]
+pub struct Checked<Num> {
+    pub value: Num,
+    pub overflowed: bool,
+}
+
+impl<Num: Default + PartialEq> Checked<Num> {
+    /// Marks the result of an overflowing operation.
+    ///
+    /// Keeps one of the non-zero operands as value, so that following operations don't divide by zero.
+    fn overflow(first: Num, second: Num) -> Self {
+        Checked {
+            value: if first == Num::default() {
+                second
+            } else {
+                first
+            },
+            overflowed: true,
+        }
+    }
+}
+
+impl<Num> From<Num> for Checked<Num> {
+    fn from(value: Num) -> Self {
+        Checked {
+            value,
+            overflowed: false,
+        }
+    }
+}
+
+impl<Num: PartialEq> PartialEq for Checked<Num> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<Num: PartialOrd> PartialOrd for Checked<Num> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl<Num: CheckedAdd + Default + PartialEq> Add for Checked<Num> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        match self.value.checked_add(&rhs.value) {
+            Some(value) => Checked {
+                value,
+                overflowed: self.overflowed || rhs.overflowed,
+            },
+            None => Checked::overflow(self.value, rhs.value),
+        }
+    }
+}
+
+impl<Num: CheckedSub + Default + PartialEq> Sub for Checked<Num> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        match self.value.checked_sub(&rhs.value) {
+            Some(value) => Checked {
+                value,
+                overflowed: self.overflowed || rhs.overflowed,
+            },
+            None => Checked::overflow(self.value, rhs.value),
+        }
+    }
+}
+
+impl<Num: CheckedMul + Default + PartialEq> Mul for Checked<Num> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        match self.value.checked_mul(&rhs.value) {
+            Some(value) => Checked {
+                value,
+                overflowed: self.overflowed || rhs.overflowed,
+            },
+            None => Checked::overflow(self.value, rhs.value),
+        }
+    }
+}
+
+impl<Num: Div<Output = Num>> Div for Checked<Num> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        Checked {
+            value: self.value / rhs.value,
+            overflowed: self.overflowed || rhs.overflowed,
+        }
+    }
+}
+
+impl<Num: Rem<Output = Num>> Rem for Checked<Num> {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        Checked {
+            value: self.value % rhs.value,
+            overflowed: self.overflowed || rhs.overflowed,
+        }
+    }
+}
+
+impl From<u8> for Checked<u32> {
+    fn from(value: u8) -> Self {
+        Checked::from(u32::from(value))
+    }
+}
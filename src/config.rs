@@ -0,0 +1,62 @@
+use std::cell::Cell;
+
+thread_local! {
+    static CONFIG: Cell<TermConfig> = Cell::new(TermConfig::default());
+    static DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Configuration of how terms are processed on the current thread.
+///
+/// Obtained using [`Term::simplification_depth_limit`](crate::Term::simplification_depth_limit)
+/// and applied using [`Term::with_config`](crate::Term::with_config).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct TermConfig {
+    /// The maximum number of nested simplification steps, or `None` if unlimited.
+    pub simplification_depth_limit: Option<usize>,
+}
+
+impl TermConfig {
+    /// Applies the configuration to the current thread until the returned guard is dropped.
+    pub(crate) fn apply(self) -> ConfigGuard {
+        ConfigGuard {
+            previous: CONFIG.with(|config| config.replace(self)),
+        }
+    }
+}
+
+/// Keeps a [`TermConfig`] applied to the current thread. The previous configuration is restored when dropped.
+#[must_use = "the configuration is reverted as soon as the guard is dropped"]
+#[derive(Debug)]
+pub struct ConfigGuard {
+    previous: TermConfig,
+}
+
+impl Drop for ConfigGuard {
+    fn drop(&mut self) {
+        CONFIG.with(|config| config.set(self.previous));
+    }
+}
+
+/// A single level of nested simplification. The level is left when dropped.
+pub struct SimplificationStep;
+
+impl SimplificationStep {
+    /// Enters another level of simplification, or returns `None` if the configured limit is reached.
+    pub fn enter() -> Option<Self> {
+        let limit = CONFIG.with(|config| config.get().simplification_depth_limit);
+        DEPTH.with(|depth| {
+            if limit.is_some_and(|limit| depth.get() >= limit) {
+                None
+            } else {
+                depth.set(depth.get() + 1);
+                Some(SimplificationStep)
+            }
+        })
+    }
+}
+
+impl Drop for SimplificationStep {
+    fn drop(&mut self) {
+        DEPTH.with(|depth| depth.set(depth.get() - 1));
+    }
+}
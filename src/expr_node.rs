@@ -0,0 +1,72 @@
+use crate::{operation::Operation, Term};
+
+/// A simple expression tree, used to exchange terms with other code.
+///
+/// Converted into a term using [`Term::from_expression_tree`], and back using [`Term::into_expression_tree`].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ExprNode {
+    /// A constant number.
+    Num(u32),
+    /// A named variable.
+    Var(String),
+    /// The sum of two nodes.
+    Add(Box<ExprNode>, Box<ExprNode>),
+    /// The difference of two nodes.
+    Sub(Box<ExprNode>, Box<ExprNode>),
+    /// The product of two nodes.
+    Mul(Box<ExprNode>, Box<ExprNode>),
+    /// The quotient of two nodes.
+    Div(Box<ExprNode>, Box<ExprNode>),
+    /// A negated node.
+    Neg(Box<ExprNode>),
+}
+
+/// Builds a term from an expression tree. Used in `Term::from_expression_tree`.
+///
+/// The term is built using the operators of `Term`, so it is simplified.
+pub fn term_from_expr_node(node: ExprNode) -> Term<u32> {
+    match node {
+        ExprNode::Num(value) => Term::from(value),
+        ExprNode::Var(name) => Term::var(name),
+        ExprNode::Add(lhs, rhs) => term_from_expr_node(*lhs) + term_from_expr_node(*rhs),
+        ExprNode::Sub(lhs, rhs) => term_from_expr_node(*lhs) - term_from_expr_node(*rhs),
+        ExprNode::Mul(lhs, rhs) => term_from_expr_node(*lhs) * term_from_expr_node(*rhs),
+        ExprNode::Div(lhs, rhs) => term_from_expr_node(*lhs) / term_from_expr_node(*rhs),
+        ExprNode::Neg(value) => -term_from_expr_node(*value),
+    }
+}
+
+/// Converts an operation into an expression tree. Used in `Term::into_expression_tree`.
+///
+/// Sums and products are written as nested binary nodes, from left to right.
+/// Returns `None` if the operation contains powers, floor divisions, modular exponentiations or bitwise operations.
+pub fn expr_node_from_operation(operation: Operation<u32>) -> Option<ExprNode> {
+    Some(match operation {
+        Operation::Number(num) => ExprNode::Num(num.value),
+        Operation::Variable(var) => ExprNode::Var(var.name),
+        Operation::Addition(add) => binary_chain(add.summands, ExprNode::Add)?,
+        Operation::Multiplication(mul) => binary_chain(mul.multipliers, ExprNode::Mul)?,
+        Operation::Division(div) => ExprNode::Div(
+            Box::new(expr_node_from_operation(*div.divident)?),
+            Box::new(expr_node_from_operation(*div.divisor)?),
+        ),
+        Operation::Negation(neg) => ExprNode::Neg(Box::new(expr_node_from_operation(*neg.value)?)),
+        _ => return None,
+    })
+}
+
+/// Combines the operands into nested binary nodes, from left to right.
+fn binary_chain(
+    operands: Vec<Operation<u32>>,
+    node: fn(Box<ExprNode>, Box<ExprNode>) -> ExprNode,
+) -> Option<ExprNode> {
+    let mut operands = operands
+        .into_iter()
+        .map(expr_node_from_operation)
+        .collect::<Option<Vec<ExprNode>>>()?
+        .into_iter();
+    let first = operands
+        .next()
+        .expect("Sums and products always have operands.");
+    Some(operands.fold(first, |acc, operand| node(Box::new(acc), Box::new(operand))))
+}
@@ -0,0 +1,28 @@
+use std::hash::Hasher;
+
+const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// A 64 bit FNV-1a hasher. Unlike the standard library's default hasher, its output is deterministic.
+pub struct FnvHasher {
+    hash: u64,
+}
+
+impl Default for FnvHasher {
+    fn default() -> Self {
+        FnvHasher { hash: OFFSET_BASIS }
+    }
+}
+
+impl Hasher for FnvHasher {
+    fn finish(&self) -> u64 {
+        self.hash
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.hash ^= u64::from(*byte);
+            self.hash = self.hash.wrapping_mul(PRIME);
+        }
+    }
+}
@@ -46,9 +46,32 @@
 #![forbid(unsafe_code)]
 #![warn(missing_docs)]
 
+mod bound_term;
+mod checked;
+mod config;
+mod expr_node;
+mod fnv;
 mod operation;
+mod parse_prefix_notation;
 mod parse_string;
+mod precision;
+mod serialize_bytes;
 mod term;
+mod term_diff;
+#[cfg(feature = "test-utils")]
+pub mod test_utils;
 
+pub use bound_term::BoundTerm;
+pub use config::{ConfigGuard, TermConfig};
+#[cfg(feature = "derive")]
+pub use crem_derive::IntoTerm;
+pub use expr_node::ExprNode;
+pub use operation::Operation;
 pub use parse_string::TryFromStrError;
-pub use term::Term;
+pub use precision::{Exact, Float32, Float64, Precision, Rational};
+pub use serialize_bytes::SerdeError;
+pub use term::{
+    LeafValue, NotAConstantError, NumberOutOfRangeError, OperationCounts, OperationVariant, Term,
+    UnboundVariableError,
+};
+pub use term_diff::{TermChange, TermDiff};
@@ -1,22 +1,45 @@
-use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+use std::{
+    fmt::Display,
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+};
+
+use num_traits::Float;
+
+use crate::config::SimplificationStep;
 
 pub mod traits;
 
-mod addition;
+pub mod addition;
+pub mod bitwise;
 mod division;
-mod multiplication;
+pub mod floor_division;
+pub mod mul_add;
+pub mod multiplication;
 mod negation;
-mod number;
+pub mod number;
+pub mod pow_mod;
+pub mod rational_power;
 pub mod variable;
 
 use addition::Addition;
+use bitwise::BitOperation;
 use division::Division;
+use floor_division::FloorDivision;
+use mul_add::MulAdd;
 use multiplication::Multiplication;
 use negation::Negation;
 use number::Number;
-use traits::{Calc, CanAddNumWell, Convert, SetVars};
+use pow_mod::PowMod;
+use rational_power::RationalPower;
+use traits::{
+    ApplyRule, Calc, CalcBatch, CalcFloat, CanAddNumWell, Convert, Diff, SetVars,
+    ToPolynomialCoefficients, ToPrefixNotation, Unify, Walk, WalkMut,
+};
 use variable::Variable;
 
+/// A node of a term.
+///
+/// Obtained by walking a term, e.g. using [`Term::walk_mut`](crate::Term::walk_mut).
 #[derive(Debug, PartialEq, PartialOrd, Clone)]
 pub enum Operation<
     Num: Add<Output = Num>
@@ -28,12 +51,189 @@ pub enum Operation<
         + Default
         + PartialOrd,
 > {
+    /// A sum of any number of summands.
     Addition(Addition<Num>),
+    /// A product of any number of multipliers.
     Multiplication(Multiplication<Num>),
+    /// A divident divided by a divisor.
     Division(Division<Num>),
+    /// A negated value.
     Negation(Negation<Num>),
+    /// A constant number.
     Number(Number<Num>),
+    /// A named variable.
     Variable(Variable<Num>),
+    /// A base raised to a rational exponent.
+    RationalPower(RationalPower<Num>),
+    /// A divident divided by a divisor, rounded towards negative infinity.
+    FloorDivision(FloorDivision<Num>),
+    /// A product of two factors plus a summand, calculated as a fused multiply-add.
+    MulAdd(MulAdd<Num>),
+    /// A base raised to an integer exponent, modulo a modulus.
+    PowMod(PowMod<Num>),
+    /// The bitwise and of two integers.
+    BitAnd(BitOperation<Num>),
+    /// The bitwise or of two integers.
+    BitOr(BitOperation<Num>),
+    /// The bitwise exclusive or of two integers.
+    BitXor(BitOperation<Num>),
+    /// An integer shifted to the left.
+    Shl(BitOperation<Num>),
+    /// An integer shifted to the right.
+    Shr(BitOperation<Num>),
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > Operation<Num>
+{
+    /// Returns the number of levels of the operation, a number or variable having a depth of one.
+    pub fn depth(&self) -> usize {
+        // the nodes are visited in post-order, so the depths of the children are always on top of the stack
+        let mut depths: Vec<usize> = Vec::new();
+        self.walk(&mut |op| {
+            let children = match op {
+                Operation::Addition(add) => add.summands.len(),
+                Operation::Multiplication(mul) => mul.multipliers.len(),
+                Operation::Division(_)
+                | Operation::FloorDivision(_)
+                | Operation::BitAnd(_)
+                | Operation::BitOr(_)
+                | Operation::BitXor(_)
+                | Operation::Shl(_)
+                | Operation::Shr(_) => 2,
+                Operation::Negation(_) | Operation::RationalPower(_) => 1,
+                Operation::Number(_) | Operation::Variable(_) => 0,
+                Operation::MulAdd(_) | Operation::PowMod(_) => 3,
+            };
+            let deepest = depths
+                .drain(depths.len() - children..)
+                .max()
+                .unwrap_or_default();
+            depths.push(deepest + 1);
+        });
+        depths.pop().unwrap_or_default()
+    }
+
+    /// Returns a heuristic of how complex the operation is. Simpler forms of the same term have a lower score.
+    ///
+    /// Numbers score one and variables two. Every other operation scores the sum of its children plus the number of its children.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let mut term = Term::from(2u32) * Term::var("x") + Term::from(1);
+    /// let mut scores = Vec::new();
+    /// term.walk_mut(|op| scores.push(op.complexity_score()));
+    ///
+    /// // 2, x, 2 * x, 1, 2 * x + 1
+    /// assert_eq!(scores, vec![1, 2, 5, 1, 8]);
+    /// ```
+    pub fn complexity_score(&self) -> u64 {
+        // the nodes are visited in post-order, so the scores of the children are always on top of the stack
+        let mut scores: Vec<u64> = Vec::new();
+        self.walk(&mut |op| {
+            let (children, own) = match op {
+                Operation::Number(_) => (0, 1),
+                Operation::Variable(_) => (0, 2),
+                Operation::Addition(add) => (add.summands.len(), add.summands.len() as u64),
+                Operation::Multiplication(mul) => {
+                    (mul.multipliers.len(), mul.multipliers.len() as u64)
+                }
+                Operation::Division(_)
+                | Operation::FloorDivision(_)
+                | Operation::BitAnd(_)
+                | Operation::BitOr(_)
+                | Operation::BitXor(_)
+                | Operation::Shl(_)
+                | Operation::Shr(_) => (2, 2),
+                Operation::Negation(_) | Operation::RationalPower(_) => (1, 1),
+                Operation::MulAdd(_) | Operation::PowMod(_) => (3, 3),
+            };
+            let sum: u64 = scores.drain(scores.len() - children..).sum();
+            scores.push(sum + own);
+        });
+        scores.pop().unwrap_or_default()
+    }
+
+    /// Checks whether the operation has a lower [`complexity_score`](Operation::complexity_score) than the other one.
+    pub fn is_simpler_than(&self, other: &Operation<Num>) -> bool {
+        self.complexity_score() < other.complexity_score()
+    }
+
+    /// Returns how tightly the operation binds when written in infix notation.
+    /// Operations with a higher precedence are evaluated first.
+    ///
+    /// Bitwise operations bind the loosest, followed by additions, multiplications and divisions, powers, negations
+    /// and finally numbers, variables and modular exponentiations, which are written like function calls.
+    /// Negative numbers bind like negations.
+    pub fn precedence(&self) -> u8 {
+        match self {
+            Operation::BitAnd(_)
+            | Operation::BitOr(_)
+            | Operation::BitXor(_)
+            | Operation::Shl(_)
+            | Operation::Shr(_) => 0,
+            Operation::Addition(_) | Operation::MulAdd(_) => 1,
+            Operation::Multiplication(_) | Operation::Division(_) | Operation::FloorDivision(_) => {
+                2
+            }
+            Operation::RationalPower(_) => 3,
+            Operation::Negation(_) => 4,
+            Operation::Number(num) if num.value < Num::default() => 4,
+            Operation::Number(_) | Operation::Variable(_) | Operation::PowMod(_) => 5,
+        }
+    }
+
+    /// Checks whether the given child of this operation has to be put in parentheses when written in infix notation.
+    ///
+    /// Children of divisions, powers, negations and bitwise operations are put in parentheses even if they bind equally tight,
+    /// as these operations are not associative.
+    pub fn needs_parens_around_child(&self, child: &Operation<Num>) -> bool {
+        match self {
+            // the arguments of a modular exponentiation are separated by commas
+            Operation::Number(_) | Operation::Variable(_) | Operation::PowMod(_) => false,
+            Operation::Division(_)
+            | Operation::FloorDivision(_)
+            | Operation::RationalPower(_)
+            | Operation::Negation(_)
+            | Operation::BitAnd(_)
+            | Operation::BitOr(_)
+            | Operation::BitXor(_)
+            | Operation::Shl(_)
+            | Operation::Shr(_) => child.precedence() <= self.precedence(),
+            _ => child.precedence() < self.precedence(),
+        }
+    }
+
+    /// Moves a negation of a division onto its divident, and a negation of a multiplication onto its first multiplier.
+    ///
+    /// Operations which are not negations are returned unchanged.
+    pub(crate) fn canonicalize_negation(self) -> Self {
+        let Operation::Negation(negation) = self else {
+            return self;
+        };
+        match *negation.value {
+            Operation::Division(div) => Operation::Division(Division {
+                divident: Box::new((-*div.divident).canonicalize_negation()),
+                divisor: div.divisor,
+            }),
+            Operation::Multiplication(mut mul) if !mul.multipliers.is_empty() => {
+                let first = mul.multipliers.remove(0);
+                mul.multipliers.insert(0, (-first).canonicalize_negation());
+                Operation::Multiplication(mul)
+            }
+            value => Operation::Negation(Negation {
+                value: Box::new(value),
+            }),
+        }
+    }
 }
 
 impl<
@@ -55,18 +255,28 @@ impl<
             + Rem<Output = T>
             + Clone
             + Default
-            + PartialOrd
-            + From<Num>,
+            + PartialOrd,
+        F: FnMut(Num) -> T,
     >(
         self,
+        f: &mut F,
     ) -> Operation<T> {
         match self {
-            Self::Addition(add) => add.convert(),
-            Self::Multiplication(mul) => mul.convert(),
-            Self::Division(div) => div.convert(),
-            Self::Negation(neg) => neg.convert(),
-            Self::Number(num) => num.convert(),
-            Self::Variable(var) => var.convert(),
+            Self::Addition(add) => add.convert(f),
+            Self::Multiplication(mul) => mul.convert(f),
+            Self::Division(div) => div.convert(f),
+            Self::Negation(neg) => neg.convert(f),
+            Self::Number(num) => num.convert(f),
+            Self::Variable(var) => var.convert(f),
+            Self::RationalPower(pow) => pow.convert(f),
+            Self::FloorDivision(div) => div.convert(f),
+            Self::MulAdd(fma) => fma.convert(f),
+            Self::PowMod(pow_mod) => pow_mod.convert(f),
+            Self::BitAnd(bit)
+            | Self::BitOr(bit)
+            | Self::BitXor(bit)
+            | Self::Shl(bit)
+            | Self::Shr(bit) => bit.convert(f),
         }
     }
 }
@@ -90,6 +300,15 @@ impl<
             Operation::Negation(neg) => neg.can_add_number_well(),
             Operation::Number(num) => num.can_add_number_well(),
             Operation::Variable(var) => var.can_add_number_well(),
+            Operation::RationalPower(pow) => pow.can_add_number_well(),
+            Operation::FloorDivision(div) => div.can_add_number_well(),
+            Operation::MulAdd(fma) => fma.can_add_number_well(),
+            Operation::PowMod(pow_mod) => pow_mod.can_add_number_well(),
+            Operation::BitAnd(bit)
+            | Operation::BitOr(bit)
+            | Operation::BitXor(bit)
+            | Operation::Shl(bit)
+            | Operation::Shr(bit) => bit.can_add_number_well(),
         }
     }
 }
@@ -113,6 +332,257 @@ impl<
             Operation::Negation(neg) => neg.set_vars(vars),
             Operation::Number(num) => num.set_vars(vars),
             Operation::Variable(var) => var.set_vars(vars),
+            Operation::RationalPower(pow) => pow.set_vars(vars),
+            Operation::FloorDivision(div) => div.set_vars(vars),
+            Operation::MulAdd(fma) => fma.set_vars(vars),
+            Operation::PowMod(pow_mod) => pow_mod.set_vars(vars),
+            Operation::BitAnd(bit)
+            | Operation::BitOr(bit)
+            | Operation::BitXor(bit)
+            | Operation::Shl(bit)
+            | Operation::Shr(bit) => bit.set_vars(vars),
+        }
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > WalkMut<Num> for Operation<Num>
+{
+    fn walk_mut<F: FnMut(&mut Operation<Num>)>(&mut self, f: &mut F) {
+        match self {
+            Operation::Addition(add) => add.walk_mut(f),
+            Operation::Multiplication(mul) => mul.walk_mut(f),
+            Operation::Division(div) => div.walk_mut(f),
+            Operation::Negation(neg) => neg.walk_mut(f),
+            Operation::Number(num) => num.walk_mut(f),
+            Operation::Variable(var) => var.walk_mut(f),
+            Operation::RationalPower(pow) => pow.walk_mut(f),
+            Operation::FloorDivision(div) => div.walk_mut(f),
+            Operation::MulAdd(fma) => fma.walk_mut(f),
+            Operation::PowMod(pow_mod) => pow_mod.walk_mut(f),
+            Operation::BitAnd(bit)
+            | Operation::BitOr(bit)
+            | Operation::BitXor(bit)
+            | Operation::Shl(bit)
+            | Operation::Shr(bit) => bit.walk_mut(f),
+        }
+        f(self);
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > Walk<Num> for Operation<Num>
+{
+    fn walk<F: FnMut(&Operation<Num>)>(&self, f: &mut F) {
+        match self {
+            Operation::Addition(add) => add.walk(f),
+            Operation::Multiplication(mul) => mul.walk(f),
+            Operation::Division(div) => div.walk(f),
+            Operation::Negation(neg) => neg.walk(f),
+            Operation::Number(num) => num.walk(f),
+            Operation::Variable(var) => var.walk(f),
+            Operation::RationalPower(pow) => pow.walk(f),
+            Operation::FloorDivision(div) => div.walk(f),
+            Operation::MulAdd(fma) => fma.walk(f),
+            Operation::PowMod(pow_mod) => pow_mod.walk(f),
+            Operation::BitAnd(bit)
+            | Operation::BitOr(bit)
+            | Operation::BitXor(bit)
+            | Operation::Shl(bit)
+            | Operation::Shr(bit) => bit.walk(f),
+        }
+        f(self);
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > Unify<Num> for Operation<Num>
+{
+    fn unify(&self, other: &Operation<Num>, bindings: &mut Vec<(String, Operation<Num>)>) -> bool {
+        match self {
+            Operation::Addition(add) => add.unify(other, bindings),
+            Operation::Multiplication(mul) => mul.unify(other, bindings),
+            Operation::Division(div) => div.unify(other, bindings),
+            Operation::Negation(neg) => neg.unify(other, bindings),
+            Operation::Number(num) => num.unify(other, bindings),
+            Operation::Variable(var) => var.unify(other, bindings),
+            Operation::RationalPower(pow) => pow.unify(other, bindings),
+            Operation::FloorDivision(div) => div.unify(other, bindings),
+            Operation::MulAdd(fma) => fma.unify(other, bindings),
+            Operation::PowMod(pow_mod) => pow_mod.unify(other, bindings),
+            Operation::BitAnd(bit)
+            | Operation::BitOr(bit)
+            | Operation::BitXor(bit)
+            | Operation::Shl(bit)
+            | Operation::Shr(bit) => bit.unify(other, bindings),
+        }
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > ApplyRule<Num> for Operation<Num>
+{
+    fn apply_rule(
+        &self,
+        pattern: &Operation<Num>,
+        replacement: &Operation<Num>,
+    ) -> Option<Operation<Num>> {
+        let mut bindings = Vec::new();
+        if pattern.unify(self, &mut bindings) {
+            let vars: Vec<(&str, &Operation<Num>)> = bindings
+                .iter()
+                .map(|binding| (binding.0.as_str(), &binding.1))
+                .collect();
+            return Some(replacement.set_vars(&vars));
+        }
+
+        match self {
+            Operation::Addition(add) => add.apply_rule(pattern, replacement),
+            Operation::Multiplication(mul) => mul.apply_rule(pattern, replacement),
+            Operation::Division(div) => div.apply_rule(pattern, replacement),
+            Operation::Negation(neg) => neg.apply_rule(pattern, replacement),
+            Operation::Number(num) => num.apply_rule(pattern, replacement),
+            Operation::Variable(var) => var.apply_rule(pattern, replacement),
+            Operation::RationalPower(pow) => pow.apply_rule(pattern, replacement),
+            Operation::FloorDivision(div) => div.apply_rule(pattern, replacement),
+            Operation::MulAdd(fma) => fma.apply_rule(pattern, replacement),
+            Operation::PowMod(pow_mod) => pow_mod.apply_rule(pattern, replacement),
+            Operation::BitAnd(bit)
+            | Operation::BitOr(bit)
+            | Operation::BitXor(bit)
+            | Operation::Shl(bit)
+            | Operation::Shr(bit) => bit.apply_rule(pattern, replacement),
+        }
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd
+            + From<u8>,
+    > ToPolynomialCoefficients<Num> for Operation<Num>
+{
+    fn to_polynomial_coefficients(&self, var: &str) -> Option<Vec<Operation<Num>>> {
+        match self {
+            Operation::Addition(add) => add.to_polynomial_coefficients(var),
+            Operation::Multiplication(mul) => mul.to_polynomial_coefficients(var),
+            Operation::Division(div) => div.to_polynomial_coefficients(var),
+            Operation::Negation(neg) => neg.to_polynomial_coefficients(var),
+            Operation::Number(num) => num.to_polynomial_coefficients(var),
+            Operation::Variable(v) => v.to_polynomial_coefficients(var),
+            Operation::RationalPower(pow) => pow.to_polynomial_coefficients(var),
+            Operation::FloorDivision(div) => div.to_polynomial_coefficients(var),
+            Operation::MulAdd(fma) => fma.to_polynomial_coefficients(var),
+            Operation::PowMod(pow_mod) => pow_mod.to_polynomial_coefficients(var),
+            Operation::BitAnd(bit)
+            | Operation::BitOr(bit)
+            | Operation::BitXor(bit)
+            | Operation::Shl(bit)
+            | Operation::Shr(bit) => bit.to_polynomial_coefficients(var),
+        }
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd
+            + From<u8>,
+    > Diff<Num> for Operation<Num>
+{
+    fn diff(&self, var: &str) -> Operation<Num> {
+        match self {
+            Operation::Addition(add) => add.diff(var),
+            Operation::Multiplication(mul) => mul.diff(var),
+            Operation::Division(div) => div.diff(var),
+            Operation::Negation(neg) => neg.diff(var),
+            Operation::Number(num) => num.diff(var),
+            Operation::Variable(v) => v.diff(var),
+            Operation::RationalPower(pow) => pow.diff(var),
+            Operation::FloorDivision(div) => div.diff(var),
+            Operation::MulAdd(fma) => fma.diff(var),
+            Operation::PowMod(pow_mod) => pow_mod.diff(var),
+            Operation::BitAnd(bit)
+            | Operation::BitOr(bit)
+            | Operation::BitXor(bit)
+            | Operation::Shl(bit)
+            | Operation::Shr(bit) => bit.diff(var),
+        }
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd
+            + Display,
+    > ToPrefixNotation for Operation<Num>
+{
+    fn to_prefix_notation(&self) -> String {
+        match self {
+            Operation::Addition(add) => add.to_prefix_notation(),
+            Operation::Multiplication(mul) => mul.to_prefix_notation(),
+            Operation::Division(div) => div.to_prefix_notation(),
+            Operation::Negation(neg) => neg.to_prefix_notation(),
+            Operation::Number(num) => num.to_prefix_notation(),
+            Operation::Variable(var) => var.to_prefix_notation(),
+            Operation::RationalPower(pow) => pow.to_prefix_notation(),
+            Operation::FloorDivision(div) => div.to_prefix_notation(),
+            Operation::MulAdd(fma) => fma.to_prefix_notation(),
+            Operation::PowMod(pow_mod) => pow_mod.to_prefix_notation(),
+            Operation::BitAnd(bit)
+            | Operation::BitOr(bit)
+            | Operation::BitXor(bit)
+            | Operation::Shl(bit)
+            | Operation::Shr(bit) => bit.to_prefix_notation(),
         }
     }
 }
@@ -145,6 +615,84 @@ impl<
             Operation::Negation(inv) => inv.calc(),
             Operation::Number(num) => Output::from(num.value.clone()),
             Operation::Variable(_) => panic!("Cannot calculate result of a term with variables."),
+            Operation::RationalPower(pow) => pow.calc(),
+            Operation::FloorDivision(div) => div.calc(),
+            Operation::MulAdd(fma) => fma.calc(),
+            Operation::PowMod(pow_mod) => pow_mod.calc(),
+            Operation::BitAnd(bit)
+            | Operation::BitOr(bit)
+            | Operation::BitXor(bit)
+            | Operation::Shl(bit)
+            | Operation::Shr(bit) => bit.calc(),
+        }
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > CalcFloat<Num> for Operation<Num>
+{
+    fn calc_float<Output: Float + From<Num>>(&self) -> Output {
+        match self {
+            Operation::Addition(add) => add.calc_float(),
+            Operation::Multiplication(mul) => mul.calc_float(),
+            Operation::Division(div) => div.calc_float(),
+            Operation::Negation(inv) => inv.calc_float(),
+            Operation::Number(num) => <Output as From<Num>>::from(num.value.clone()),
+            Operation::Variable(_) => panic!("Cannot calculate result of a term with variables."),
+            Operation::RationalPower(pow) => pow.calc_float(),
+            Operation::FloorDivision(div) => div.calc_float(),
+            Operation::MulAdd(fma) => fma.calc_float(),
+            Operation::PowMod(pow_mod) => pow_mod.calc_float(),
+            Operation::BitAnd(bit)
+            | Operation::BitOr(bit)
+            | Operation::BitXor(bit)
+            | Operation::Shl(bit)
+            | Operation::Shr(bit) => bit.calc_float(),
+        }
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > CalcBatch<Num> for Operation<Num>
+{
+    fn calc_batch<Output: Float + From<Num>, const N: usize>(
+        &self,
+        var: &str,
+        values: &[Output; N],
+    ) -> [Output; N] {
+        match self {
+            Operation::Addition(add) => add.calc_batch(var, values),
+            Operation::Multiplication(mul) => mul.calc_batch(var, values),
+            Operation::Division(div) => div.calc_batch(var, values),
+            Operation::Negation(inv) => inv.calc_batch(var, values),
+            Operation::Number(num) => [<Output as From<Num>>::from(num.value.clone()); N],
+            Operation::Variable(variable) if variable.name == var => *values,
+            Operation::Variable(_) => panic!("Cannot calculate result of a term with variables."),
+            Operation::RationalPower(pow) => pow.calc_batch(var, values),
+            Operation::FloorDivision(div) => div.calc_batch(var, values),
+            Operation::MulAdd(fma) => fma.calc_batch(var, values),
+            Operation::PowMod(pow_mod) => pow_mod.calc_batch(var, values),
+            Operation::BitAnd(bit)
+            | Operation::BitOr(bit)
+            | Operation::BitXor(bit)
+            | Operation::Shl(bit)
+            | Operation::Shr(bit) => bit.calc_batch(var, values),
         }
     }
 }
@@ -195,6 +743,12 @@ impl<
     type Output = Self;
 
     fn add(self, rhs: Self) -> Self::Output {
+        let Some(_step) = SimplificationStep::enter() else {
+            return Operation::Addition(Addition {
+                summands: vec![self, rhs],
+            });
+        };
+
         match (self, rhs) {
             (Operation::Addition(first), Operation::Addition(second)) => first + second,
             (Operation::Multiplication(first), Operation::Multiplication(second)) => first + second,
@@ -202,6 +756,10 @@ impl<
             (Operation::Negation(first), Operation::Negation(second)) => first + second,
             (Operation::Number(first), Operation::Number(second)) => first + second,
             (Operation::Variable(first), Operation::Variable(second)) => first + second,
+            (Operation::RationalPower(first), Operation::RationalPower(second)) => first + second,
+            (Operation::FloorDivision(first), Operation::FloorDivision(second)) => first + second,
+            (Operation::MulAdd(first), Operation::MulAdd(second)) => first + second,
+            (Operation::PowMod(first), Operation::PowMod(second)) => first + second,
 
             (Operation::Number(num), any) if (num.value == Num::default()) => any,
             (any, Operation::Number(num)) if (num.value == Num::default()) => any,
@@ -257,6 +815,16 @@ impl<
     type Output = Self;
 
     fn div(self, rhs: Self) -> Self::Output {
+        let Some(_step) = SimplificationStep::enter() else {
+            if rhs == Operation::default() {
+                panic!("Cannot divide by zero.")
+            }
+            return Operation::Division(Division {
+                divident: Box::new(self),
+                divisor: Box::new(rhs),
+            });
+        };
+
         match (self, rhs) {
             (Operation::Addition(divident), Operation::Addition(divisor)) => divident / divisor,
             (Operation::Multiplication(divident), Operation::Multiplication(divisor)) => {
@@ -266,6 +834,14 @@ impl<
             (Operation::Negation(divident), Operation::Negation(divisor)) => divident / divisor,
             (Operation::Number(divident), Operation::Number(divisor)) => divident / divisor,
             (Operation::Variable(divident), Operation::Variable(divisor)) => divident / divisor,
+            (Operation::RationalPower(divident), Operation::RationalPower(divisor)) => {
+                divident / divisor
+            }
+            (Operation::FloorDivision(divident), Operation::FloorDivision(divisor)) => {
+                divident / divisor
+            }
+            (Operation::MulAdd(divident), Operation::MulAdd(divisor)) => divident / divisor,
+            (Operation::PowMod(divident), Operation::PowMod(divisor)) => divident / divisor,
 
             (_, Operation::Number(num)) if (num.value == Num::default()) => {
                 panic!("Cannot divide by zero.")
@@ -303,6 +879,12 @@ impl<
     type Output = Self;
 
     fn mul(self, rhs: Self) -> Self::Output {
+        let Some(_step) = SimplificationStep::enter() else {
+            return Operation::Multiplication(Multiplication {
+                multipliers: vec![self, rhs],
+            });
+        };
+
         match (self, rhs) {
             (Operation::Addition(first), Operation::Addition(second)) => first * second,
             (Operation::Multiplication(first), Operation::Multiplication(second)) => first * second,
@@ -310,6 +892,10 @@ impl<
             (Operation::Negation(first), Operation::Negation(second)) => first * second,
             (Operation::Number(first), Operation::Number(second)) => first * second,
             (Operation::Variable(first), Operation::Variable(second)) => first * second,
+            (Operation::RationalPower(first), Operation::RationalPower(second)) => first * second,
+            (Operation::FloorDivision(first), Operation::FloorDivision(second)) => first * second,
+            (Operation::MulAdd(first), Operation::MulAdd(second)) => first * second,
+            (Operation::PowMod(first), Operation::PowMod(second)) => first * second,
 
             (Operation::Number(num), _) if (num.value == Num::default()) => Operation::Number(num),
             (_, Operation::Number(num)) if (num.value == Num::default()) => Operation::Number(num),
@@ -354,6 +940,17 @@ impl<
     type Output = Self;
 
     fn sub(self, rhs: Self) -> Self::Output {
+        let Some(_step) = SimplificationStep::enter() else {
+            return Operation::Addition(Addition {
+                summands: vec![
+                    self,
+                    Operation::Negation(Negation {
+                        value: Box::new(rhs),
+                    }),
+                ],
+            });
+        };
+
         match (self, rhs) {
             (Operation::Addition(first), Operation::Addition(second)) => first - second,
             (Operation::Multiplication(first), Operation::Multiplication(second)) => first - second,
@@ -361,6 +958,10 @@ impl<
             (Operation::Negation(first), Operation::Negation(second)) => first - second,
             (Operation::Number(first), Operation::Number(second)) => first - second,
             (Operation::Variable(first), Operation::Variable(second)) => first - second,
+            (Operation::RationalPower(first), Operation::RationalPower(second)) => first - second,
+            (Operation::FloorDivision(first), Operation::FloorDivision(second)) => first - second,
+            (Operation::MulAdd(first), Operation::MulAdd(second)) => first - second,
+            (Operation::PowMod(first), Operation::PowMod(second)) => first - second,
 
             (Operation::Number(num), any) if (num.value == Num::default()) => -any,
             (any, Operation::Number(num)) if (num.value == Num::default()) => any,
@@ -402,6 +1003,58 @@ impl<
             Operation::Negation(neg) => -neg,
             Operation::Number(num) => -num,
             Operation::Variable(var) => -var,
+            Operation::RationalPower(pow) => -pow,
+            Operation::FloorDivision(div) => -div,
+            Operation::MulAdd(fma) => -fma,
+            Operation::PowMod(pow_mod) => -pow_mod,
+            Operation::BitAnd(bit)
+            | Operation::BitOr(bit)
+            | Operation::BitXor(bit)
+            | Operation::Shl(bit)
+            | Operation::Shr(bit) => -bit,
         }
     }
 }
+
+/// Removes all trailing zero coefficients of a polynomial.
+pub fn trim_coefficients<
+    Num: Add<Output = Num>
+        + Sub<Output = Num>
+        + Mul<Output = Num>
+        + Div<Output = Num>
+        + Rem<Output = Num>
+        + Clone
+        + Default
+        + PartialOrd,
+>(
+    coefficients: &mut Vec<Operation<Num>>,
+) {
+    while coefficients.last() == Some(&Operation::default()) {
+        coefficients.pop();
+    }
+}
+
+/// Multiplies the operations, leaving out factors of one.
+pub fn multiply_skipping_one<
+    Num: Add<Output = Num>
+        + Sub<Output = Num>
+        + Mul<Output = Num>
+        + Div<Output = Num>
+        + Rem<Output = Num>
+        + Clone
+        + Default
+        + PartialOrd
+        + From<u8>,
+>(
+    first: Operation<Num>,
+    second: Operation<Num>,
+) -> Operation<Num> {
+    let one = Operation::from(Num::from(1));
+    if first == one {
+        second
+    } else if second == one {
+        first
+    } else {
+        first * second
+    }
+}
@@ -1,11 +1,19 @@
-use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+use std::{
+    fmt::Display,
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+};
+
+use num_traits::Float;
 
 use super::{
     division::Division,
     multiplication::Multiplication,
     negation::Negation,
     number::Number,
-    traits::{Calc, CanAddNumWell, Convert, SetVars},
+    traits::{
+        ApplyRule, Calc, CalcBatch, CalcFloat, CanAddNumWell, Convert, Diff, SetVars,
+        ToPolynomialCoefficients, ToPrefixNotation, Unify, Walk, WalkMut,
+    },
     Operation,
 };
 
@@ -42,16 +50,17 @@ impl<
             + Rem<Output = T>
             + Clone
             + Default
-            + PartialOrd
-            + From<Num>,
+            + PartialOrd,
+        F: FnMut(Num) -> T,
     >(
         self,
+        f: &mut F,
     ) -> Operation<T> {
         Operation::Addition(Addition {
             summands: self
                 .summands
                 .into_iter()
-                .map(|summand| summand.convert())
+                .map(|summand| summand.convert(f))
                 .collect(),
         })
     }
@@ -121,6 +130,161 @@ impl<
     }
 }
 
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > WalkMut<Num> for Addition<Num>
+{
+    fn walk_mut<F: FnMut(&mut Operation<Num>)>(&mut self, f: &mut F) {
+        for summand in &mut self.summands {
+            summand.walk_mut(f);
+        }
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > Walk<Num> for Addition<Num>
+{
+    fn walk<F: FnMut(&Operation<Num>)>(&self, f: &mut F) {
+        for summand in &self.summands {
+            summand.walk(f);
+        }
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > Unify<Num> for Addition<Num>
+{
+    fn unify(&self, other: &Operation<Num>, bindings: &mut Vec<(String, Operation<Num>)>) -> bool {
+        match other {
+            Operation::Addition(other) => {
+                self.summands.len() == other.summands.len()
+                    && self
+                        .summands
+                        .iter()
+                        .zip(&other.summands)
+                        .all(|(summand, other)| summand.unify(other, bindings))
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > ApplyRule<Num> for Addition<Num>
+{
+    fn apply_rule(
+        &self,
+        pattern: &Operation<Num>,
+        replacement: &Operation<Num>,
+    ) -> Option<Operation<Num>> {
+        for i in 0..self.summands.len() {
+            if let Some(replaced) = self.summands[i].apply_rule(pattern, replacement) {
+                let mut summands = self.summands.clone();
+                summands[i] = replaced;
+                return Some(
+                    summands
+                        .into_iter()
+                        .fold(Operation::from(Num::default()), |acc, op| acc + op),
+                );
+            }
+        }
+        None
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd
+            + From<u8>,
+    > ToPolynomialCoefficients<Num> for Addition<Num>
+{
+    fn to_polynomial_coefficients(&self, var: &str) -> Option<Vec<Operation<Num>>> {
+        let mut result = Vec::new();
+        for summand in &self.summands {
+            result = add_polynomials(result, summand.to_polynomial_coefficients(var)?);
+        }
+        Some(result)
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd
+            + From<u8>,
+    > Diff<Num> for Addition<Num>
+{
+    fn diff(&self, var: &str) -> Operation<Num> {
+        self.summands
+            .iter()
+            .fold(Operation::default(), |acc, summand| acc + summand.diff(var))
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd
+            + Display,
+    > ToPrefixNotation for Addition<Num>
+{
+    fn to_prefix_notation(&self) -> String {
+        let summands: Vec<String> = self
+            .summands
+            .iter()
+            .map(|summand| summand.to_prefix_notation())
+            .collect();
+        format!("(+ {})", summands.join(" "))
+    }
+}
+
 impl<
         Num: Add<Output = Num>
             + Sub<Output = Num>
@@ -150,6 +314,53 @@ impl<
     }
 }
 
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > CalcFloat<Num> for Addition<Num>
+{
+    fn calc_float<Output: Float + From<Num>>(&self) -> Output {
+        let mut result = self.summands[0].calc_float();
+        for i in 1..self.summands.len() {
+            result = result + self.summands[i].calc_float();
+        }
+        result
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > CalcBatch<Num> for Addition<Num>
+{
+    fn calc_batch<Output: Float + From<Num>, const N: usize>(
+        &self,
+        var: &str,
+        values: &[Output; N],
+    ) -> [Output; N] {
+        let mut result = self.summands[0].calc_batch(var, values);
+        for i in 1..self.summands.len() {
+            let lanes = self.summands[i].calc_batch(var, values);
+            for lane in 0..N {
+                result[lane] = result[lane] + lanes[lane];
+            }
+        }
+        result
+    }
+}
+
 impl<
         Num: Add<Output = Num>
             + Sub<Output = Num>
@@ -257,3 +468,27 @@ impl<
         })
     }
 }
+
+/// Adds two polynomials, given by their coefficients (starting with the constant one).
+pub fn add_polynomials<
+    Num: Add<Output = Num>
+        + Sub<Output = Num>
+        + Mul<Output = Num>
+        + Div<Output = Num>
+        + Rem<Output = Num>
+        + Clone
+        + Default
+        + PartialOrd,
+>(
+    mut first: Vec<Operation<Num>>,
+    second: Vec<Operation<Num>>,
+) -> Vec<Operation<Num>> {
+    for (i, coefficient) in second.into_iter().enumerate() {
+        if i < first.len() {
+            first[i] = std::mem::take(&mut first[i]) + coefficient;
+        } else {
+            first.push(coefficient);
+        }
+    }
+    first
+}
@@ -0,0 +1,592 @@
+use std::{
+    cmp::Ordering,
+    fmt::{self, Debug, Display},
+    ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Neg, Rem, Sub},
+    sync::Arc,
+};
+
+use num_traits::{CheckedShl, CheckedShr, Float, ToPrimitive};
+
+use super::{
+    negation::Negation,
+    traits::{
+        ApplyRule, Calc, CalcBatch, CalcFloat, CanAddNumWell, Convert, Diff, SetVars,
+        ToPolynomialCoefficients, ToPrefixNotation, Unify, Walk, WalkMut,
+    },
+    trim_coefficients, Operation,
+};
+
+/// The kind of a bitwise operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum BitOperator {
+    And,
+    Or,
+    Xor,
+    Shl,
+    Shr,
+}
+
+impl BitOperator {
+    /// The symbol of the operator in prefix notation.
+    pub fn symbol(self) -> &'static str {
+        match self {
+            BitOperator::And => "&",
+            BitOperator::Or => "|",
+            BitOperator::Xor => "xor",
+            BitOperator::Shl => "<<",
+            BitOperator::Shr => ">>",
+        }
+    }
+}
+
+/// Evaluates a bitwise operation on two numbers, returning `None` if the result cannot be represented.
+pub type BitEvaluator<Num> = Arc<dyn Fn(Num, Num) -> Option<Num> + Send + Sync>;
+
+/// A bitwise operation on two integers.
+///
+/// As bitwise operations are not available for every number type, the evaluator is created from the
+/// operator traits of the number type when the operation is created, and stored alongside.
+/// Replacing variables happens without those traits, so the stored evaluator is used instead.
+/// It is lost when converting the term into another number type using [`Convert`].
+#[derive(Clone)]
+pub struct BitOperation<
+    Num: Add<Output = Num>
+        + Sub<Output = Num>
+        + Mul<Output = Num>
+        + Div<Output = Num>
+        + Rem<Output = Num>
+        + Clone
+        + Default
+        + PartialOrd,
+> {
+    pub operator: BitOperator,
+    pub lhs: Box<Operation<Num>>,
+    pub rhs: Box<Operation<Num>>,
+    pub evaluate: Option<BitEvaluator<Num>>,
+}
+
+/// Converts the number type of an operation, converting the evaluators of all bitwise operations within as well.
+///
+/// Unlike [`Convert`], this keeps the evaluators, as long as there is a way to convert them.
+pub fn convert_keeping_evaluators<
+    Num: Add<Output = Num>
+        + Sub<Output = Num>
+        + Mul<Output = Num>
+        + Div<Output = Num>
+        + Rem<Output = Num>
+        + Clone
+        + Default
+        + PartialOrd,
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Rem<Output = T>
+        + Clone
+        + Default
+        + PartialOrd,
+>(
+    operation: Operation<Num>,
+    mut f: impl FnMut(Num) -> T,
+    mut convert_evaluator: impl FnMut(BitEvaluator<Num>) -> BitEvaluator<T>,
+) -> Operation<T> {
+    // converting keeps the structure, so the evaluators are reattached in the order they were walked in
+    let mut evaluators = Vec::new();
+    operation.walk(&mut |operation| {
+        if let Operation::BitAnd(bit)
+        | Operation::BitOr(bit)
+        | Operation::BitXor(bit)
+        | Operation::Shl(bit)
+        | Operation::Shr(bit) = operation
+        {
+            evaluators.push(bit.evaluate.clone());
+        }
+    });
+
+    let mut converted = operation.convert(&mut f);
+    let mut evaluators = evaluators.into_iter();
+    converted.walk_mut(&mut |operation| {
+        if let Operation::BitAnd(bit)
+        | Operation::BitOr(bit)
+        | Operation::BitXor(bit)
+        | Operation::Shl(bit)
+        | Operation::Shr(bit) = operation
+        {
+            bit.evaluate = evaluators.next().flatten().map(&mut convert_evaluator);
+        }
+    });
+    converted
+}
+
+/// Creates the evaluator of a bitwise and.
+pub fn bit_and_evaluator<Num: BitAnd<Output = Num>>() -> BitEvaluator<Num> {
+    Arc::new(|lhs, rhs| Some(lhs & rhs))
+}
+
+/// Creates the evaluator of a bitwise or.
+pub fn bit_or_evaluator<Num: BitOr<Output = Num>>() -> BitEvaluator<Num> {
+    Arc::new(|lhs, rhs| Some(lhs | rhs))
+}
+
+/// Creates the evaluator of a bitwise xor.
+pub fn bit_xor_evaluator<Num: BitXor<Output = Num>>() -> BitEvaluator<Num> {
+    Arc::new(|lhs, rhs| Some(lhs ^ rhs))
+}
+
+/// Creates the evaluator of a left shift. Shifting by the number of bits or more cannot be evaluated.
+pub fn shl_evaluator<Num: CheckedShl + ToPrimitive>() -> BitEvaluator<Num> {
+    Arc::new(|lhs, rhs| lhs.checked_shl(rhs.to_u32()?))
+}
+
+/// Creates the evaluator of a right shift. Shifting by the number of bits or more cannot be evaluated.
+pub fn shr_evaluator<Num: CheckedShr + ToPrimitive>() -> BitEvaluator<Num> {
+    Arc::new(|lhs, rhs| lhs.checked_shr(rhs.to_u32()?))
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > BitOperation<Num>
+{
+    /// Applies the operator to both operands.
+    /// The result is calculated immediately if both are numbers, the evaluator is known and the result can be represented.
+    pub fn apply(
+        operator: BitOperator,
+        lhs: Operation<Num>,
+        rhs: Operation<Num>,
+        evaluate: Option<BitEvaluator<Num>>,
+    ) -> Operation<Num> {
+        let result = match (&lhs, &rhs, &evaluate) {
+            (Operation::Number(lhs), Operation::Number(rhs), Some(evaluate)) => {
+                evaluate(lhs.value.clone(), rhs.value.clone())
+            }
+            _ => None,
+        };
+        match result {
+            Some(result) => Operation::from(result),
+            None => BitOperation {
+                operator,
+                lhs: Box::new(lhs),
+                rhs: Box::new(rhs),
+                evaluate,
+            }
+            .into_operation(),
+        }
+    }
+
+    /// Evaluates the operation if both operands are numbers.
+    /// Only the evaluator knows how, so the operation cannot be calculated using any other number type.
+    fn evaluate_numbers(&self) -> Option<Num> {
+        match (&*self.lhs, &*self.rhs, &self.evaluate) {
+            (Operation::Number(lhs), Operation::Number(rhs), Some(evaluate)) => {
+                evaluate(lhs.value.clone(), rhs.value.clone())
+            }
+            _ => None,
+        }
+    }
+
+    /// Wraps the operation into the variant matching its operator.
+    fn into_operation(self) -> Operation<Num> {
+        match self.operator {
+            BitOperator::And => Operation::BitAnd(self),
+            BitOperator::Or => Operation::BitOr(self),
+            BitOperator::Xor => Operation::BitXor(self),
+            BitOperator::Shl => Operation::Shl(self),
+            BitOperator::Shr => Operation::Shr(self),
+        }
+    }
+}
+
+// The evaluator is not printed, as functions cannot be printed.
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd
+            + Debug,
+    > Debug for BitOperation<Num>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BitOperation")
+            .field("operator", &self.operator)
+            .field("lhs", &self.lhs)
+            .field("rhs", &self.rhs)
+            .finish_non_exhaustive()
+    }
+}
+
+// The evaluator is not compared, as functions cannot be compared reliably.
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > PartialEq for BitOperation<Num>
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.operator == other.operator && self.lhs == other.lhs && self.rhs == other.rhs
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > PartialOrd for BitOperation<Num>
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match self.operator.cmp(&other.operator) {
+            Ordering::Equal => (&self.lhs, &self.rhs).partial_cmp(&(&other.lhs, &other.rhs)),
+            ordering => Some(ordering),
+        }
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > Convert<Num> for BitOperation<Num>
+{
+    fn convert<
+        T: Add<Output = T>
+            + Sub<Output = T>
+            + Mul<Output = T>
+            + Div<Output = T>
+            + Rem<Output = T>
+            + Clone
+            + Default
+            + PartialOrd,
+        F: FnMut(Num) -> T,
+    >(
+        self,
+        f: &mut F,
+    ) -> Operation<T> {
+        BitOperation {
+            operator: self.operator,
+            lhs: Box::new(self.lhs.convert(f)),
+            rhs: Box::new(self.rhs.convert(f)),
+            evaluate: None,
+        }
+        .into_operation()
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > CanAddNumWell for BitOperation<Num>
+{
+    fn can_add_number_well(&self) -> bool {
+        false
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > SetVars<Num> for BitOperation<Num>
+{
+    fn set_vars(&self, vars: &[(&str, &Operation<Num>)]) -> Operation<Num> {
+        BitOperation::apply(
+            self.operator,
+            self.lhs.set_vars(vars),
+            self.rhs.set_vars(vars),
+            self.evaluate.clone(),
+        )
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > WalkMut<Num> for BitOperation<Num>
+{
+    fn walk_mut<F: FnMut(&mut Operation<Num>)>(&mut self, f: &mut F) {
+        self.lhs.walk_mut(f);
+        self.rhs.walk_mut(f);
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > Walk<Num> for BitOperation<Num>
+{
+    fn walk<F: FnMut(&Operation<Num>)>(&self, f: &mut F) {
+        self.lhs.walk(f);
+        self.rhs.walk(f);
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > Unify<Num> for BitOperation<Num>
+{
+    fn unify(&self, other: &Operation<Num>, bindings: &mut Vec<(String, Operation<Num>)>) -> bool {
+        match other {
+            Operation::BitAnd(other)
+            | Operation::BitOr(other)
+            | Operation::BitXor(other)
+            | Operation::Shl(other)
+            | Operation::Shr(other)
+                if other.operator == self.operator =>
+            {
+                self.lhs.unify(&other.lhs, bindings) && self.rhs.unify(&other.rhs, bindings)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > ApplyRule<Num> for BitOperation<Num>
+{
+    fn apply_rule(
+        &self,
+        pattern: &Operation<Num>,
+        replacement: &Operation<Num>,
+    ) -> Option<Operation<Num>> {
+        if let Some(lhs) = self.lhs.apply_rule(pattern, replacement) {
+            Some(BitOperation::apply(
+                self.operator,
+                lhs,
+                (*self.rhs).clone(),
+                self.evaluate.clone(),
+            ))
+        } else {
+            self.rhs.apply_rule(pattern, replacement).map(|rhs| {
+                BitOperation::apply(
+                    self.operator,
+                    (*self.lhs).clone(),
+                    rhs,
+                    self.evaluate.clone(),
+                )
+            })
+        }
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd
+            + From<u8>,
+    > ToPolynomialCoefficients<Num> for BitOperation<Num>
+{
+    fn to_polynomial_coefficients(&self, var: &str) -> Option<Vec<Operation<Num>>> {
+        let mut lhs = self.lhs.to_polynomial_coefficients(var)?;
+        let mut rhs = self.rhs.to_polynomial_coefficients(var)?;
+        trim_coefficients(&mut lhs);
+        trim_coefficients(&mut rhs);
+        if lhs.len() > 1 || rhs.len() > 1 {
+            None
+        } else {
+            Some(vec![self.clone().into_operation()])
+        }
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd
+            + From<u8>,
+    > Diff<Num> for BitOperation<Num>
+{
+    fn diff(&self, var: &str) -> Operation<Num> {
+        if self.lhs.diff(var) == Operation::default() && self.rhs.diff(var) == Operation::default()
+        {
+            Operation::default()
+        } else {
+            panic!("Cannot differentiate bitwise operations.")
+        }
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd
+            + Display,
+    > ToPrefixNotation for BitOperation<Num>
+{
+    fn to_prefix_notation(&self) -> String {
+        format!(
+            "({} {} {})",
+            self.operator.symbol(),
+            self.lhs.to_prefix_notation(),
+            self.rhs.to_prefix_notation()
+        )
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > Calc<Num> for BitOperation<Num>
+{
+    fn calc<
+        Output: Add<Output = Output>
+            + Sub<Output = Output>
+            + Mul<Output = Output>
+            + Div<Output = Output>
+            + Neg<Output = Output>
+            + From<Num>,
+    >(
+        &self,
+    ) -> Output {
+        match self.evaluate_numbers() {
+            Some(result) => Output::from(result),
+            None => panic!(
+                "Cannot calculate bitwise operations which could not be evaluated while simplifying."
+            ),
+        }
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > CalcFloat<Num> for BitOperation<Num>
+{
+    fn calc_float<Output: Float + From<Num>>(&self) -> Output {
+        match self.evaluate_numbers() {
+            Some(result) => <Output as From<Num>>::from(result),
+            None => panic!("Cannot calculate bitwise operations using floating point numbers."),
+        }
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > CalcBatch<Num> for BitOperation<Num>
+{
+    fn calc_batch<Output: Float + From<Num>, const N: usize>(
+        &self,
+        _var: &str,
+        _values: &[Output; N],
+    ) -> [Output; N] {
+        match self.evaluate_numbers() {
+            Some(result) => [<Output as From<Num>>::from(result); N],
+            None => panic!("Cannot calculate bitwise operations using floating point numbers."),
+        }
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > Neg for BitOperation<Num>
+{
+    type Output = Operation<Num>;
+
+    fn neg(self) -> Self::Output {
+        Operation::Negation(Negation {
+            value: Box::new(self.into_operation()),
+        })
+    }
+}
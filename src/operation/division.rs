@@ -1,9 +1,19 @@
-use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+use std::{
+    array::from_fn,
+    fmt::Display,
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+};
+
+use num_traits::Float;
 
 use super::{
+    multiply_skipping_one,
     negation::Negation,
-    traits::{Calc, CanAddNumWell, Convert, SetVars},
-    Operation,
+    traits::{
+        ApplyRule, Calc, CalcBatch, CalcFloat, CanAddNumWell, Convert, Diff, SetVars,
+        ToPolynomialCoefficients, ToPrefixNotation, Unify, Walk, WalkMut,
+    },
+    trim_coefficients, Operation,
 };
 
 #[derive(Debug, PartialEq, PartialOrd, Default, Clone)]
@@ -40,14 +50,15 @@ impl<
             + Rem<Output = T>
             + Clone
             + Default
-            + PartialOrd
-            + From<Num>,
+            + PartialOrd,
+        F: FnMut(Num) -> T,
     >(
         self,
+        f: &mut F,
     ) -> Operation<T> {
         Operation::Division(Division {
-            divident: Box::new(self.divident.convert()),
-            divisor: Box::new(self.divisor.convert()),
+            divident: Box::new(self.divident.convert(f)),
+            divisor: Box::new(self.divisor.convert(f)),
         })
     }
 }
@@ -87,6 +98,165 @@ impl<
     }
 }
 
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > WalkMut<Num> for Division<Num>
+{
+    fn walk_mut<F: FnMut(&mut Operation<Num>)>(&mut self, f: &mut F) {
+        self.divident.walk_mut(f);
+        self.divisor.walk_mut(f);
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > Walk<Num> for Division<Num>
+{
+    fn walk<F: FnMut(&Operation<Num>)>(&self, f: &mut F) {
+        self.divident.walk(f);
+        self.divisor.walk(f);
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > Unify<Num> for Division<Num>
+{
+    fn unify(&self, other: &Operation<Num>, bindings: &mut Vec<(String, Operation<Num>)>) -> bool {
+        match other {
+            Operation::Division(other) => {
+                self.divident.unify(&other.divident, bindings)
+                    && self.divisor.unify(&other.divisor, bindings)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > ApplyRule<Num> for Division<Num>
+{
+    fn apply_rule(
+        &self,
+        pattern: &Operation<Num>,
+        replacement: &Operation<Num>,
+    ) -> Option<Operation<Num>> {
+        if let Some(divident) = self.divident.apply_rule(pattern, replacement) {
+            Some(divident / (*self.divisor).clone())
+        } else {
+            self.divisor
+                .apply_rule(pattern, replacement)
+                .map(|divisor| (*self.divident).clone() / divisor)
+        }
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd
+            + From<u8>,
+    > ToPolynomialCoefficients<Num> for Division<Num>
+{
+    fn to_polynomial_coefficients(&self, var: &str) -> Option<Vec<Operation<Num>>> {
+        let mut divisor = self.divisor.to_polynomial_coefficients(var)?;
+        trim_coefficients(&mut divisor);
+        if divisor.len() > 1 {
+            return None;
+        }
+        let divisor = divisor.pop()?;
+
+        Some(
+            self.divident
+                .to_polynomial_coefficients(var)?
+                .into_iter()
+                .map(|coefficient| coefficient / divisor.clone())
+                .collect(),
+        )
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd
+            + From<u8>,
+    > Diff<Num> for Division<Num>
+{
+    fn diff(&self, var: &str) -> Operation<Num> {
+        let divident_derivative = self.divident.diff(var);
+        let divisor_derivative = self.divisor.diff(var);
+        if divisor_derivative == Operation::default() {
+            return divident_derivative / (*self.divisor).clone();
+        }
+
+        // quotient rule: (a' * b - a * b') / b^2
+        (multiply_skipping_one(divident_derivative, (*self.divisor).clone())
+            - multiply_skipping_one((*self.divident).clone(), divisor_derivative))
+            / ((*self.divisor).clone() * (*self.divisor).clone())
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd
+            + Display,
+    > ToPrefixNotation for Division<Num>
+{
+    fn to_prefix_notation(&self) -> String {
+        format!(
+            "(/ {} {})",
+            self.divident.to_prefix_notation(),
+            self.divisor.to_prefix_notation()
+        )
+    }
+}
+
 impl<
         Num: Add<Output = Num>
             + Sub<Output = Num>
@@ -112,6 +282,44 @@ impl<
     }
 }
 
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > CalcFloat<Num> for Division<Num>
+{
+    fn calc_float<Output: Float + From<Num>>(&self) -> Output {
+        self.divident.calc_float::<Output>() / self.divisor.calc_float::<Output>()
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > CalcBatch<Num> for Division<Num>
+{
+    fn calc_batch<Output: Float + From<Num>, const N: usize>(
+        &self,
+        var: &str,
+        values: &[Output; N],
+    ) -> [Output; N] {
+        let divident = self.divident.calc_batch(var, values);
+        let divisor = self.divisor.calc_batch(var, values);
+        from_fn(|lane| divident[lane] / divisor[lane])
+    }
+}
+
 impl<
         Num: Add<Output = Num>
             + Sub<Output = Num>
@@ -198,7 +406,7 @@ impl<
         if s_divisor == r_divisor {
             (s_divident - r_divident) / s_divisor
         } else {
-            ((s_divident * r_divisor.clone()) + (r_divident * s_divisor.clone()))
+            ((s_divident * r_divisor.clone()) - (r_divident * s_divisor.clone()))
                 / (s_divisor * r_divisor)
         }
     }
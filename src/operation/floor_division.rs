@@ -0,0 +1,529 @@
+use std::{
+    array::from_fn,
+    fmt::Display,
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+};
+
+use num_traits::Float;
+
+use super::{
+    addition::Addition,
+    division::Division,
+    multiplication::Multiplication,
+    negation::Negation,
+    traits::{
+        ApplyRule, Calc, CalcBatch, CalcFloat, CanAddNumWell, Convert, Diff, SetVars,
+        ToPolynomialCoefficients, ToPrefixNotation, Unify, Walk, WalkMut,
+    },
+    trim_coefficients, Operation,
+};
+
+#[derive(Debug, PartialEq, PartialOrd, Default, Clone)]
+pub struct FloorDivision<
+    Num: Add<Output = Num>
+        + Sub<Output = Num>
+        + Mul<Output = Num>
+        + Div<Output = Num>
+        + Rem<Output = Num>
+        + Clone
+        + Default
+        + PartialOrd,
+> {
+    pub divident: Box<Operation<Num>>,
+    pub divisor: Box<Operation<Num>>,
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > FloorDivision<Num>
+{
+    /// Divides the divident by the divisor, rounding towards negative infinity.
+    /// The result is calculated immediately if both are rational constants.
+    pub fn divide(divident: Operation<Num>, divisor: Operation<Num>) -> Operation<Num> {
+        if divisor == Operation::default() {
+            panic!("Cannot divide by zero.");
+        }
+        if divident == Operation::default() {
+            return divident;
+        }
+
+        match (as_fraction(&divident), as_fraction(&divisor)) {
+            (
+                Some((divident_negative, divident_numer, divident_denom)),
+                Some((divisor_negative, divisor_numer, divisor_denom)),
+            ) => {
+                // (a/b) / (c/d) = (a*d) / (b*c)
+                let numer = divident_numer * divisor_denom;
+                let denom = divident_denom * divisor_numer;
+
+                let remainder = numer.clone() % denom.clone();
+                let quotient = (numer.clone() - remainder.clone()) / denom.clone();
+
+                let quotient = if divident_negative == divisor_negative {
+                    return Operation::from(quotient);
+                } else if remainder == Num::default() {
+                    quotient
+                } else {
+                    // rounding away from zero: (numer + denom - remainder) / denom
+                    (numer + denom.clone() - remainder) / denom
+                };
+
+                // negative numbers can only be represented directly if the number type is signed
+                let mut signed = false;
+                for operation in [&divident, &divisor] {
+                    operation.walk(&mut |op| {
+                        if let Operation::Number(num) = op {
+                            signed |= num.value < Num::default();
+                        }
+                    });
+                }
+
+                if signed {
+                    Operation::from(Num::default() - quotient)
+                } else {
+                    -Operation::from(quotient)
+                }
+            }
+            _ => Operation::FloorDivision(FloorDivision {
+                divident: Box::new(divident),
+                divisor: Box::new(divisor),
+            }),
+        }
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > Convert<Num> for FloorDivision<Num>
+{
+    fn convert<
+        T: Add<Output = T>
+            + Sub<Output = T>
+            + Mul<Output = T>
+            + Div<Output = T>
+            + Rem<Output = T>
+            + Clone
+            + Default
+            + PartialOrd,
+        F: FnMut(Num) -> T,
+    >(
+        self,
+        f: &mut F,
+    ) -> Operation<T> {
+        Operation::FloorDivision(FloorDivision {
+            divident: Box::new(self.divident.convert(f)),
+            divisor: Box::new(self.divisor.convert(f)),
+        })
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > CanAddNumWell for FloorDivision<Num>
+{
+    fn can_add_number_well(&self) -> bool {
+        false
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > SetVars<Num> for FloorDivision<Num>
+{
+    fn set_vars(&self, vars: &[(&str, &Operation<Num>)]) -> Operation<Num> {
+        FloorDivision::divide(self.divident.set_vars(vars), self.divisor.set_vars(vars))
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > WalkMut<Num> for FloorDivision<Num>
+{
+    fn walk_mut<F: FnMut(&mut Operation<Num>)>(&mut self, f: &mut F) {
+        self.divident.walk_mut(f);
+        self.divisor.walk_mut(f);
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > Walk<Num> for FloorDivision<Num>
+{
+    fn walk<F: FnMut(&Operation<Num>)>(&self, f: &mut F) {
+        self.divident.walk(f);
+        self.divisor.walk(f);
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > Unify<Num> for FloorDivision<Num>
+{
+    fn unify(&self, other: &Operation<Num>, bindings: &mut Vec<(String, Operation<Num>)>) -> bool {
+        match other {
+            Operation::FloorDivision(other) => {
+                self.divident.unify(&other.divident, bindings)
+                    && self.divisor.unify(&other.divisor, bindings)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > ApplyRule<Num> for FloorDivision<Num>
+{
+    fn apply_rule(
+        &self,
+        pattern: &Operation<Num>,
+        replacement: &Operation<Num>,
+    ) -> Option<Operation<Num>> {
+        if let Some(divident) = self.divident.apply_rule(pattern, replacement) {
+            Some(FloorDivision::divide(divident, (*self.divisor).clone()))
+        } else {
+            self.divisor
+                .apply_rule(pattern, replacement)
+                .map(|divisor| FloorDivision::divide((*self.divident).clone(), divisor))
+        }
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd
+            + From<u8>,
+    > ToPolynomialCoefficients<Num> for FloorDivision<Num>
+{
+    fn to_polynomial_coefficients(&self, var: &str) -> Option<Vec<Operation<Num>>> {
+        let mut divident = self.divident.to_polynomial_coefficients(var)?;
+        let mut divisor = self.divisor.to_polynomial_coefficients(var)?;
+        trim_coefficients(&mut divident);
+        trim_coefficients(&mut divisor);
+        if divident.len() > 1 || divisor.len() > 1 {
+            None
+        } else {
+            Some(vec![Operation::FloorDivision(self.clone())])
+        }
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd
+            + From<u8>,
+    > Diff<Num> for FloorDivision<Num>
+{
+    fn diff(&self, _var: &str) -> Operation<Num> {
+        // the result only changes at discrete points, so the derivative is zero everywhere else
+        Operation::default()
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd
+            + Display,
+    > ToPrefixNotation for FloorDivision<Num>
+{
+    fn to_prefix_notation(&self) -> String {
+        format!(
+            "(// {} {})",
+            self.divident.to_prefix_notation(),
+            self.divisor.to_prefix_notation()
+        )
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > Calc<Num> for FloorDivision<Num>
+{
+    fn calc<
+        Output: Add<Output = Output>
+            + Sub<Output = Output>
+            + Mul<Output = Output>
+            + Div<Output = Output>
+            + Neg<Output = Output>
+            + From<Num>,
+    >(
+        &self,
+    ) -> Output {
+        panic!("Cannot calculate floor division of irrational terms without floating point output, use `calc_float` instead.")
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > CalcFloat<Num> for FloorDivision<Num>
+{
+    fn calc_float<Output: Float + From<Num>>(&self) -> Output {
+        (self.divident.calc_float::<Output>() / self.divisor.calc_float::<Output>()).floor()
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > CalcBatch<Num> for FloorDivision<Num>
+{
+    fn calc_batch<Output: Float + From<Num>, const N: usize>(
+        &self,
+        var: &str,
+        values: &[Output; N],
+    ) -> [Output; N] {
+        let divident = self.divident.calc_batch(var, values);
+        let divisor = self.divisor.calc_batch(var, values);
+        from_fn(|lane| (divident[lane] / divisor[lane]).floor())
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > Add for FloorDivision<Num>
+{
+    type Output = Operation<Num>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Operation::Addition(Addition {
+            summands: vec![
+                Operation::FloorDivision(self),
+                Operation::FloorDivision(rhs),
+            ],
+        })
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > Mul for FloorDivision<Num>
+{
+    type Output = Operation<Num>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Operation::Multiplication(Multiplication {
+            multipliers: vec![
+                Operation::FloorDivision(self),
+                Operation::FloorDivision(rhs),
+            ],
+        })
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > Div for FloorDivision<Num>
+{
+    type Output = Operation<Num>;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        Operation::Division(Division {
+            divident: Box::new(Operation::FloorDivision(self)),
+            divisor: Box::new(Operation::FloorDivision(rhs)),
+        })
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > Sub for FloorDivision<Num>
+{
+    type Output = Operation<Num>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        if self == rhs {
+            Operation::default()
+        } else {
+            Operation::Addition(Addition {
+                summands: vec![
+                    Operation::FloorDivision(self),
+                    -Operation::FloorDivision(rhs),
+                ],
+            })
+        }
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > Neg for FloorDivision<Num>
+{
+    type Output = Operation<Num>;
+
+    fn neg(self) -> Self::Output {
+        Operation::Negation(Negation {
+            value: Box::new(Operation::FloorDivision(self)),
+        })
+    }
+}
+
+/// Splits a non-zero rational constant into its sign, its numerator and its denominator.
+/// Both numerator and denominator are positive.
+fn as_fraction<
+    Num: Add<Output = Num>
+        + Sub<Output = Num>
+        + Mul<Output = Num>
+        + Div<Output = Num>
+        + Rem<Output = Num>
+        + Clone
+        + Default
+        + PartialOrd,
+>(
+    operation: &Operation<Num>,
+) -> Option<(bool, Num, Num)> {
+    match operation {
+        Operation::Number(num) if num.value == Num::default() => None,
+        Operation::Number(num) => {
+            // dividing the number by itself yields one without requiring further bounds
+            let one = num.value.clone() / num.value.clone();
+            if num.value < Num::default() {
+                Some((true, Num::default() - num.value.clone(), one))
+            } else {
+                Some((false, num.value.clone(), one))
+            }
+        }
+        Operation::Division(div) => {
+            let (divident_negative, divident_numer, divident_denom) = as_fraction(&div.divident)?;
+            let (divisor_negative, divisor_numer, divisor_denom) = as_fraction(&div.divisor)?;
+            Some((
+                divident_negative != divisor_negative,
+                divident_numer * divisor_denom,
+                divident_denom * divisor_numer,
+            ))
+        }
+        Operation::Negation(neg) => {
+            let (negative, numer, denom) = as_fraction(&neg.value)?;
+            Some((!negative, numer, denom))
+        }
+        _ => None,
+    }
+}
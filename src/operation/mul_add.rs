@@ -0,0 +1,477 @@
+use std::{
+    array::from_fn,
+    fmt::Display,
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+};
+
+use num_traits::Float;
+
+use super::{
+    addition::{add_polynomials, Addition},
+    division::Division,
+    multiplication::{multiply_polynomials, Multiplication},
+    multiply_skipping_one,
+    negation::Negation,
+    traits::{
+        ApplyRule, Calc, CalcBatch, CalcFloat, CanAddNumWell, Convert, Diff, SetVars,
+        ToPolynomialCoefficients, ToPrefixNotation, Unify, Walk, WalkMut,
+    },
+    Operation,
+};
+
+#[derive(Debug, PartialEq, PartialOrd, Default, Clone)]
+pub struct MulAdd<
+    Num: Add<Output = Num>
+        + Sub<Output = Num>
+        + Mul<Output = Num>
+        + Div<Output = Num>
+        + Rem<Output = Num>
+        + Clone
+        + Default
+        + PartialOrd,
+> {
+    pub multiplicand: Box<Operation<Num>>,
+    pub multiplier: Box<Operation<Num>>,
+    pub summand: Box<Operation<Num>>,
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > MulAdd<Num>
+{
+    /// Multiplies the multiplicand by the multiplier and adds the summand.
+    /// The result is only kept as a fused operation if the product cannot be calculated immediately.
+    pub fn fuse(
+        multiplicand: Operation<Num>,
+        multiplier: Operation<Num>,
+        summand: Operation<Num>,
+    ) -> Operation<Num> {
+        match (&multiplicand, &multiplier) {
+            (Operation::Number(_), Operation::Number(_)) => multiplicand * multiplier + summand,
+            _ if multiplicand == Operation::default() || multiplier == Operation::default() => {
+                summand
+            }
+            _ if summand == Operation::default() => multiplicand * multiplier,
+            _ => Operation::MulAdd(MulAdd {
+                multiplicand: Box::new(multiplicand),
+                multiplier: Box::new(multiplier),
+                summand: Box::new(summand),
+            }),
+        }
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > Convert<Num> for MulAdd<Num>
+{
+    fn convert<
+        T: Add<Output = T>
+            + Sub<Output = T>
+            + Mul<Output = T>
+            + Div<Output = T>
+            + Rem<Output = T>
+            + Clone
+            + Default
+            + PartialOrd,
+        F: FnMut(Num) -> T,
+    >(
+        self,
+        f: &mut F,
+    ) -> Operation<T> {
+        Operation::MulAdd(MulAdd {
+            multiplicand: Box::new(self.multiplicand.convert(f)),
+            multiplier: Box::new(self.multiplier.convert(f)),
+            summand: Box::new(self.summand.convert(f)),
+        })
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > CanAddNumWell for MulAdd<Num>
+{
+    fn can_add_number_well(&self) -> bool {
+        false
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > SetVars<Num> for MulAdd<Num>
+{
+    fn set_vars(&self, vars: &[(&str, &Operation<Num>)]) -> Operation<Num> {
+        MulAdd::fuse(
+            self.multiplicand.set_vars(vars),
+            self.multiplier.set_vars(vars),
+            self.summand.set_vars(vars),
+        )
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > WalkMut<Num> for MulAdd<Num>
+{
+    fn walk_mut<F: FnMut(&mut Operation<Num>)>(&mut self, f: &mut F) {
+        self.multiplicand.walk_mut(f);
+        self.multiplier.walk_mut(f);
+        self.summand.walk_mut(f);
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > Walk<Num> for MulAdd<Num>
+{
+    fn walk<F: FnMut(&Operation<Num>)>(&self, f: &mut F) {
+        self.multiplicand.walk(f);
+        self.multiplier.walk(f);
+        self.summand.walk(f);
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > Unify<Num> for MulAdd<Num>
+{
+    fn unify(&self, other: &Operation<Num>, bindings: &mut Vec<(String, Operation<Num>)>) -> bool {
+        match other {
+            Operation::MulAdd(other) => {
+                self.multiplicand.unify(&other.multiplicand, bindings)
+                    && self.multiplier.unify(&other.multiplier, bindings)
+                    && self.summand.unify(&other.summand, bindings)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > ApplyRule<Num> for MulAdd<Num>
+{
+    fn apply_rule(
+        &self,
+        pattern: &Operation<Num>,
+        replacement: &Operation<Num>,
+    ) -> Option<Operation<Num>> {
+        if let Some(multiplicand) = self.multiplicand.apply_rule(pattern, replacement) {
+            Some(MulAdd::fuse(
+                multiplicand,
+                (*self.multiplier).clone(),
+                (*self.summand).clone(),
+            ))
+        } else if let Some(multiplier) = self.multiplier.apply_rule(pattern, replacement) {
+            Some(MulAdd::fuse(
+                (*self.multiplicand).clone(),
+                multiplier,
+                (*self.summand).clone(),
+            ))
+        } else {
+            self.summand
+                .apply_rule(pattern, replacement)
+                .map(|summand| {
+                    MulAdd::fuse(
+                        (*self.multiplicand).clone(),
+                        (*self.multiplier).clone(),
+                        summand,
+                    )
+                })
+        }
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd
+            + From<u8>,
+    > ToPolynomialCoefficients<Num> for MulAdd<Num>
+{
+    fn to_polynomial_coefficients(&self, var: &str) -> Option<Vec<Operation<Num>>> {
+        let multiplicand = self.multiplicand.to_polynomial_coefficients(var)?;
+        let multiplier = self.multiplier.to_polynomial_coefficients(var)?;
+        let summand = self.summand.to_polynomial_coefficients(var)?;
+        Some(add_polynomials(
+            multiply_polynomials(&multiplicand, &multiplier),
+            summand,
+        ))
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd
+            + From<u8>,
+    > Diff<Num> for MulAdd<Num>
+{
+    fn diff(&self, var: &str) -> Operation<Num> {
+        // product rule for the multiplication, plus the derivative of the summand
+        multiply_skipping_one(self.multiplicand.diff(var), (*self.multiplier).clone())
+            + multiply_skipping_one((*self.multiplicand).clone(), self.multiplier.diff(var))
+            + self.summand.diff(var)
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd
+            + Display,
+    > ToPrefixNotation for MulAdd<Num>
+{
+    fn to_prefix_notation(&self) -> String {
+        format!(
+            "(fma {} {} {})",
+            self.multiplicand.to_prefix_notation(),
+            self.multiplier.to_prefix_notation(),
+            self.summand.to_prefix_notation()
+        )
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > Calc<Num> for MulAdd<Num>
+{
+    fn calc<
+        Output: Add<Output = Output>
+            + Sub<Output = Output>
+            + Mul<Output = Output>
+            + Div<Output = Output>
+            + Neg<Output = Output>
+            + From<Num>,
+    >(
+        &self,
+    ) -> Output {
+        self.multiplicand.calc::<Output>() * self.multiplier.calc::<Output>()
+            + self.summand.calc::<Output>()
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > CalcFloat<Num> for MulAdd<Num>
+{
+    fn calc_float<Output: Float + From<Num>>(&self) -> Output {
+        self.multiplicand.calc_float::<Output>().mul_add(
+            self.multiplier.calc_float::<Output>(),
+            self.summand.calc_float::<Output>(),
+        )
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > CalcBatch<Num> for MulAdd<Num>
+{
+    fn calc_batch<Output: Float + From<Num>, const N: usize>(
+        &self,
+        var: &str,
+        values: &[Output; N],
+    ) -> [Output; N] {
+        let multiplicand = self.multiplicand.calc_batch(var, values);
+        let multiplier = self.multiplier.calc_batch(var, values);
+        let summand = self.summand.calc_batch(var, values);
+        from_fn(|lane| multiplicand[lane].mul_add(multiplier[lane], summand[lane]))
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > Add for MulAdd<Num>
+{
+    type Output = Operation<Num>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Operation::Addition(Addition {
+            summands: vec![Operation::MulAdd(self), Operation::MulAdd(rhs)],
+        })
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > Mul for MulAdd<Num>
+{
+    type Output = Operation<Num>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Operation::Multiplication(Multiplication {
+            multipliers: vec![Operation::MulAdd(self), Operation::MulAdd(rhs)],
+        })
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > Div for MulAdd<Num>
+{
+    type Output = Operation<Num>;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        Operation::Division(Division {
+            divident: Box::new(Operation::MulAdd(self)),
+            divisor: Box::new(Operation::MulAdd(rhs)),
+        })
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > Sub for MulAdd<Num>
+{
+    type Output = Operation<Num>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        if self == rhs {
+            Operation::default()
+        } else {
+            Operation::Addition(Addition {
+                summands: vec![Operation::MulAdd(self), -Operation::MulAdd(rhs)],
+            })
+        }
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > Neg for MulAdd<Num>
+{
+    type Output = Operation<Num>;
+
+    fn neg(self) -> Self::Output {
+        Operation::Negation(Negation {
+            value: Box::new(Operation::MulAdd(self)),
+        })
+    }
+}
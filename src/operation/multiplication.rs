@@ -1,10 +1,19 @@
-use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+use std::{
+    fmt::Display,
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+};
+
+use num_traits::Float;
 
 use super::{
     addition::Addition,
     division::Division,
+    multiply_skipping_one,
     negation::Negation,
-    traits::{Calc, CanAddNumWell, Convert, SetVars},
+    traits::{
+        ApplyRule, Calc, CalcBatch, CalcFloat, CanAddNumWell, Convert, Diff, SetVars,
+        ToPolynomialCoefficients, ToPrefixNotation, Unify, Walk, WalkMut,
+    },
     Operation,
 };
 
@@ -22,6 +31,27 @@ pub struct Multiplication<
     pub multipliers: Vec<Operation<Num>>,
 }
 
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > Multiplication<Num>
+{
+    /// Wraps the multiplication into an operation, unwrapping it if there is only a single multiplier.
+    fn into_operation(mut self) -> Operation<Num> {
+        if self.multipliers.len() == 1 {
+            self.multipliers.remove(0)
+        } else {
+            Operation::Multiplication(self)
+        }
+    }
+}
+
 impl<
         Num: Add<Output = Num>
             + Sub<Output = Num>
@@ -41,16 +71,17 @@ impl<
             + Rem<Output = T>
             + Clone
             + Default
-            + PartialOrd
-            + From<Num>,
+            + PartialOrd,
+        F: FnMut(Num) -> T,
     >(
         self,
+        f: &mut F,
     ) -> Operation<T> {
         Operation::Multiplication(Multiplication {
             multipliers: self
                 .multipliers
                 .into_iter()
-                .map(|multiplier| multiplier.convert())
+                .map(|multiplier| multiplier.convert(f))
                 .collect(),
         })
     }
@@ -92,6 +123,176 @@ impl<
     }
 }
 
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > WalkMut<Num> for Multiplication<Num>
+{
+    fn walk_mut<F: FnMut(&mut Operation<Num>)>(&mut self, f: &mut F) {
+        for multiplier in &mut self.multipliers {
+            multiplier.walk_mut(f);
+        }
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > Walk<Num> for Multiplication<Num>
+{
+    fn walk<F: FnMut(&Operation<Num>)>(&self, f: &mut F) {
+        for multiplier in &self.multipliers {
+            multiplier.walk(f);
+        }
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > Unify<Num> for Multiplication<Num>
+{
+    fn unify(&self, other: &Operation<Num>, bindings: &mut Vec<(String, Operation<Num>)>) -> bool {
+        match other {
+            Operation::Multiplication(other) => {
+                self.multipliers.len() == other.multipliers.len()
+                    && self
+                        .multipliers
+                        .iter()
+                        .zip(&other.multipliers)
+                        .all(|(multiplier, other)| multiplier.unify(other, bindings))
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > ApplyRule<Num> for Multiplication<Num>
+{
+    fn apply_rule(
+        &self,
+        pattern: &Operation<Num>,
+        replacement: &Operation<Num>,
+    ) -> Option<Operation<Num>> {
+        for i in 0..self.multipliers.len() {
+            if let Some(replaced) = self.multipliers[i].apply_rule(pattern, replacement) {
+                let mut multipliers = self.multipliers.clone();
+                multipliers[i] = replaced;
+                let mut result = multipliers.remove(0);
+                for multiplier in multipliers {
+                    result = result * multiplier;
+                }
+                return Some(result);
+            }
+        }
+        None
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd
+            + From<u8>,
+    > ToPolynomialCoefficients<Num> for Multiplication<Num>
+{
+    fn to_polynomial_coefficients(&self, var: &str) -> Option<Vec<Operation<Num>>> {
+        let mut result = vec![Operation::from(Num::from(1))];
+        for multiplier in &self.multipliers {
+            result = multiply_polynomials(&result, &multiplier.to_polynomial_coefficients(var)?);
+        }
+        Some(result)
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd
+            + From<u8>,
+    > Diff<Num> for Multiplication<Num>
+{
+    fn diff(&self, var: &str) -> Operation<Num> {
+        // product rule: the derivative of each multiplier times all other multipliers
+        let mut result = Operation::default();
+        for (i, multiplier) in self.multipliers.iter().enumerate() {
+            let derivative = multiplier.diff(var);
+            if derivative == Operation::default() {
+                continue;
+            }
+            let others = self
+                .multipliers
+                .iter()
+                .enumerate()
+                .filter(|(j, _)| *j != i)
+                .fold(Operation::from(Num::from(1)), |acc, (_, other)| {
+                    multiply_skipping_one(acc, other.clone())
+                });
+            result = result + multiply_skipping_one(others, derivative);
+        }
+        result
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd
+            + Display,
+    > ToPrefixNotation for Multiplication<Num>
+{
+    fn to_prefix_notation(&self) -> String {
+        let multipliers: Vec<String> = self
+            .multipliers
+            .iter()
+            .map(|multiplier| multiplier.to_prefix_notation())
+            .collect();
+        format!("(* {})", multipliers.join(" "))
+    }
+}
+
 impl<
         Num: Add<Output = Num>
             + Sub<Output = Num>
@@ -121,6 +322,53 @@ impl<
     }
 }
 
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > CalcFloat<Num> for Multiplication<Num>
+{
+    fn calc_float<Output: Float + From<Num>>(&self) -> Output {
+        let mut result = self.multipliers[0].calc_float();
+        for i in 1..self.multipliers.len() {
+            result = result * self.multipliers[i].calc_float();
+        }
+        result
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > CalcBatch<Num> for Multiplication<Num>
+{
+    fn calc_batch<Output: Float + From<Num>, const N: usize>(
+        &self,
+        var: &str,
+        values: &[Output; N],
+    ) -> [Output; N] {
+        let mut result = self.multipliers[0].calc_batch(var, values);
+        for i in 1..self.multipliers.len() {
+            let lanes = self.multipliers[i].calc_batch(var, values);
+            for lane in 0..N {
+                result[lane] = result[lane] * lanes[lane];
+            }
+        }
+        result
+    }
+}
+
 impl<
         Num: Add<Output = Num>
             + Sub<Output = Num>
@@ -135,23 +383,26 @@ impl<
     type Output = Operation<Num>;
 
     fn add(mut self, mut rhs: Self) -> Self::Output {
+        let original = (self.clone(), rhs.clone());
         let mut on_both_sides = Vec::new();
 
         // TODO: optimize
         for i in (0..self.multipliers.len()).rev() {
-            for j in (i..rhs.multipliers.len()).rev() {
+            for j in (0..rhs.multipliers.len()).rev() {
                 if self.multipliers[i] == rhs.multipliers[j] {
                     on_both_sides.push(self.multipliers.remove(i));
                     rhs.multipliers.remove(j);
+                    break;
                 }
             }
         }
 
-        if on_both_sides.is_empty() {
+        // a side without any multipliers left would be one, which cannot be represented generically
+        if on_both_sides.is_empty() || self.multipliers.is_empty() || rhs.multipliers.is_empty() {
             Operation::Addition(Addition {
                 summands: vec![
-                    Operation::Multiplication(self),
-                    Operation::Multiplication(rhs),
+                    Operation::Multiplication(original.0),
+                    Operation::Multiplication(original.1),
                 ],
             })
         } else {
@@ -208,17 +459,32 @@ impl<
     type Output = Operation<Num>;
 
     fn div(mut self, mut rhs: Self) -> Self::Output {
+        let original = (self.clone(), rhs.clone());
+
         for i in (0..self.multipliers.len()).rev() {
-            for j in (i..rhs.multipliers.len()).rev() {
+            for j in (0..rhs.multipliers.len()).rev() {
                 if self.multipliers[i] == rhs.multipliers[j] {
                     self.multipliers.remove(i);
                     rhs.multipliers.remove(j);
+                    break;
                 }
             }
         }
+
+        // a divident without any multipliers left would be one, which cannot be represented generically
+        if self.multipliers.is_empty() {
+            return Operation::Division(Division {
+                divident: Box::new(Operation::Multiplication(original.0)),
+                divisor: Box::new(Operation::Multiplication(original.1)),
+            });
+        }
+        if rhs.multipliers.is_empty() {
+            return self.into_operation();
+        }
+
         Operation::Division(Division {
-            divident: Box::new(Operation::Multiplication(self)),
-            divisor: Box::new(Operation::Multiplication(rhs)),
+            divident: Box::new(self.into_operation()),
+            divisor: Box::new(rhs.into_operation()),
         })
     }
 }
@@ -237,21 +503,24 @@ impl<
     type Output = Operation<Num>;
 
     fn sub(mut self, mut rhs: Self) -> Self::Output {
+        let original = (self.clone(), rhs.clone());
         let mut on_both_sides = Vec::new();
 
         // TODO: optimize
         for i in (0..self.multipliers.len()).rev() {
-            for j in (i..rhs.multipliers.len()).rev() {
+            for j in (0..rhs.multipliers.len()).rev() {
                 if self.multipliers[i] == rhs.multipliers[j] {
                     on_both_sides.push(self.multipliers.remove(i));
                     rhs.multipliers.remove(j);
+                    break;
                 }
             }
         }
 
-        if on_both_sides.is_empty() {
+        // a side without any multipliers left would be one, which cannot be represented generically
+        if on_both_sides.is_empty() || self.multipliers.is_empty() || rhs.multipliers.is_empty() {
             Operation::Addition(Addition {
-                summands: vec![Operation::Multiplication(self), -rhs],
+                summands: vec![Operation::Multiplication(original.0), -original.1],
             })
         } else {
             on_both_sides.push(Operation::Addition(Addition {
@@ -290,3 +559,30 @@ impl<
         })
     }
 }
+
+/// Multiplies two polynomials, given by their coefficients (starting with the constant one).
+pub fn multiply_polynomials<
+    Num: Add<Output = Num>
+        + Sub<Output = Num>
+        + Mul<Output = Num>
+        + Div<Output = Num>
+        + Rem<Output = Num>
+        + Clone
+        + Default
+        + PartialOrd,
+>(
+    first: &[Operation<Num>],
+    second: &[Operation<Num>],
+) -> Vec<Operation<Num>> {
+    if first.is_empty() || second.is_empty() {
+        return Vec::new();
+    }
+
+    let mut result = vec![Operation::default(); first.len() + second.len() - 1];
+    for (i, a) in first.iter().enumerate() {
+        for (j, b) in second.iter().enumerate() {
+            result[i + j] = std::mem::take(&mut result[i + j]) + a.clone() * b.clone();
+        }
+    }
+    result
+}
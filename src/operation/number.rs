@@ -1,9 +1,15 @@
-use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+use std::{
+    fmt::Display,
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+};
 
 use super::{
     division::Division,
     negation::Negation,
-    traits::{CanAddNumWell, Convert, SetVars},
+    traits::{
+        ApplyRule, CanAddNumWell, Convert, Diff, SetVars, ToPolynomialCoefficients,
+        ToPrefixNotation, Unify, Walk, WalkMut,
+    },
     Operation,
 };
 
@@ -41,13 +47,14 @@ impl<
             + Rem<Output = T>
             + Clone
             + Default
-            + PartialOrd
-            + From<Num>,
+            + PartialOrd,
+        F: FnMut(Num) -> T,
     >(
         self,
+        f: &mut F,
     ) -> Operation<T> {
         Operation::Number(Number {
-            value: T::from(self.value),
+            value: f(self.value),
         })
     }
 }
@@ -84,6 +91,124 @@ impl<
     }
 }
 
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > WalkMut<Num> for Number<Num>
+{
+    fn walk_mut<F: FnMut(&mut Operation<Num>)>(&mut self, _f: &mut F) {}
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > Walk<Num> for Number<Num>
+{
+    fn walk<F: FnMut(&Operation<Num>)>(&self, _f: &mut F) {}
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > Unify<Num> for Number<Num>
+{
+    fn unify(&self, other: &Operation<Num>, _bindings: &mut Vec<(String, Operation<Num>)>) -> bool {
+        match other {
+            Operation::Number(other) => self == other,
+            _ => false,
+        }
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > ApplyRule<Num> for Number<Num>
+{
+    fn apply_rule(
+        &self,
+        _pattern: &Operation<Num>,
+        _replacement: &Operation<Num>,
+    ) -> Option<Operation<Num>> {
+        None
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd
+            + From<u8>,
+    > ToPolynomialCoefficients<Num> for Number<Num>
+{
+    fn to_polynomial_coefficients(&self, _var: &str) -> Option<Vec<Operation<Num>>> {
+        Some(vec![Operation::Number(self.clone())])
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd
+            + From<u8>,
+    > Diff<Num> for Number<Num>
+{
+    fn diff(&self, _var: &str) -> Operation<Num> {
+        Operation::default()
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd
+            + Display,
+    > ToPrefixNotation for Number<Num>
+{
+    fn to_prefix_notation(&self) -> String {
+        self.value.to_string()
+    }
+}
+
 impl<
         Num: Add<Output = Num>
             + Sub<Output = Num>
@@ -208,7 +333,7 @@ impl<
     }
 }
 
-fn greatest_common_divisor<
+pub fn greatest_common_divisor<
     Num: Add<Output = Num>
         + Sub<Output = Num>
         + Mul<Output = Num>
@@ -233,3 +358,105 @@ fn greatest_common_divisor<
 
     bigger
 }
+
+/// Finds the exact `n`-th root of the value, if there is one.
+pub fn exact_root<
+    Num: Add<Output = Num>
+        + Sub<Output = Num>
+        + Mul<Output = Num>
+        + Div<Output = Num>
+        + Rem<Output = Num>
+        + Clone
+        + Default
+        + PartialOrd
+        + From<u8>,
+>(
+    value: Num,
+    n: u32,
+) -> Option<Num> {
+    exact_root_with_one(value, n, Num::from(1))
+}
+
+/// Finds the exact `n`-th root of the value, if there is one. Used where `Num` cannot be created from a `u8`.
+pub fn exact_root_with_one<
+    Num: Add<Output = Num>
+        + Sub<Output = Num>
+        + Mul<Output = Num>
+        + Div<Output = Num>
+        + Rem<Output = Num>
+        + Clone
+        + Default
+        + PartialOrd,
+>(
+    value: Num,
+    n: u32,
+    one: Num,
+) -> Option<Num> {
+    if value <= one || n == 0 {
+        return if value == Num::default() || value == one {
+            Some(value)
+        } else {
+            None
+        };
+    }
+
+    // binary search, comparing without overflowing
+
+    let (mut low, mut high) = (one.clone(), value.clone());
+
+    for _ in 0..256 {
+        if low > high {
+            break;
+        }
+        let middle = low.clone() + (high.clone() - low.clone()) / (one.clone() + one.clone());
+
+        let mut power = one.clone();
+        let mut too_big = false;
+        // powers of one never grow, so they would not stop the loop early
+        for _ in 0..if middle == one { 0 } else { n } {
+            if power > value.clone() / middle.clone() {
+                too_big = true;
+                break;
+            }
+            power = power * middle.clone();
+        }
+
+        if !too_big && power == value {
+            return Some(middle);
+        } else if too_big || power > value {
+            high = middle - one.clone();
+        } else {
+            low = middle + one.clone();
+        }
+    }
+
+    None
+}
+
+/// Converts an unsigned integer into the number type, byte by byte.
+pub fn number_from_u32<
+    Num: Add<Output = Num>
+        + Sub<Output = Num>
+        + Mul<Output = Num>
+        + Div<Output = Num>
+        + Rem<Output = Num>
+        + Clone
+        + Default
+        + PartialOrd
+        + From<u8>,
+>(
+    value: u32,
+) -> Num {
+    value
+        .to_be_bytes()
+        .into_iter()
+        .skip_while(|byte| *byte == 0)
+        .fold(Num::default(), |acc, byte| {
+            if acc == Num::default() {
+                Num::from(byte)
+            } else {
+                // multiplying by 16 twice, as 256 does not fit into a byte
+                acc * Num::from(16) * Num::from(16) + Num::from(byte)
+            }
+        })
+}
@@ -0,0 +1,595 @@
+use std::{
+    array::from_fn,
+    fmt::Display,
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+};
+
+use num_traits::Float;
+
+use super::{
+    addition::Addition,
+    division::Division,
+    multiplication::Multiplication,
+    negation::Negation,
+    traits::{
+        ApplyRule, Calc, CalcBatch, CalcFloat, CanAddNumWell, Convert, Diff, SetVars,
+        ToPolynomialCoefficients, ToPrefixNotation, Unify, Walk, WalkMut,
+    },
+    trim_coefficients, Operation,
+};
+
+#[derive(Debug, PartialEq, PartialOrd, Default, Clone)]
+pub struct PowMod<
+    Num: Add<Output = Num>
+        + Sub<Output = Num>
+        + Mul<Output = Num>
+        + Div<Output = Num>
+        + Rem<Output = Num>
+        + Clone
+        + Default
+        + PartialOrd,
+> {
+    pub base: Box<Operation<Num>>,
+    pub exp: Box<Operation<Num>>,
+    pub modulus: Box<Operation<Num>>,
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > PowMod<Num>
+{
+    /// Raises the base to the power of the exponent, modulo the modulus.
+    /// The result is calculated immediately if all three are numbers.
+    pub fn reduce(
+        base: Operation<Num>,
+        exp: Operation<Num>,
+        modulus: Operation<Num>,
+    ) -> Operation<Num> {
+        if modulus == Operation::default() {
+            panic!("Cannot divide by zero.");
+        }
+
+        match (&base, &exp, &modulus) {
+            (Operation::Number(base), Operation::Number(exp), Operation::Number(modulus)) => {
+                Operation::from(pow_mod(
+                    base.value.clone(),
+                    exp.value.clone(),
+                    modulus.value.clone(),
+                ))
+            }
+            _ => Operation::PowMod(PowMod {
+                base: Box::new(base),
+                exp: Box::new(exp),
+                modulus: Box::new(modulus),
+            }),
+        }
+    }
+}
+
+/// Calculates `base` to the power of `exp`, modulo `modulus`, using square-and-multiply.
+///
+/// The result lies between zero and the modulus.
+fn pow_mod<
+    Num: Add<Output = Num>
+        + Sub<Output = Num>
+        + Mul<Output = Num>
+        + Div<Output = Num>
+        + Rem<Output = Num>
+        + Clone
+        + PartialOrd,
+>(
+    base: Num,
+    mut exp: Num,
+    modulus: Num,
+) -> Num {
+    let zero = modulus.clone() - modulus.clone();
+    if exp < zero {
+        panic!("Cannot raise to a negative power modulo a number.");
+    }
+
+    let one = modulus.clone() / modulus.clone();
+    let two = one.clone() + one.clone();
+    if exp.clone() % one.clone() != zero {
+        panic!("Cannot raise to a non-integral power modulo a number.");
+    }
+
+    // calculating within the range of the positive modulus, so that no intermediate result exceeds it
+    let negative_modulus = modulus < zero;
+    let modulus = if negative_modulus {
+        zero.clone() - modulus
+    } else {
+        modulus
+    };
+    let mut base = base % modulus.clone();
+    if base < zero {
+        base = base + modulus.clone();
+    }
+
+    let mut result = one % modulus.clone();
+    while exp > zero {
+        let bit = exp.clone() % two.clone();
+        if bit != zero {
+            result = mul_mod(result, base.clone(), &modulus);
+        }
+        exp = (exp - bit) / two.clone();
+        base = mul_mod(base.clone(), base, &modulus);
+    }
+
+    // the result takes the sign of the modulus
+    if negative_modulus && result != zero {
+        result = result - modulus;
+    }
+    result
+}
+
+/// Multiplies two numbers between zero and the modulus, modulo the modulus, using double-and-add.
+///
+/// Unlike multiplying directly, this does not overflow for moduli up to the largest number.
+fn mul_mod<
+    Num: Add<Output = Num>
+        + Sub<Output = Num>
+        + Mul<Output = Num>
+        + Div<Output = Num>
+        + Rem<Output = Num>
+        + Clone
+        + PartialOrd,
+>(
+    mut lhs: Num,
+    mut rhs: Num,
+    modulus: &Num,
+) -> Num {
+    let zero = modulus.clone() - modulus.clone();
+    let one = modulus.clone() / modulus.clone();
+    let two = one.clone() + one;
+
+    let mut result = zero.clone();
+    while rhs > zero {
+        let bit = rhs.clone() % two.clone();
+        if bit != zero {
+            result = add_mod(result, lhs.clone(), modulus);
+        }
+        rhs = (rhs - bit) / two.clone();
+        lhs = add_mod(lhs.clone(), lhs, modulus);
+    }
+    result
+}
+
+/// Adds two numbers between zero and the modulus, modulo the modulus, without exceeding the modulus.
+fn add_mod<
+    Num: Add<Output = Num>
+        + Sub<Output = Num>
+        + Mul<Output = Num>
+        + Div<Output = Num>
+        + Rem<Output = Num>
+        + Clone
+        + PartialOrd,
+>(
+    lhs: Num,
+    rhs: Num,
+    modulus: &Num,
+) -> Num {
+    let gap = modulus.clone() - rhs.clone();
+    if lhs >= gap {
+        lhs - gap
+    } else {
+        lhs + rhs
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > Convert<Num> for PowMod<Num>
+{
+    fn convert<
+        T: Add<Output = T>
+            + Sub<Output = T>
+            + Mul<Output = T>
+            + Div<Output = T>
+            + Rem<Output = T>
+            + Clone
+            + Default
+            + PartialOrd,
+        F: FnMut(Num) -> T,
+    >(
+        self,
+        f: &mut F,
+    ) -> Operation<T> {
+        Operation::PowMod(PowMod {
+            base: Box::new(self.base.convert(f)),
+            exp: Box::new(self.exp.convert(f)),
+            modulus: Box::new(self.modulus.convert(f)),
+        })
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > CanAddNumWell for PowMod<Num>
+{
+    fn can_add_number_well(&self) -> bool {
+        false
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > SetVars<Num> for PowMod<Num>
+{
+    fn set_vars(&self, vars: &[(&str, &Operation<Num>)]) -> Operation<Num> {
+        PowMod::reduce(
+            self.base.set_vars(vars),
+            self.exp.set_vars(vars),
+            self.modulus.set_vars(vars),
+        )
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > WalkMut<Num> for PowMod<Num>
+{
+    fn walk_mut<F: FnMut(&mut Operation<Num>)>(&mut self, f: &mut F) {
+        self.base.walk_mut(f);
+        self.exp.walk_mut(f);
+        self.modulus.walk_mut(f);
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > Walk<Num> for PowMod<Num>
+{
+    fn walk<F: FnMut(&Operation<Num>)>(&self, f: &mut F) {
+        self.base.walk(f);
+        self.exp.walk(f);
+        self.modulus.walk(f);
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > Unify<Num> for PowMod<Num>
+{
+    fn unify(&self, other: &Operation<Num>, bindings: &mut Vec<(String, Operation<Num>)>) -> bool {
+        match other {
+            Operation::PowMod(other) => {
+                self.base.unify(&other.base, bindings)
+                    && self.exp.unify(&other.exp, bindings)
+                    && self.modulus.unify(&other.modulus, bindings)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > ApplyRule<Num> for PowMod<Num>
+{
+    fn apply_rule(
+        &self,
+        pattern: &Operation<Num>,
+        replacement: &Operation<Num>,
+    ) -> Option<Operation<Num>> {
+        if let Some(base) = self.base.apply_rule(pattern, replacement) {
+            Some(PowMod::reduce(
+                base,
+                (*self.exp).clone(),
+                (*self.modulus).clone(),
+            ))
+        } else if let Some(exp) = self.exp.apply_rule(pattern, replacement) {
+            Some(PowMod::reduce(
+                (*self.base).clone(),
+                exp,
+                (*self.modulus).clone(),
+            ))
+        } else {
+            self.modulus
+                .apply_rule(pattern, replacement)
+                .map(|modulus| PowMod::reduce((*self.base).clone(), (*self.exp).clone(), modulus))
+        }
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd
+            + From<u8>,
+    > ToPolynomialCoefficients<Num> for PowMod<Num>
+{
+    fn to_polynomial_coefficients(&self, var: &str) -> Option<Vec<Operation<Num>>> {
+        // only constant with respect to the variable, as the remainder is not a polynomial
+        for child in [&self.base, &self.exp, &self.modulus] {
+            let mut coefficients = child.to_polynomial_coefficients(var)?;
+            trim_coefficients(&mut coefficients);
+            if coefficients.len() > 1 {
+                return None;
+            }
+        }
+        Some(vec![Operation::PowMod(self.clone())])
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd
+            + From<u8>,
+    > Diff<Num> for PowMod<Num>
+{
+    fn diff(&self, _var: &str) -> Operation<Num> {
+        // modular exponentiation is only meaningful for integers, where it has no derivative
+        Operation::default()
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd
+            + Display,
+    > ToPrefixNotation for PowMod<Num>
+{
+    fn to_prefix_notation(&self) -> String {
+        format!(
+            "(powmod {} {} {})",
+            self.base.to_prefix_notation(),
+            self.exp.to_prefix_notation(),
+            self.modulus.to_prefix_notation()
+        )
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > Calc<Num> for PowMod<Num>
+{
+    fn calc<
+        Output: Add<Output = Output>
+            + Sub<Output = Output>
+            + Mul<Output = Output>
+            + Div<Output = Output>
+            + Neg<Output = Output>
+            + From<Num>,
+    >(
+        &self,
+    ) -> Output {
+        match (&*self.base, &*self.exp, &*self.modulus) {
+            (Operation::Number(base), Operation::Number(exp), Operation::Number(modulus)) => {
+                Output::from(pow_mod(
+                    base.value.clone(),
+                    exp.value.clone(),
+                    modulus.value.clone(),
+                ))
+            }
+            _ => panic!("Cannot calculate modular exponentiation of non-integer terms without floating point output, use `calc_float` instead."),
+        }
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > CalcFloat<Num> for PowMod<Num>
+{
+    fn calc_float<Output: Float + From<Num>>(&self) -> Output {
+        pow_mod(
+            self.base.calc_float::<Output>(),
+            self.exp.calc_float::<Output>().floor(),
+            self.modulus.calc_float::<Output>(),
+        )
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > CalcBatch<Num> for PowMod<Num>
+{
+    fn calc_batch<Output: Float + From<Num>, const N: usize>(
+        &self,
+        var: &str,
+        values: &[Output; N],
+    ) -> [Output; N] {
+        let base = self.base.calc_batch(var, values);
+        let exp = self.exp.calc_batch(var, values);
+        let modulus = self.modulus.calc_batch(var, values);
+        from_fn(|lane| pow_mod(base[lane], exp[lane].floor(), modulus[lane]))
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > Add for PowMod<Num>
+{
+    type Output = Operation<Num>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Operation::Addition(Addition {
+            summands: vec![Operation::PowMod(self), Operation::PowMod(rhs)],
+        })
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > Mul for PowMod<Num>
+{
+    type Output = Operation<Num>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Operation::Multiplication(Multiplication {
+            multipliers: vec![Operation::PowMod(self), Operation::PowMod(rhs)],
+        })
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > Div for PowMod<Num>
+{
+    type Output = Operation<Num>;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        Operation::Division(Division {
+            divident: Box::new(Operation::PowMod(self)),
+            divisor: Box::new(Operation::PowMod(rhs)),
+        })
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > Sub for PowMod<Num>
+{
+    type Output = Operation<Num>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        if self == rhs {
+            Operation::default()
+        } else {
+            Operation::Addition(Addition {
+                summands: vec![Operation::PowMod(self), -Operation::PowMod(rhs)],
+            })
+        }
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > Neg for PowMod<Num>
+{
+    type Output = Operation<Num>;
+
+    fn neg(self) -> Self::Output {
+        Operation::Negation(Negation {
+            value: Box::new(Operation::PowMod(self)),
+        })
+    }
+}
@@ -0,0 +1,599 @@
+use std::{
+    fmt::Display,
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+};
+
+use num_traits::{cast, Float};
+
+use super::{
+    addition::Addition,
+    division::Division,
+    multiplication::Multiplication,
+    multiply_skipping_one,
+    negation::Negation,
+    number::{exact_root_with_one, greatest_common_divisor, number_from_u32},
+    traits::{
+        ApplyRule, Calc, CalcBatch, CalcFloat, CanAddNumWell, Convert, Diff, SetVars,
+        ToPolynomialCoefficients, ToPrefixNotation, Unify, Walk, WalkMut,
+    },
+    trim_coefficients, Operation,
+};
+
+#[derive(Debug, PartialEq, PartialOrd, Default, Clone)]
+pub struct RationalPower<
+    Num: Add<Output = Num>
+        + Sub<Output = Num>
+        + Mul<Output = Num>
+        + Div<Output = Num>
+        + Rem<Output = Num>
+        + Clone
+        + Default
+        + PartialOrd,
+> {
+    pub base: Box<Operation<Num>>,
+    pub numer: u32,
+    pub denom: u32,
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > RationalPower<Num>
+{
+    /// Raises the base to the power of `numer / denom`.
+    /// The exponent has to be reduced already, and neither be zero nor one.
+    ///
+    /// Nested powers are merged. If the merged exponent is one, the inner base is returned instead.
+    /// If the merged exponent does not fit into a `u32`, the powers are kept nested.
+    pub fn with_base(base: Operation<Num>, numer: u32, denom: u32) -> Operation<Num> {
+        match base {
+            Operation::RationalPower(pow) => {
+                // reducing crosswise first, so that the products only overflow if the result does not fit
+                let outer_gcd = greatest_common_divisor(pow.numer, denom);
+                let inner_gcd = greatest_common_divisor(numer, pow.denom);
+                let merged_numer = (pow.numer / outer_gcd).checked_mul(numer / inner_gcd);
+                let merged_denom = (pow.denom / inner_gcd).checked_mul(denom / outer_gcd);
+
+                match (merged_numer, merged_denom) {
+                    (Some(numer), Some(denom)) if numer == denom => *pow.base,
+                    (Some(numer), Some(denom)) => Operation::RationalPower(RationalPower {
+                        base: pow.base,
+                        numer,
+                        denom,
+                    }),
+                    _ => Operation::RationalPower(RationalPower {
+                        base: Box::new(Operation::RationalPower(pow)),
+                        numer,
+                        denom,
+                    }),
+                }
+            }
+            base => Operation::RationalPower(RationalPower {
+                base: Box::new(base),
+                numer,
+                denom,
+            }),
+        }
+    }
+}
+
+/// The largest exponent up to which integral powers of bases other than numbers are multiplied out.
+///
+/// Larger powers are kept as a power, so that the size of the term does not grow with the exponent.
+const MAX_EXPANDED_EXPONENT: u32 = 64;
+
+/// Raises the base to the power of `exp`, which has to be at least one.
+///
+/// Numbers are multiplied out using exponentiation by squaring.
+/// Other bases are only multiplied out up to an exponent of `MAX_EXPANDED_EXPONENT`.
+pub fn integral_power<
+    Num: Add<Output = Num>
+        + Sub<Output = Num>
+        + Mul<Output = Num>
+        + Div<Output = Num>
+        + Rem<Output = Num>
+        + Clone
+        + Default
+        + PartialOrd,
+>(
+    base: Operation<Num>,
+    exp: u32,
+) -> Operation<Num> {
+    match base {
+        Operation::Number(_) => power_by_squaring(base, exp),
+        base if exp <= MAX_EXPANDED_EXPONENT => power_by_squaring(base, exp),
+        base => RationalPower::with_base(base, exp, 1),
+    }
+}
+
+/// Multiplies the base with itself until it is raised to the power of `exp`, which has to be at least one.
+///
+/// Uses exponentiation by squaring, so only about `2 * log2(exp)` multiplications are needed.
+fn power_by_squaring<
+    Num: Add<Output = Num>
+        + Sub<Output = Num>
+        + Mul<Output = Num>
+        + Div<Output = Num>
+        + Rem<Output = Num>
+        + Clone
+        + Default
+        + PartialOrd,
+>(
+    mut base: Operation<Num>,
+    mut exp: u32,
+) -> Operation<Num> {
+    let mut result = None;
+    loop {
+        if exp % 2 == 1 {
+            result = Some(match result {
+                Some(result) => result * base.clone(),
+                None => base.clone(),
+            });
+        }
+        exp /= 2;
+        // the base is only squared if it is still needed, so it cannot overflow needlessly
+        if exp == 0 {
+            break;
+        }
+        base = base.clone() * base;
+    }
+    result.expect("Cannot raise to the power of zero without a one.")
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > Convert<Num> for RationalPower<Num>
+{
+    fn convert<
+        T: Add<Output = T>
+            + Sub<Output = T>
+            + Mul<Output = T>
+            + Div<Output = T>
+            + Rem<Output = T>
+            + Clone
+            + Default
+            + PartialOrd,
+        F: FnMut(Num) -> T,
+    >(
+        self,
+        f: &mut F,
+    ) -> Operation<T> {
+        Operation::RationalPower(RationalPower {
+            base: Box::new(self.base.convert(f)),
+            numer: self.numer,
+            denom: self.denom,
+        })
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > CanAddNumWell for RationalPower<Num>
+{
+    fn can_add_number_well(&self) -> bool {
+        false
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > SetVars<Num> for RationalPower<Num>
+{
+    fn set_vars(&self, vars: &[(&str, &Operation<Num>)]) -> Operation<Num> {
+        RationalPower::with_base(self.base.set_vars(vars), self.numer, self.denom)
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > WalkMut<Num> for RationalPower<Num>
+{
+    fn walk_mut<F: FnMut(&mut Operation<Num>)>(&mut self, f: &mut F) {
+        self.base.walk_mut(f);
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > Walk<Num> for RationalPower<Num>
+{
+    fn walk<F: FnMut(&Operation<Num>)>(&self, f: &mut F) {
+        self.base.walk(f);
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > Unify<Num> for RationalPower<Num>
+{
+    fn unify(&self, other: &Operation<Num>, bindings: &mut Vec<(String, Operation<Num>)>) -> bool {
+        match other {
+            Operation::RationalPower(other) => {
+                self.numer == other.numer
+                    && self.denom == other.denom
+                    && self.base.unify(&other.base, bindings)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > ApplyRule<Num> for RationalPower<Num>
+{
+    fn apply_rule(
+        &self,
+        pattern: &Operation<Num>,
+        replacement: &Operation<Num>,
+    ) -> Option<Operation<Num>> {
+        self.base
+            .apply_rule(pattern, replacement)
+            .map(|base| RationalPower::with_base(base, self.numer, self.denom))
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd
+            + From<u8>,
+    > ToPolynomialCoefficients<Num> for RationalPower<Num>
+{
+    fn to_polynomial_coefficients(&self, var: &str) -> Option<Vec<Operation<Num>>> {
+        let mut base = self.base.to_polynomial_coefficients(var)?;
+        trim_coefficients(&mut base);
+        if base.len() > 1 {
+            None
+        } else {
+            Some(vec![Operation::RationalPower(self.clone())])
+        }
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd
+            + From<u8>,
+    > Diff<Num> for RationalPower<Num>
+{
+    fn diff(&self, var: &str) -> Operation<Num> {
+        let base_derivative = self.base.diff(var);
+        if base_derivative == Operation::default() {
+            return Operation::default();
+        }
+
+        // power rule: (numer / denom) * base^((numer - denom) / denom) * base'
+        let factor = Operation::from(number_from_u32::<Num>(self.numer))
+            / Operation::from(number_from_u32::<Num>(self.denom));
+        let power = if self.denom == 1 {
+            integral_power((*self.base).clone(), self.numer - 1)
+        } else if self.numer > self.denom {
+            RationalPower::with_base((*self.base).clone(), self.numer - self.denom, self.denom)
+        } else {
+            Operation::from(Num::from(1))
+                / RationalPower::with_base(
+                    (*self.base).clone(),
+                    self.denom - self.numer,
+                    self.denom,
+                )
+        };
+        multiply_skipping_one(factor * power, base_derivative)
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd
+            + Display,
+    > ToPrefixNotation for RationalPower<Num>
+{
+    fn to_prefix_notation(&self) -> String {
+        format!(
+            "(^ {} {} {})",
+            self.base.to_prefix_notation(),
+            self.numer,
+            self.denom
+        )
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > Calc<Num> for RationalPower<Num>
+{
+    fn calc<
+        Output: Add<Output = Output>
+            + Sub<Output = Output>
+            + Mul<Output = Output>
+            + Div<Output = Output>
+            + Neg<Output = Output>
+            + From<Num>,
+    >(
+        &self,
+    ) -> Output {
+        let base = match &*self.base {
+            // constant bases are only left if they were set after creating the power, so their root may be exact
+            Operation::Number(number) if self.denom > 1 => {
+                let value = number.value.clone();
+                if value == Num::default() {
+                    return Output::from(value);
+                }
+                match exact_root_with_one(value.clone(), self.denom, value.clone() / value) {
+                    Some(root) => Operation::from(root),
+                    None => panic!("Cannot calculate irrational powers without floating point output, use `calc_float` instead."),
+                }
+            }
+            base if self.denom == 1 => base.clone(),
+            _ => panic!("Cannot calculate rational powers without floating point output, use `calc_float` instead."),
+        };
+        match base {
+            Operation::Number(_) => power_by_squaring(base, self.numer).calc(),
+            base => (1..self.numer).fold(base.calc(), |acc: Output, _| acc * base.calc()),
+        }
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > CalcFloat<Num> for RationalPower<Num>
+{
+    fn calc_float<Output: Float + From<Num>>(&self) -> Output {
+        let exponent =
+            cast::<u32, Output>(self.numer).unwrap() / cast::<u32, Output>(self.denom).unwrap();
+        self.base.calc_float::<Output>().powf(exponent)
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > CalcBatch<Num> for RationalPower<Num>
+{
+    fn calc_batch<Output: Float + From<Num>, const N: usize>(
+        &self,
+        var: &str,
+        values: &[Output; N],
+    ) -> [Output; N] {
+        let exponent =
+            cast::<u32, Output>(self.numer).unwrap() / cast::<u32, Output>(self.denom).unwrap();
+        self.base
+            .calc_batch(var, values)
+            .map(|base| base.powf(exponent))
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > Add for RationalPower<Num>
+{
+    type Output = Operation<Num>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Operation::Addition(Addition {
+            summands: vec![
+                Operation::RationalPower(self),
+                Operation::RationalPower(rhs),
+            ],
+        })
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > Mul for RationalPower<Num>
+{
+    type Output = Operation<Num>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        if self.base == rhs.base {
+            // adding the exponents using their least common denominator, so that they only overflow if the sum does not fit
+            let gcd = greatest_common_divisor(self.denom, rhs.denom);
+            let numer = self
+                .numer
+                .checked_mul(rhs.denom / gcd)
+                .zip(rhs.numer.checked_mul(self.denom / gcd))
+                .and_then(|(lhs, rhs)| lhs.checked_add(rhs));
+            let denom = (self.denom / gcd).checked_mul(rhs.denom);
+
+            match (numer, denom) {
+                (Some(numer), Some(denom)) => {
+                    let gcd = greatest_common_divisor(numer, denom);
+                    let (numer, denom) = (numer / gcd, denom / gcd);
+                    if denom == 1 {
+                        integral_power(*self.base, numer)
+                    } else {
+                        RationalPower::with_base(*self.base, numer, denom)
+                    }
+                }
+                _ => Operation::Multiplication(Multiplication {
+                    multipliers: vec![
+                        Operation::RationalPower(self),
+                        Operation::RationalPower(rhs),
+                    ],
+                }),
+            }
+        } else {
+            Operation::Multiplication(Multiplication {
+                multipliers: vec![
+                    Operation::RationalPower(self),
+                    Operation::RationalPower(rhs),
+                ],
+            })
+        }
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > Div for RationalPower<Num>
+{
+    type Output = Operation<Num>;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        Operation::Division(Division {
+            divident: Box::new(Operation::RationalPower(self)),
+            divisor: Box::new(Operation::RationalPower(rhs)),
+        })
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > Sub for RationalPower<Num>
+{
+    type Output = Operation<Num>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        if self == rhs {
+            Operation::default()
+        } else {
+            Operation::Addition(Addition {
+                summands: vec![
+                    Operation::RationalPower(self),
+                    -Operation::RationalPower(rhs),
+                ],
+            })
+        }
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > Neg for RationalPower<Num>
+{
+    type Output = Operation<Num>;
+
+    fn neg(self) -> Self::Output {
+        Operation::Negation(Negation {
+            value: Box::new(Operation::RationalPower(self)),
+        })
+    }
+}
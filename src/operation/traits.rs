@@ -1,5 +1,7 @@
 use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
 
+use num_traits::Float;
+
 use super::Operation;
 
 pub trait Convert<
@@ -21,10 +23,11 @@ pub trait Convert<
             + Rem<Output = T>
             + Clone
             + Default
-            + PartialOrd
-            + From<Num>,
+            + PartialOrd,
+        F: FnMut(Num) -> T,
     >(
         self,
+        f: &mut F,
     ) -> Operation<T>;
 }
 
@@ -51,6 +54,38 @@ pub trait Calc<
     ) -> Output;
 }
 
+pub trait CalcFloat<
+    Num: Add<Output = Num>
+        + Sub<Output = Num>
+        + Mul<Output = Num>
+        + Div<Output = Num>
+        + Rem<Output = Num>
+        + Clone
+        + Default
+        + PartialOrd,
+>
+{
+    fn calc_float<Output: Float + From<Num>>(&self) -> Output;
+}
+
+pub trait CalcBatch<
+    Num: Add<Output = Num>
+        + Sub<Output = Num>
+        + Mul<Output = Num>
+        + Div<Output = Num>
+        + Rem<Output = Num>
+        + Clone
+        + Default
+        + PartialOrd,
+>
+{
+    fn calc_batch<Output: Float + From<Num>, const N: usize>(
+        &self,
+        var: &str,
+        values: &[Output; N],
+    ) -> [Output; N];
+}
+
 pub trait SetVars<
     Num: Add<Output = Num>
         + Sub<Output = Num>
@@ -65,6 +100,98 @@ pub trait SetVars<
     fn set_vars(&self, vars: &[(&str, &Operation<Num>)]) -> Operation<Num>;
 }
 
+pub trait Walk<
+    Num: Add<Output = Num>
+        + Sub<Output = Num>
+        + Mul<Output = Num>
+        + Div<Output = Num>
+        + Rem<Output = Num>
+        + Clone
+        + Default
+        + PartialOrd,
+>
+{
+    fn walk<F: FnMut(&Operation<Num>)>(&self, f: &mut F);
+}
+
+pub trait WalkMut<
+    Num: Add<Output = Num>
+        + Sub<Output = Num>
+        + Mul<Output = Num>
+        + Div<Output = Num>
+        + Rem<Output = Num>
+        + Clone
+        + Default
+        + PartialOrd,
+>
+{
+    fn walk_mut<F: FnMut(&mut Operation<Num>)>(&mut self, f: &mut F);
+}
+
+pub trait Unify<
+    Num: Add<Output = Num>
+        + Sub<Output = Num>
+        + Mul<Output = Num>
+        + Div<Output = Num>
+        + Rem<Output = Num>
+        + Clone
+        + Default
+        + PartialOrd,
+>
+{
+    fn unify(&self, other: &Operation<Num>, bindings: &mut Vec<(String, Operation<Num>)>) -> bool;
+}
+
+pub trait ApplyRule<
+    Num: Add<Output = Num>
+        + Sub<Output = Num>
+        + Mul<Output = Num>
+        + Div<Output = Num>
+        + Rem<Output = Num>
+        + Clone
+        + Default
+        + PartialOrd,
+>
+{
+    fn apply_rule(
+        &self,
+        pattern: &Operation<Num>,
+        replacement: &Operation<Num>,
+    ) -> Option<Operation<Num>>;
+}
+
+pub trait ToPolynomialCoefficients<
+    Num: Add<Output = Num>
+        + Sub<Output = Num>
+        + Mul<Output = Num>
+        + Div<Output = Num>
+        + Rem<Output = Num>
+        + Clone
+        + Default
+        + PartialOrd,
+>
+{
+    fn to_polynomial_coefficients(&self, var: &str) -> Option<Vec<Operation<Num>>>;
+}
+
+pub trait Diff<
+    Num: Add<Output = Num>
+        + Sub<Output = Num>
+        + Mul<Output = Num>
+        + Div<Output = Num>
+        + Rem<Output = Num>
+        + Clone
+        + Default
+        + PartialOrd,
+>
+{
+    fn diff(&self, var: &str) -> Operation<Num>;
+}
+
+pub trait ToPrefixNotation {
+    fn to_prefix_notation(&self) -> String;
+}
+
 pub trait CanAddNumWell {
     fn can_add_number_well(&self) -> bool;
 }
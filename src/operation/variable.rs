@@ -1,4 +1,5 @@
 use std::{
+    fmt::Display,
     marker::PhantomData,
     ops::{Add, Div, Mul, Neg, Rem, Sub},
 };
@@ -8,7 +9,10 @@ use super::{
     division::Division,
     multiplication::Multiplication,
     negation::Negation,
-    traits::{CanAddNumWell, Convert, SetVars},
+    traits::{
+        ApplyRule, CanAddNumWell, Convert, Diff, SetVars, ToPolynomialCoefficients,
+        ToPrefixNotation, Unify, Walk, WalkMut,
+    },
     Operation,
 };
 
@@ -46,13 +50,14 @@ impl<
             + Rem<Output = T>
             + Clone
             + Default
-            + PartialOrd
-            + From<Num>,
+            + PartialOrd,
+        F: FnMut(Num) -> T,
     >(
         self,
+        _f: &mut F,
     ) -> Operation<T> {
         Operation::Variable(Variable {
-            phantom: PhantomData::default(),
+            phantom: PhantomData,
             name: self.name,
         })
     }
@@ -95,6 +100,138 @@ impl<
     }
 }
 
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > WalkMut<Num> for Variable<Num>
+{
+    fn walk_mut<F: FnMut(&mut Operation<Num>)>(&mut self, _f: &mut F) {}
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > Walk<Num> for Variable<Num>
+{
+    fn walk<F: FnMut(&Operation<Num>)>(&self, _f: &mut F) {}
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > Unify<Num> for Variable<Num>
+{
+    fn unify(&self, other: &Operation<Num>, bindings: &mut Vec<(String, Operation<Num>)>) -> bool {
+        for binding in bindings.iter() {
+            if binding.0 == self.name {
+                return &binding.1 == other;
+            }
+        }
+        bindings.push((self.name.clone(), other.clone()));
+        true
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > ApplyRule<Num> for Variable<Num>
+{
+    fn apply_rule(
+        &self,
+        _pattern: &Operation<Num>,
+        _replacement: &Operation<Num>,
+    ) -> Option<Operation<Num>> {
+        None
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd
+            + From<u8>,
+    > ToPolynomialCoefficients<Num> for Variable<Num>
+{
+    fn to_polynomial_coefficients(&self, var: &str) -> Option<Vec<Operation<Num>>> {
+        if self.name == var {
+            Some(vec![
+                Operation::from(Num::default()),
+                Operation::from(Num::from(1)),
+            ])
+        } else {
+            Some(vec![Operation::Variable(self.clone())])
+        }
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd
+            + From<u8>,
+    > Diff<Num> for Variable<Num>
+{
+    fn diff(&self, var: &str) -> Operation<Num> {
+        if self.name == var {
+            Operation::from(Num::from(1))
+        } else {
+            Operation::default()
+        }
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd
+            + Display,
+    > ToPrefixNotation for Variable<Num>
+{
+    fn to_prefix_notation(&self) -> String {
+        self.name.clone()
+    }
+}
+
 impl<
         Num: Add<Output = Num>
             + Sub<Output = Num>
@@ -109,7 +246,7 @@ impl<
     fn from(value: String) -> Self {
         Variable {
             name: value,
-            phantom: PhantomData::default(),
+            phantom: PhantomData,
         }
     }
 }
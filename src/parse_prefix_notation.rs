@@ -0,0 +1,190 @@
+use std::{iter::Peekable, str::Chars};
+
+use crate::{parse_string::TryFromStrError, Term};
+
+/// Parses a term in prefix notation. Used in `Term::from_prefix_notation`.
+///
+/// Every node is written as `(op arg1 arg2 ...)`, where `op` is one of `+`, `-`, `*`, `/`, `//`, `^`, `&`, `|`, `xor`, `<<`, `>>`, `powmod` and `fma`.
+/// Numbers and variables are written as they are.
+///
+/// Expected behavior:
+/// ```rust
+/// # use crem::*;
+/// assert_eq!(
+///     Term::from_prefix_notation("(+ (* 2 x) 1)")?,
+///     Term::from(2) * Term::var("x") + Term::from(1)
+/// );
+/// # Ok::<(), TryFromStrError>(())
+/// ```
+pub fn parse_prefix_notation(value: &str) -> Result<Term<u32>, TryFromStrError> {
+    let mut chars = value.chars().peekable();
+
+    let term = parse_node(&mut chars)?;
+
+    skip_whitespace(&mut chars);
+    match chars.next() {
+        None => Ok(term),
+        Some(any) => Err(TryFromStrError::UnexpectedCharacter(any)),
+    }
+}
+
+/// Parses a single node, which is either a number, a variable or an operation within brackets.
+fn parse_node(chars: &mut Peekable<Chars>) -> Result<Term<u32>, TryFromStrError> {
+    skip_whitespace(chars);
+
+    match chars.next() {
+        None => Err(TryFromStrError::UnexpectedEof),
+        Some('(') => parse_operation(chars),
+        Some(first) if first.is_ascii_digit() => Ok(Term::from(read_number(first, chars)?)),
+        Some(first) if first.is_alphabetic() || first == '_' => {
+            Ok(Term::var(read_word(first, chars, |char| {
+                char.is_alphanumeric() || char == '_'
+            })))
+        }
+        Some(any) => Err(TryFromStrError::UnexpectedCharacter(any)),
+    }
+}
+
+/// Parses an operation and its arguments. The opening bracket has already been read.
+fn parse_operation(chars: &mut Peekable<Chars>) -> Result<Term<u32>, TryFromStrError> {
+    skip_whitespace(chars);
+
+    let operator = chars.next().ok_or(TryFromStrError::UnexpectedEof)?;
+    let floor = operator == '/' && chars.next_if_eq(&'/').is_some();
+    match operator {
+        '<' | '>' if chars.next_if_eq(&operator).is_none() => {
+            return Err(TryFromStrError::UnexpectedCharacter(operator))
+        }
+        'x' | 'p' | 'f' => {
+            let rest: &[char] = match operator {
+                'x' => &['o', 'r'],
+                'p' => &['o', 'w', 'm', 'o', 'd'],
+                _ => &['m', 'a'],
+            };
+            for &expected in rest {
+                match chars.next() {
+                    None => return Err(TryFromStrError::UnexpectedEof),
+                    Some(char) if char == expected => (),
+                    Some(any) => return Err(TryFromStrError::UnexpectedCharacter(any)),
+                }
+            }
+        }
+        _ => (),
+    }
+
+    if operator == '^' {
+        let base = parse_node(chars)?;
+        let numer = parse_exponent_part(chars)?;
+        let denom = parse_exponent_part(chars)?;
+
+        skip_whitespace(chars);
+        return match chars.next() {
+            None => Err(TryFromStrError::UnexpectedEof),
+            Some(')') if denom == 0 => Err(TryFromStrError::DivisionByZero),
+            // raising numbers to a power overflows easily, so it is checked instead of panicking
+            Some(')') => Term::from_checked(base.clone().into_checked().pow_rational(numer, denom))
+                .ok_or_else(|| TryFromStrError::IntegerOverflow {
+                    value: format!("(^ {} {numer} {denom})", base.to_prefix_notation()),
+                }),
+            Some(any) => Err(TryFromStrError::UnexpectedCharacter(any)),
+        };
+    }
+
+    let mut arguments = Vec::new();
+    loop {
+        skip_whitespace(chars);
+        match chars.peek() {
+            None => return Err(TryFromStrError::UnexpectedEof),
+            Some(')') => {
+                chars.next();
+                break;
+            }
+            Some(_) => arguments.push(parse_node(chars)?),
+        }
+    }
+
+    let mut arguments = arguments.into_iter();
+    let first = arguments
+        .next()
+        .ok_or(TryFromStrError::UnexpectedCharacter(')'))?;
+
+    match operator {
+        '+' => Ok(arguments.fold(first, |acc, term| acc + term)),
+        '*' => Ok(arguments.fold(first, |acc, term| acc * term)),
+        '-' => {
+            let rest: Vec<Term<u32>> = arguments.collect();
+            if rest.is_empty() {
+                Ok(-first)
+            } else {
+                Ok(rest.into_iter().fold(first, |acc, term| acc - term))
+            }
+        }
+        '/' => {
+            let mut result = first;
+            for term in arguments {
+                if term == Term::from(0u32) {
+                    return Err(TryFromStrError::DivisionByZero);
+                }
+                if floor {
+                    result = Term::floor_div(result, term);
+                } else {
+                    result /= term;
+                }
+            }
+            Ok(result)
+        }
+        '&' => Ok(arguments.fold(first, |acc, term| acc & term)),
+        '|' => Ok(arguments.fold(first, |acc, term| acc | term)),
+        'x' => Ok(arguments.fold(first, |acc, term| acc ^ term)),
+        '<' => Ok(arguments.fold(first, |acc, term| acc << term)),
+        '>' => Ok(arguments.fold(first, |acc, term| acc >> term)),
+        // exactly a base, an exponent and a modulus
+        'p' => match (arguments.next(), arguments.next(), arguments.next()) {
+            (Some(_), Some(modulus), None) if modulus == Term::from(0u32) => {
+                Err(TryFromStrError::DivisionByZero)
+            }
+            (Some(exp), Some(modulus), None) => Ok(Term::pow_mod_term(first, exp, modulus)),
+            _ => Err(TryFromStrError::UnexpectedCharacter(')')),
+        },
+        // exactly two factors and a summand
+        'f' => match (arguments.next(), arguments.next(), arguments.next()) {
+            (Some(multiplier), Some(summand), None) => {
+                Ok(Term::mul_add(first, multiplier, summand))
+            }
+            _ => Err(TryFromStrError::UnexpectedCharacter(')')),
+        },
+        any => Err(TryFromStrError::UnexpectedCharacter(any)),
+    }
+}
+
+/// Parses the numerator or denominator of an exponent.
+fn parse_exponent_part(chars: &mut Peekable<Chars>) -> Result<u32, TryFromStrError> {
+    skip_whitespace(chars);
+
+    match chars.next() {
+        None => Err(TryFromStrError::UnexpectedEof),
+        Some(first) if first.is_ascii_digit() => read_number(first, chars),
+        Some(any) => Err(TryFromStrError::UnexpectedCharacter(any)),
+    }
+}
+
+/// Reads a number, starting with the given digit.
+fn read_number(first: char, chars: &mut Peekable<Chars>) -> Result<u32, TryFromStrError> {
+    let buffer = read_word(first, chars, |char| char.is_ascii_digit());
+    buffer
+        .parse::<u32>()
+        .map_err(|_| TryFromStrError::IntegerOverflow { value: buffer })
+}
+
+/// Reads all characters matching the predicate, starting with the given character.
+fn read_word(first: char, chars: &mut Peekable<Chars>, predicate: impl Fn(char) -> bool) -> String {
+    let mut buffer = String::from(first);
+    while let Some(char) = chars.next_if(|char| predicate(*char)) {
+        buffer.push(char);
+    }
+    buffer
+}
+
+fn skip_whitespace(chars: &mut Peekable<Chars>) {
+    while chars.next_if(|char| char.is_whitespace()).is_some() {}
+}
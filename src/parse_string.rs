@@ -1,14 +1,26 @@
-use std::mem::take;
+use std::{
+    mem::take,
+    ops::{Add, Div, Mul, Rem, Sub},
+};
+
+use num_traits::{CheckedAdd, CheckedMul, CheckedSub};
 
 use crate::Term;
 
 /// Error when creating a term from an invalid string.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum TryFromStrError {
     /// An illegal character was encountered.
     UnexpectedCharacter(char),
     /// The EOF was reached while some operations or brackets were still open.
     UnexpectedEof,
+    /// A number was too large to be represented, or folding the numbers overflowed.
+    IntegerOverflow {
+        /// The number, or the formular whose folding overflowed, as it was written.
+        value: String,
+    },
+    /// A division by zero was encountered.
+    DivisionByZero,
 }
 
 /// Parses a formular. Used in `impl TryFrom<&str> for Term`.
@@ -20,9 +32,97 @@ pub enum TryFromStrError {
 /// # use crem::*;
 /// assert_eq!(Term::try_from("2 + 3")?, Term::from(2) + Term::from(3));
 /// assert_eq!(Term::try_from("2 + 3")?, Term::from(5));
+/// assert_eq!(Term::try_from("6 & 3 | 1 << 3")?, Term::from(10));
 /// # Ok::<(), TryFromStrError>(())
 /// ```
 pub fn parse_string(value: &str) -> Result<Term<u32>, TryFromStrError> {
+    first_error(parse_string_collecting_errors(value))
+}
+
+/// Parses a formular, recovering from errors instead of stopping at the first one.
+///
+/// Returns a best-effort term, alongside all errors encountered in order.
+/// Illegal characters and numbers which are too large are skipped, divisions by zero are left out
+/// and unclosed brackets are closed at the end of the formular.
+pub fn parse_string_collecting_errors(value: &str) -> (Term<u32>, Vec<TryFromStrError>) {
+    let mut errors = Vec::new();
+    let term = parse_u32(value, false, &mut errors);
+    (term, errors)
+}
+
+/// Parses a formular, allowing implicit multiplication of numbers and variables.
+///
+/// Identifiers are read as variables. A number immediately followed by an identifier (`2x`) is multiplied with it.
+pub fn parse_string_with_implicit_multiplication(
+    value: &str,
+) -> Result<Term<u32>, TryFromStrError> {
+    let mut errors = Vec::new();
+    let term = parse_u32(value, true, &mut errors);
+    first_error((term, errors))
+}
+
+/// Parses a formular using signed integers. Used in `Term::from_str_i64`.
+///
+/// Bitwise operators are not supported.
+pub fn parse_string_i64(value: &str) -> Result<Term<i64>, TryFromStrError> {
+    let mut errors = Vec::new();
+    let term = parse_i64(value, false, &mut errors);
+    first_error((term, errors))
+}
+
+/// Returns the term, or the first error if there were any.
+fn first_error<T>((term, errors): (T, Vec<TryFromStrError>)) -> Result<T, TryFromStrError> {
+    match errors.into_iter().next() {
+        Some(error) => Err(error),
+        None => Ok(term),
+    }
+}
+
+/// Parses a formular using unsigned integers, collecting all errors.
+fn parse_u32(
+    value: &str,
+    implicit_multiplication: bool,
+    errors: &mut Vec<TryFromStrError>,
+) -> Term<u32> {
+    match parse_bitwise(value, implicit_multiplication, errors) {
+        Some(term) => term,
+        None => parse_arithmetic(value, implicit_multiplication, parse_u32, errors),
+    }
+}
+
+/// Parses a formular using signed integers, collecting all errors.
+fn parse_i64(
+    value: &str,
+    implicit_multiplication: bool,
+    errors: &mut Vec<TryFromStrError>,
+) -> Term<i64> {
+    parse_arithmetic(value, implicit_multiplication, parse_i64, errors)
+}
+
+/// Parses a formular consisting of additions, subtractions, multiplications and divisions, collecting all errors.
+///
+/// Terms within brackets are parsed using `parse_brackets`.
+/// If `implicit_multiplication` is set, identifiers are read as variables and a number directly followed by one is multiplied with it.
+fn parse_arithmetic<
+    Num: Add<Output = Num>
+        + Sub<Output = Num>
+        + Mul<Output = Num>
+        + Div<Output = Num>
+        + Rem<Output = Num>
+        + Clone
+        + Default
+        + PartialOrd
+        + From<u32>
+        + CheckedAdd
+        + CheckedSub
+        + CheckedMul
+        + 'static,
+>(
+    value: &str,
+    implicit_multiplication: bool,
+    parse_brackets: fn(&str, bool, &mut Vec<TryFromStrError>) -> Term<Num>,
+    errors: &mut Vec<TryFromStrError>,
+) -> Term<Num> {
     enum Operation {
         Add,
         Mul,
@@ -61,6 +161,9 @@ pub fn parse_string(value: &str) -> Result<Term<u32>, TryFromStrError> {
         /// The depth is increased for every encountered `(` and decreased for every encountered `)`.
         /// The depth cannot be zero (since that would mean that the outer-most pair of brackets has already been closed).
         Brackets(usize /* depth */, String /* buffer */),
+        /// A variable has started being read. Only used with implicit multiplication.
+        /// The characters of its name read so far are stored in the buffer.
+        Identifier(String /* buffer */),
     }
 
     /// The current state of the state machine.
@@ -83,32 +186,56 @@ pub fn parse_string(value: &str) -> Result<Term<u32>, TryFromStrError> {
     }
 
     // The work-in-progress result. Contains all complete terms added so far.
-    let mut result = Term::from(0u32);
+    let mut result = Term::from(Num::from(0u32));
 
     // The current work-in-progress term.
     // Whenever a * or / is encountered, its applied to this term.
     // When a + is encountered, this term is added to the result and replaced with the new term.
-    let mut working_term = Box::new([Term::from(0u32)]);
+    let mut working_term = Box::new([Term::from(Num::from(0u32))]);
 
     // Processes a term, applying the operation as appropriate.
     // Multiplications and divisions are applied to the current `working_term`.
     // If the operation is an addition, the current `working_term` is added to the result and replaced by this new term.
-    let mut process_term = |operation: Operation, negated: bool, term: Term<u32>| {
+    // Terms which could not be read, divisions by zero and operations which overflow are recorded as errors and skipped.
+    let mut process_term = |operation: Operation,
+                            negated: bool,
+                            term: Result<Term<Num>, TryFromStrError>,
+                            errors: &mut Vec<TryFromStrError>| {
+        let term = match term {
+            Ok(term) => term,
+            Err(error) => return errors.push(error),
+        };
         let t = if negated { -term } else { term };
+        let overflow = || TryFromStrError::IntegerOverflow {
+            value: value.trim().to_string(),
+        };
         match operation {
             Operation::Add => {
-                result += take(&mut working_term[0]);
+                match result.clone().checked_add(take(&mut working_term[0])) {
+                    Some(sum) => result = sum,
+                    None => errors.push(overflow()),
+                }
                 working_term[0] = t;
             }
-            Operation::Mul => {
-                working_term[0] *= t;
-            }
+            Operation::Mul => match working_term[0].clone().checked_mul(t) {
+                Some(product) => working_term[0] = product,
+                None => errors.push(overflow()),
+            },
             Operation::Div => {
-                working_term[0] /= t;
+                if t == Term::from(Num::default()) {
+                    return errors.push(TryFromStrError::DivisionByZero);
+                }
+                match working_term[0].clone().checked_div(t) {
+                    Some(quotient) => working_term[0] = quotient,
+                    None => errors.push(overflow()),
+                }
             }
         }
     };
 
+    // Reads the digits of a number as a term.
+    let integer = |buffer: &str| parse_integer(buffer).map(|value| Term::from(Num::from(value)));
+
     // The current state of the state machine.
     // Starts with adding something.
     let mut state = State::Term(Operation::Add, false, Value::None);
@@ -123,7 +250,13 @@ pub fn parse_string(value: &str) -> Result<Term<u32>, TryFromStrError> {
                 '-' => State::Term(Operation::Add, true, Value::None),
                 '(' => State::Term(Operation::Mul, false, Value::Brackets(1, String::new())),
                 any if any.is_whitespace() => state,
-                any => return Err(TryFromStrError::UnexpectedCharacter(any)),
+                any if implicit_multiplication && any.is_alphabetic() => {
+                    State::Term(Operation::Mul, false, Value::Identifier(any.into()))
+                }
+                any => {
+                    errors.push(TryFromStrError::UnexpectedCharacter(any));
+                    state
+                }
             },
             State::Term(op, neg, val) => match val {
                 Value::None => match char {
@@ -131,10 +264,16 @@ pub fn parse_string(value: &str) -> Result<Term<u32>, TryFromStrError> {
                     '0' | '1' | '2' | '3' | '4' | '5' | '6' | '7' | '8' | '9' => {
                         State::Term(op, neg, Value::PreComma(char.into()))
                     }
-                    '.' => State::Term(op, neg, Value::PostComma(0, char.into())),
+                    '.' => State::Term(op, neg, Value::PostComma(0, String::new())),
                     '(' => State::Term(op, neg, Value::Brackets(1, String::new())),
                     any if any.is_whitespace() => State::Term(op, neg, Value::None),
-                    any => return Err(TryFromStrError::UnexpectedCharacter(any)),
+                    any if implicit_multiplication && any.is_alphabetic() => {
+                        State::Term(op, neg, Value::Identifier(any.into()))
+                    }
+                    any => {
+                        errors.push(TryFromStrError::UnexpectedCharacter(any));
+                        State::Term(op, neg, Value::None)
+                    }
                 },
                 Value::Brackets(depth, mut buffer) => match char {
                     '(' => {
@@ -143,7 +282,12 @@ pub fn parse_string(value: &str) -> Result<Term<u32>, TryFromStrError> {
                     }
                     ')' => {
                         if depth == 1 {
-                            process_term(op, neg, parse_string(&buffer)?);
+                            process_term(
+                                op,
+                                neg,
+                                Ok(parse_brackets(&buffer, implicit_multiplication, errors)),
+                                errors,
+                            );
                             State::AfterTerm
                         } else {
                             buffer.push(')');
@@ -160,32 +304,37 @@ pub fn parse_string(value: &str) -> Result<Term<u32>, TryFromStrError> {
                         buffer.push(char);
                         State::Term(op, neg, Value::PreComma(buffer))
                     }
-                    '.' => State::Term(
-                        op,
-                        neg,
-                        Value::PostComma(buffer.parse::<u32>().unwrap(), String::new()),
-                    ),
+                    '.' => {
+                        let pre = parse_integer(&buffer).unwrap_or_else(|error| {
+                            errors.push(error);
+                            0
+                        });
+                        State::Term(op, neg, Value::PostComma(pre, String::new()))
+                    }
                     '+' | '*' | '/' => {
-                        let term = Term::from(buffer.parse::<u32>().unwrap());
-                        process_term(op, neg, term);
+                        process_term(op, neg, integer(&buffer), errors);
                         State::Term(Operation::try_from(char).unwrap(), false, Value::None)
                     }
                     '-' => {
-                        let term = Term::from(buffer.parse::<u32>().unwrap());
-                        process_term(op, neg, term);
+                        process_term(op, neg, integer(&buffer), errors);
                         State::Term(Operation::Add, true, Value::None)
                     }
                     '(' => {
-                        let term = Term::from(buffer.parse::<u32>().unwrap());
-                        process_term(op, neg, term);
+                        process_term(op, neg, integer(&buffer), errors);
                         State::Term(Operation::Mul, false, Value::Brackets(1, String::new()))
                     }
                     any if any.is_whitespace() => {
-                        let term = Term::from(buffer.parse::<u32>().unwrap());
-                        process_term(op, neg, term);
+                        process_term(op, neg, integer(&buffer), errors);
                         State::AfterTerm
                     }
-                    any => return Err(TryFromStrError::UnexpectedCharacter(any)),
+                    any if implicit_multiplication && any.is_alphabetic() => {
+                        process_term(op, neg, integer(&buffer), errors);
+                        State::Term(Operation::Mul, false, Value::Identifier(any.into()))
+                    }
+                    any => {
+                        errors.push(TryFromStrError::UnexpectedCharacter(any));
+                        State::Term(op, neg, Value::PreComma(buffer))
+                    }
                 },
                 Value::PostComma(pre, mut buffer) => match char {
                     '0' | '1' | '2' | '3' | '4' | '5' | '6' | '7' | '8' | '9' => {
@@ -193,42 +342,55 @@ pub fn parse_string(value: &str) -> Result<Term<u32>, TryFromStrError> {
                         State::Term(op, neg, Value::PostComma(pre, buffer))
                     }
                     '+' | '*' | '/' => {
-                        let term = Term::from(pre)
-                            + Term::div(
-                                buffer.parse::<u32>().unwrap(),
-                                10u32.pow(buffer.len() as u32),
-                            );
-                        process_term(op, neg, term);
+                        process_term(op, neg, parse_decimal(pre, &buffer), errors);
                         State::Term(Operation::try_from(char).unwrap(), false, Value::None)
                     }
                     '-' => {
-                        let term = Term::from(pre)
-                            + Term::div(
-                                buffer.parse::<u32>().unwrap(),
-                                10u32.pow(buffer.len() as u32),
-                            );
-                        process_term(op, neg, term);
+                        process_term(op, neg, parse_decimal(pre, &buffer), errors);
                         State::Term(Operation::Add, true, Value::None)
                     }
                     '(' => {
-                        let term = Term::from(pre)
-                            + Term::div(
-                                buffer.parse::<u32>().unwrap(),
-                                10u32.pow(buffer.len() as u32),
-                            );
-                        process_term(op, neg, term);
+                        process_term(op, neg, parse_decimal(pre, &buffer), errors);
                         State::Term(Operation::Mul, false, Value::Brackets(1, String::new()))
                     }
                     any if any.is_whitespace() => {
-                        let term = Term::from(pre)
-                            + Term::div(
-                                buffer.parse::<u32>().unwrap(),
-                                10u32.pow(buffer.len() as u32),
-                            );
-                        process_term(op, neg, term);
+                        process_term(op, neg, parse_decimal(pre, &buffer), errors);
                         State::AfterTerm
                     }
-                    any => return Err(TryFromStrError::UnexpectedCharacter(any)),
+                    any if implicit_multiplication && any.is_alphabetic() => {
+                        process_term(op, neg, parse_decimal(pre, &buffer), errors);
+                        State::Term(Operation::Mul, false, Value::Identifier(any.into()))
+                    }
+                    any => {
+                        errors.push(TryFromStrError::UnexpectedCharacter(any));
+                        State::Term(op, neg, Value::PostComma(pre, buffer))
+                    }
+                },
+                Value::Identifier(mut buffer) => match char {
+                    any if any.is_alphanumeric() || any == '_' => {
+                        buffer.push(any);
+                        State::Term(op, neg, Value::Identifier(buffer))
+                    }
+                    '+' | '*' | '/' => {
+                        process_term(op, neg, Ok(Term::var(&buffer)), errors);
+                        State::Term(Operation::try_from(char).unwrap(), false, Value::None)
+                    }
+                    '-' => {
+                        process_term(op, neg, Ok(Term::var(&buffer)), errors);
+                        State::Term(Operation::Add, true, Value::None)
+                    }
+                    '(' => {
+                        process_term(op, neg, Ok(Term::var(&buffer)), errors);
+                        State::Term(Operation::Mul, false, Value::Brackets(1, String::new()))
+                    }
+                    any if any.is_whitespace() => {
+                        process_term(op, neg, Ok(Term::var(&buffer)), errors);
+                        State::AfterTerm
+                    }
+                    any => {
+                        errors.push(TryFromStrError::UnexpectedCharacter(any));
+                        State::Term(op, neg, Value::Identifier(buffer))
+                    }
                 },
             },
         }
@@ -237,24 +399,131 @@ pub fn parse_string(value: &str) -> Result<Term<u32>, TryFromStrError> {
     // Processes the final state the machine was left in.
     match state {
         State::Term(op, neg, val) => match val {
-            Value::None | Value::Brackets(_, _) => return Err(TryFromStrError::UnexpectedEof),
+            Value::None => errors.push(TryFromStrError::UnexpectedEof),
+            Value::Brackets(_, buffer) => {
+                errors.push(TryFromStrError::UnexpectedEof);
+                process_term(
+                    op,
+                    neg,
+                    Ok(parse_brackets(&buffer, implicit_multiplication, errors)),
+                    errors,
+                );
+            }
             Value::PreComma(buffer) => {
-                let term = Term::from(buffer.parse::<u32>().unwrap());
-                process_term(op, neg, term);
+                process_term(op, neg, integer(&buffer), errors);
             }
             Value::PostComma(pre, buffer) => {
-                let term = Term::from(pre)
-                    + Term::div(
-                        buffer.parse::<u32>().unwrap(),
-                        10u32.pow(buffer.len() as u32),
-                    );
-                process_term(op, neg, term);
+                process_term(op, neg, parse_decimal(pre, &buffer), errors);
+            }
+            Value::Identifier(buffer) => {
+                process_term(op, neg, Ok(Term::var(&buffer)), errors);
             }
         },
         State::AfterTerm => (),
     }
 
-    result += take(&mut working_term[0]);
+    match result.clone().checked_add(take(&mut working_term[0])) {
+        Some(sum) => sum,
+        None => {
+            errors.push(TryFromStrError::IntegerOverflow {
+                value: value.trim().to_string(),
+            });
+            result
+        }
+    }
+}
+
+/// Parses the digits of a number.
+fn parse_integer(buffer: &str) -> Result<u32, TryFromStrError> {
+    buffer
+        .parse::<u32>()
+        .map_err(|_| TryFromStrError::IntegerOverflow {
+            value: buffer.to_string(),
+        })
+}
+
+/// Creates a decimal number from the number before the comma and the digits after it.
+fn parse_decimal<
+    Num: Add<Output = Num>
+        + Sub<Output = Num>
+        + Mul<Output = Num>
+        + Div<Output = Num>
+        + Rem<Output = Num>
+        + Clone
+        + Default
+        + PartialOrd
+        + From<u32>,
+>(
+    pre: u32,
+    buffer: &str,
+) -> Result<Term<Num>, TryFromStrError> {
+    if buffer.is_empty() {
+        return Ok(Term::from(Num::from(pre)));
+    }
+    let digits = buffer.len() as u32;
+    if 10u32.checked_pow(digits).is_none() {
+        return Err(TryFromStrError::IntegerOverflow {
+            value: format!("{pre}.{buffer}"),
+        });
+    }
+    Ok(Term::from_decimal(pre, parse_integer(buffer)?, digits).convert())
+}
+
+/// Parses a formular containing bitwise operators outside of brackets, collecting all errors. Returns `None` if there are none.
+///
+/// `|` binds looser than `&`, which binds looser than `<<` and `>>`. All of them bind looser than arithmetic operations.
+/// A leading `~` inverts everything up to the next bitwise operator.
+fn parse_bitwise(
+    value: &str,
+    implicit_multiplication: bool,
+    errors: &mut Vec<TryFromStrError>,
+) -> Option<Term<u32>> {
+    let mut depth = 0usize;
+    let mut last_or = None;
+    let mut last_and = None;
+    let mut last_shift = None;
+
+    let mut chars = value.char_indices().peekable();
+    while let Some((index, char)) = chars.next() {
+        match char {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            '|' if depth == 0 => last_or = Some((index, 1)),
+            '&' if depth == 0 => last_and = Some((index, 1)),
+            '<' | '>' if depth == 0 && chars.next_if(|(_, next)| *next == char).is_some() => {
+                last_shift = Some((index, 2))
+            }
+            _ => (),
+        }
+    }
+
+    if let Some((index, length)) = last_or.or(last_and).or(last_shift) {
+        let lhs = parse_u32(&value[..index], implicit_multiplication, errors);
+        let rhs = parse_u32(&value[index + length..], implicit_multiplication, errors);
+
+        // shifting by the number of bits or more would overflow, so the shift is left out
+        if length == 2
+            && rhs
+                .clone()
+                .try_into_constant()
+                .is_ok_and(|bits| bits >= u32::BITS)
+        {
+            errors.push(TryFromStrError::IntegerOverflow {
+                value: value.trim().to_string(),
+            });
+            return Some(lhs);
+        }
+
+        return Some(match &value[index..index + length] {
+            "|" => lhs | rhs,
+            "&" => lhs & rhs,
+            "<<" => lhs << rhs,
+            _ => lhs >> rhs,
+        });
+    }
 
-    Ok(result)
+    value
+        .trim_start()
+        .strip_prefix('~')
+        .map(|rest| Term::from(u32::MAX) ^ parse_u32(rest, implicit_multiplication, errors))
 }
@@ -0,0 +1,138 @@
+use std::ops::{Add, Div, Mul, Rem, Sub};
+
+#[cfg(feature = "num-rational")]
+use num_integer::Integer;
+#[cfg(feature = "num-rational")]
+use num_rational::Ratio;
+#[cfg(feature = "num-rational")]
+use num_traits::CheckedSub;
+
+use crate::Term;
+
+/// A level of precision a term can be evaluated with. Used in [`Term::evaluate_with_precision`].
+pub trait Precision<
+    Num: Add<Output = Num>
+        + Sub<Output = Num>
+        + Mul<Output = Num>
+        + Div<Output = Num>
+        + Rem<Output = Num>
+        + Clone
+        + Default
+        + PartialOrd,
+>
+{
+    /// The type of the result.
+    type Output;
+
+    /// Evaluates the term.
+    fn evaluate(term: &Term<Num>) -> Self::Output;
+}
+
+/// Evaluates using 64 bit floating point numbers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Float64;
+
+/// Evaluates using 64 bit floating point numbers and rounds the result to 32 bits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Float32;
+
+/// Does not evaluate at all, returning the simplified term itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Exact;
+
+/// Evaluates into an exact fraction. Requires the `num-rational` feature.
+///
+/// Panics if the term is not a fraction of constant numbers, e.g. if it contains variables or irrational powers,
+/// or if it is negative and the numbers are unsigned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Rational;
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > Precision<Num> for Float64
+where
+    f64: From<Num>,
+{
+    type Output = f64;
+
+    fn evaluate(term: &Term<Num>) -> f64 {
+        term.calc_float()
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > Precision<Num> for Float32
+where
+    f64: From<Num>,
+{
+    type Output = f32;
+
+    fn evaluate(term: &Term<Num>) -> f32 {
+        // not every number type converts into f32, so the result is rounded instead
+        term.calc_float::<f64>() as f32
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > Precision<Num> for Exact
+{
+    type Output = Term<Num>;
+
+    fn evaluate(term: &Term<Num>) -> Term<Num> {
+        term.clone()
+    }
+}
+
+#[cfg(feature = "num-rational")]
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd
+            + Integer
+            + From<u8>
+            + CheckedSub,
+    > Precision<Num> for Rational
+{
+    type Output = Ratio<Num>;
+
+    fn evaluate(term: &Term<Num>) -> Ratio<Num> {
+        match term.constant_fraction() {
+            Some((false, numer, denom)) => Ratio::new(numer, denom),
+            Some((true, numer, denom)) => match Num::default().checked_sub(&numer) {
+                Some(numer) => Ratio::new(numer, denom),
+                None => panic!("Cannot represent a negative term as a ratio of unsigned numbers."),
+            },
+            None => panic!(
+                "Cannot represent a term which is not a fraction of constant numbers as a ratio."
+            ),
+        }
+    }
+}
@@ -0,0 +1,295 @@
+use std::fmt::{self, Display};
+
+use crate::{checked::Checked, operation::Operation, Term};
+
+/// Error when deserializing a term from corrupt bytes.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SerdeError {
+    /// The bytes ended while an operation was still incomplete.
+    UnexpectedEof,
+    /// A byte which is not the tag of any operation was encountered where an operation was expected.
+    UnknownTag(u8),
+    /// A length or count does not fit into a `usize`.
+    VarintOverflow,
+    /// The name of a variable is not valid UTF-8.
+    InvalidUtf8,
+    /// An operation is missing its children, e.g. an addition without summands.
+    MissingOperand,
+    /// A division by zero was encountered.
+    DivisionByZero,
+    /// There were bytes left after the term was complete.
+    TrailingBytes,
+    /// A number overflowed while rebuilding the term, or a number was shifted by at least 32 bits.
+    Overflow,
+    /// Operations are nested more than 256 levels deep.
+    NestingTooDeep,
+}
+
+impl Display for SerdeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SerdeError::UnexpectedEof => write!(f, "unexpected end of bytes"),
+            SerdeError::UnknownTag(tag) => write!(f, "unknown tag {tag:#04x}"),
+            SerdeError::VarintOverflow => write!(f, "varint too large"),
+            SerdeError::InvalidUtf8 => write!(f, "variable name is not valid UTF-8"),
+            SerdeError::MissingOperand => write!(f, "operation without operands"),
+            SerdeError::DivisionByZero => write!(f, "division by zero"),
+            SerdeError::TrailingBytes => write!(f, "trailing bytes after the term"),
+            SerdeError::Overflow => write!(f, "overflow while rebuilding the term"),
+            SerdeError::NestingTooDeep => write!(f, "operations nested too deeply"),
+        }
+    }
+}
+
+impl std::error::Error for SerdeError {}
+
+/// The maximum number of operations nested within each other when deserializing.
+///
+/// Deserialization recurses for every level, so deeper nesting is rejected instead of risking a stack overflow.
+const MAX_DEPTH: usize = 256;
+
+const NUMBER: u8 = 0x01;
+const ADDITION: u8 = 0x02;
+const MULTIPLICATION: u8 = 0x03;
+const DIVISION: u8 = 0x04;
+const NEGATION: u8 = 0x05;
+const VARIABLE: u8 = 0x06;
+const POWER: u8 = 0x07;
+const FLOOR_DIVISION: u8 = 0x08;
+const MUL_ADD: u8 = 0x09;
+const POW_MOD: u8 = 0x0a;
+const BIT_AND: u8 = 0x0b;
+const BIT_OR: u8 = 0x0c;
+const BIT_XOR: u8 = 0x0d;
+const SHL: u8 = 0x0e;
+const SHR: u8 = 0x0f;
+
+/// Writes an operation in the binary format described in `serialization.md`. Used in `Term::serialize_to_bytes`.
+pub fn serialize_operation(operation: &Operation<u32>, bytes: &mut Vec<u8>) {
+    match operation {
+        Operation::Number(num) => {
+            bytes.push(NUMBER);
+            bytes.extend_from_slice(&num.value.to_le_bytes());
+        }
+        Operation::Variable(var) => {
+            bytes.push(VARIABLE);
+            write_varint(var.name.len(), bytes);
+            bytes.extend_from_slice(var.name.as_bytes());
+        }
+        Operation::Addition(add) => serialize_list(ADDITION, &add.summands, bytes),
+        Operation::Multiplication(mul) => serialize_list(MULTIPLICATION, &mul.multipliers, bytes),
+        Operation::Division(div) => {
+            serialize_children(DIVISION, &[&div.divident, &div.divisor], bytes)
+        }
+        Operation::Negation(neg) => serialize_children(NEGATION, &[&neg.value], bytes),
+        Operation::RationalPower(pow) => {
+            bytes.push(POWER);
+            bytes.extend_from_slice(&pow.numer.to_le_bytes());
+            bytes.extend_from_slice(&pow.denom.to_le_bytes());
+            serialize_operation(&pow.base, bytes);
+        }
+        Operation::FloorDivision(div) => {
+            serialize_children(FLOOR_DIVISION, &[&div.divident, &div.divisor], bytes)
+        }
+        Operation::MulAdd(mul_add) => serialize_children(
+            MUL_ADD,
+            &[&mul_add.multiplicand, &mul_add.multiplier, &mul_add.summand],
+            bytes,
+        ),
+        Operation::PowMod(pow_mod) => serialize_children(
+            POW_MOD,
+            &[&pow_mod.base, &pow_mod.exp, &pow_mod.modulus],
+            bytes,
+        ),
+        Operation::BitAnd(bit) => serialize_children(BIT_AND, &[&bit.lhs, &bit.rhs], bytes),
+        Operation::BitOr(bit) => serialize_children(BIT_OR, &[&bit.lhs, &bit.rhs], bytes),
+        Operation::BitXor(bit) => serialize_children(BIT_XOR, &[&bit.lhs, &bit.rhs], bytes),
+        Operation::Shl(bit) => serialize_children(SHL, &[&bit.lhs, &bit.rhs], bytes),
+        Operation::Shr(bit) => serialize_children(SHR, &[&bit.lhs, &bit.rhs], bytes),
+    }
+}
+
+/// Writes the tag, the number of children and the children.
+fn serialize_list(tag: u8, children: &[Operation<u32>], bytes: &mut Vec<u8>) {
+    bytes.push(tag);
+    write_varint(children.len(), bytes);
+    for child in children {
+        serialize_operation(child, bytes);
+    }
+}
+
+/// Writes the tag and a fixed number of children.
+fn serialize_children(tag: u8, children: &[&Operation<u32>], bytes: &mut Vec<u8>) {
+    bytes.push(tag);
+    for child in children {
+        serialize_operation(child, bytes);
+    }
+}
+
+/// Writes an unsigned LEB128 varint: seven bits per byte, least significant first,
+/// with the highest bit set on every byte but the last.
+fn write_varint(mut value: usize, bytes: &mut Vec<u8>) {
+    while value >= 0x80 {
+        bytes.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+    bytes.push(value as u8);
+}
+
+/// Reads a term in the binary format described in `serialization.md`. Used in `Term::deserialize_from_bytes`.
+///
+/// The term is built using the operators of `Term`, so it is simplified.
+///
+/// Expected behavior:
+/// ```rust
+/// # use crem::*;
+/// assert_eq!(
+///     Term::deserialize_from_bytes(&[0x02, 0x02, 0x01, 2, 0, 0, 0, 0x06, 0x01, b'x'])?,
+///     Term::from(2) + Term::var("x")
+/// );
+/// assert_eq!(
+///     Term::deserialize_from_bytes(&[0x01, 2, 0]),
+///     Err(SerdeError::UnexpectedEof)
+/// );
+/// # Ok::<(), SerdeError>(())
+/// ```
+pub fn deserialize_term(bytes: &[u8]) -> Result<Term<u32>, SerdeError> {
+    let mut bytes = bytes.iter().copied();
+
+    // the term is rebuilt using numbers which detect overflows
+    let term = deserialize_node(&mut bytes, 0)?;
+
+    match bytes.next() {
+        None => Term::from_checked(term).ok_or(SerdeError::Overflow),
+        Some(_) => Err(SerdeError::TrailingBytes),
+    }
+}
+
+/// Reads a single operation, including all of its children.
+fn deserialize_node(
+    bytes: &mut impl Iterator<Item = u8>,
+    depth: usize,
+) -> Result<Term<Checked<u32>>, SerdeError> {
+    if depth >= MAX_DEPTH {
+        return Err(SerdeError::NestingTooDeep);
+    }
+    let zero = Term::from(Checked::from(0u32));
+
+    let tag = bytes.next().ok_or(SerdeError::UnexpectedEof)?;
+    match tag {
+        NUMBER => Ok(Term::from(Checked::from(read_u32(bytes)?))),
+        VARIABLE => {
+            let len = read_varint(bytes)?;
+            let name = bytes.by_ref().take(len).collect::<Vec<u8>>();
+            if name.len() < len {
+                return Err(SerdeError::UnexpectedEof);
+            }
+            match String::from_utf8(name) {
+                Ok(name) => Ok(Term::var(name)),
+                Err(_) => Err(SerdeError::InvalidUtf8),
+            }
+        }
+        ADDITION | MULTIPLICATION => {
+            let count = read_varint(bytes)?;
+            let mut children = Vec::new();
+            for _ in 0..count {
+                children.push(deserialize_node(bytes, depth + 1)?);
+            }
+            let mut children = children.into_iter();
+            let first = children.next().ok_or(SerdeError::MissingOperand)?;
+            if tag == ADDITION {
+                Ok(children.fold(first, |acc, term| acc + term))
+            } else {
+                Ok(children.fold(first, |acc, term| acc * term))
+            }
+        }
+        DIVISION | FLOOR_DIVISION => {
+            let divident = deserialize_node(bytes, depth + 1)?;
+            let divisor = deserialize_node(bytes, depth + 1)?;
+            if divisor == zero {
+                Err(SerdeError::DivisionByZero)
+            } else if tag == DIVISION {
+                Ok(divident / divisor)
+            } else {
+                Ok(Term::floor_div(divident, divisor))
+            }
+        }
+        NEGATION => Ok(-deserialize_node(bytes, depth + 1)?),
+        POWER => {
+            let numer = read_u32(bytes)?;
+            let denom = read_u32(bytes)?;
+            let base = deserialize_node(bytes, depth + 1)?;
+            if denom == 0 {
+                Err(SerdeError::DivisionByZero)
+            } else {
+                Ok(base.pow_rational(numer, denom))
+            }
+        }
+        MUL_ADD => {
+            let multiplicand = deserialize_node(bytes, depth + 1)?;
+            let multiplier = deserialize_node(bytes, depth + 1)?;
+            let summand = deserialize_node(bytes, depth + 1)?;
+            Ok(Term::mul_add(multiplicand, multiplier, summand))
+        }
+        POW_MOD => {
+            let base = deserialize_node(bytes, depth + 1)?;
+            let exp = deserialize_node(bytes, depth + 1)?;
+            let modulus = deserialize_node(bytes, depth + 1)?;
+            if modulus == zero {
+                Err(SerdeError::DivisionByZero)
+            } else {
+                Ok(Term::pow_mod_term(base, exp, modulus))
+            }
+        }
+        BIT_AND | BIT_OR | BIT_XOR | SHL | SHR => {
+            // bitwise operations cannot overflow, so they are applied to the unchecked operands
+            let lhs = Term::from_checked(deserialize_node(bytes, depth + 1)?);
+            let rhs = Term::from_checked(deserialize_node(bytes, depth + 1)?);
+            let (lhs, rhs): (Term<u32>, Term<u32>) = lhs.zip(rhs).ok_or(SerdeError::Overflow)?;
+            if matches!(tag, SHL | SHR)
+                && rhs
+                    .clone()
+                    .try_into_constant()
+                    .is_ok_and(|bits| bits >= u32::BITS)
+            {
+                return Err(SerdeError::Overflow);
+            }
+            Ok(match tag {
+                BIT_AND => lhs & rhs,
+                BIT_OR => lhs | rhs,
+                BIT_XOR => lhs ^ rhs,
+                SHL => lhs << rhs,
+                _ => lhs >> rhs,
+            }
+            .into_checked())
+        }
+        any => Err(SerdeError::UnknownTag(any)),
+    }
+}
+
+/// Reads a little endian `u32`.
+fn read_u32(bytes: &mut impl Iterator<Item = u8>) -> Result<u32, SerdeError> {
+    let mut buffer = [0; 4];
+    for byte in &mut buffer {
+        *byte = bytes.next().ok_or(SerdeError::UnexpectedEof)?;
+    }
+    Ok(u32::from_le_bytes(buffer))
+}
+
+/// Reads an unsigned LEB128 varint, as written by `write_varint`.
+fn read_varint(bytes: &mut impl Iterator<Item = u8>) -> Result<usize, SerdeError> {
+    let mut value: usize = 0;
+    let mut shift = 0;
+    loop {
+        let byte = bytes.next().ok_or(SerdeError::UnexpectedEof)?;
+        let bits = (byte & 0x7f) as usize;
+        if shift >= usize::BITS || (bits << shift) >> shift != bits {
+            return Err(SerdeError::VarintOverflow);
+        }
+        value |= bits << shift;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+        shift += 7;
+    }
+}
@@ -1,12 +1,55 @@
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+use std::{
+    cmp::Ordering,
+    collections::HashMap,
+    fmt::{self, Debug, Display},
+    hash::{Hash, Hasher},
+    mem::{swap, take},
+    ops::{
+        Add, AddAssign, BitAnd, BitOr, BitXor, Div, DivAssign, Mul, MulAssign, Neg, Rem, Shl, Shr,
+        Sub, SubAssign,
+    },
+    sync::Arc,
+};
+
+use num_traits::{
+    Bounded, CheckedAdd, CheckedMul, CheckedShl, CheckedShr, CheckedSub, Float, ToPrimitive,
+};
 
 use crate::{
+    bound_term::BoundTerm,
+    checked::Checked,
+    config::{ConfigGuard, TermConfig},
+    expr_node::{expr_node_from_operation, term_from_expr_node, ExprNode},
+    fnv::FnvHasher,
     operation::{
-        traits::{Calc, Convert, SetVars},
+        addition::Addition,
+        bitwise::{
+            bit_and_evaluator, bit_or_evaluator, bit_xor_evaluator, convert_keeping_evaluators,
+            shl_evaluator, shr_evaluator, BitOperation, BitOperator,
+        },
+        floor_division::FloorDivision,
+        mul_add::MulAdd,
+        multiplication::Multiplication,
+        multiply_skipping_one,
+        number::{exact_root, greatest_common_divisor, number_from_u32},
+        pow_mod::PowMod,
+        rational_power::{integral_power, RationalPower},
+        traits::{
+            ApplyRule, Calc, CalcBatch, CalcFloat, Convert, Diff, SetVars,
+            ToPolynomialCoefficients, ToPrefixNotation, Unify, Walk, WalkMut,
+        },
+        trim_coefficients,
         variable::Variable,
         Operation,
     },
-    parse_string::{parse_string, TryFromStrError},
+    parse_prefix_notation::parse_prefix_notation,
+    parse_string::{
+        parse_string, parse_string_collecting_errors, parse_string_i64,
+        parse_string_with_implicit_multiplication, TryFromStrError,
+    },
+    precision::Precision,
+    serialize_bytes::{deserialize_term, serialize_operation, SerdeError},
+    term_diff::TermDiff,
 };
 
 /// A mathematical term.
@@ -47,6 +90,323 @@ impl Term<u32> {
     ) -> Result<Output, TryFromStrError> {
         Ok(Term::try_from(term)?.calc())
     }
+
+    /// Parses a formular, collecting all errors instead of stopping at the first one.
+    ///
+    /// Returns a best-effort term alongside the errors, in the order they were encountered.
+    /// Illegal characters and numbers which are too large are skipped, divisions by zero are left out
+    /// and unclosed brackets are closed at the end of the formular.
+    ///
+    /// ```rust
+    /// # use crem::*;
+    /// let (term, errors) = Term::parse_collecting_errors("2 $ + 3 / 0 + (4");
+    /// assert_eq!(term, Term::from(9));
+    /// assert_eq!(
+    ///     errors,
+    ///     vec![
+    ///         TryFromStrError::UnexpectedCharacter('$'),
+    ///         TryFromStrError::DivisionByZero,
+    ///         TryFromStrError::UnexpectedEof,
+    ///     ]
+    /// );
+    /// ```
+    pub fn parse_collecting_errors(value: &str) -> (Self, Vec<TryFromStrError>) {
+        parse_string_collecting_errors(value)
+    }
+
+    /// Checks a formular for errors, returning all of them in the order they were encountered.
+    ///
+    /// Returns an empty list if the formular is valid. The errors are the same as those of
+    /// [`parse_collecting_errors`](Term::parse_collecting_errors), but the term is discarded.
+    ///
+    /// ```rust
+    /// # use crem::*;
+    /// assert_eq!(Term::validate_syntax("2 * (3 + 4)"), vec![]);
+    /// assert_eq!(
+    ///     Term::validate_syntax("2 $ + 3 / (1 - 1"),
+    ///     vec![
+    ///         TryFromStrError::UnexpectedCharacter('$'),
+    ///         TryFromStrError::UnexpectedEof,
+    ///         TryFromStrError::DivisionByZero,
+    ///     ]
+    /// );
+    /// ```
+    pub fn validate_syntax(value: &str) -> Vec<TryFromStrError> {
+        parse_string_collecting_errors(value).1
+    }
+
+    /// Parses a fraction in strict `p/q` notation, where `p` and `q` are non-negative integers.
+    ///
+    /// No whitespace, signs or other operations are allowed. Use [`try_from`](Term::try_from) for formulars.
+    ///
+    /// ```rust
+    /// # use crem::*;
+    /// assert_eq!(Term::parse_fraction_string("22/7")?, Term::div(22, 7));
+    /// assert_eq!(Term::parse_fraction_string("6/4")?, Term::div(3, 2));
+    /// assert_eq!(
+    ///     Term::parse_fraction_string("2 / 3"),
+    ///     Err(TryFromStrError::UnexpectedCharacter(' '))
+    /// );
+    /// assert_eq!(Term::parse_fraction_string("1/0"), Err(TryFromStrError::DivisionByZero));
+    /// # Ok::<(), TryFromStrError>(())
+    /// ```
+    pub fn parse_fraction_string(value: &str) -> Result<Self, TryFromStrError> {
+        // `after` is the character following the part, if any
+        let parse_part = |part: &str, after: Option<char>| {
+            if let Some(any) = part.chars().find(|char| !char.is_ascii_digit()) {
+                return Err(TryFromStrError::UnexpectedCharacter(any));
+            }
+            if part.is_empty() {
+                return Err(after.map_or(
+                    TryFromStrError::UnexpectedEof,
+                    TryFromStrError::UnexpectedCharacter,
+                ));
+            }
+            part.parse::<u32>()
+                .map_err(|_| TryFromStrError::IntegerOverflow {
+                    value: part.to_string(),
+                })
+        };
+
+        let (numer, denom) = match value.split_once('/') {
+            Some((numer, denom)) => (parse_part(numer, Some('/'))?, parse_part(denom, None)?),
+            None => {
+                parse_part(value, None)?;
+                return Err(TryFromStrError::UnexpectedEof);
+            }
+        };
+
+        if denom == 0 {
+            return Err(TryFromStrError::DivisionByZero);
+        }
+        Ok(Term::div(numer, denom))
+    }
+
+    /// Parses a formular, allowing variables and implicit multiplication of a number with a variable.
+    ///
+    /// A number immediately followed by a variable name (`2x`) is multiplied with it.
+    ///
+    /// ```rust
+    /// # use crem::*;
+    /// let term = Term::parse_with_implicit_multiplication("2x + 3y")?;
+    /// assert_eq!(term, Term::from(2) * Term::var("x") + Term::from(3) * Term::var("y"));
+    /// assert_eq!(
+    ///     Term::try_from("2x"),
+    ///     Err(TryFromStrError::UnexpectedCharacter('x'))
+    /// );
+    /// # Ok::<(), TryFromStrError>(())
+    /// ```
+    pub fn parse_with_implicit_multiplication(value: &str) -> Result<Self, TryFromStrError> {
+        parse_string_with_implicit_multiplication(value)
+    }
+
+    /// Parses a term written in prefix notation, e.g. `(+ (* 2 x) 1)`.
+    ///
+    /// This is the inverse of [`to_prefix_notation`](Term::to_prefix_notation).
+    ///
+    /// ```rust
+    /// # use crem::*;
+    /// let term = Term::from(2) * Term::var("x") + Term::from(1);
+    /// assert_eq!(Term::from_prefix_notation(&term.to_prefix_notation())?, term);
+    /// # Ok::<(), TryFromStrError>(())
+    /// ```
+    pub fn from_prefix_notation(value: &str) -> Result<Self, TryFromStrError> {
+        parse_prefix_notation(value)
+    }
+
+    /// Creates the decimal number `integer_part.decimal_part`, where `decimal_part` has `decimal_digits` digits.
+    ///
+    /// The fraction is reduced to its lowest terms immediately.
+    /// Panics if `10^decimal_digits` does not fit into a `u32`.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// assert_eq!(Term::from_decimal(3, 14, 2), Term::from(3) + Term::div(7, 50));
+    /// assert_eq!(Term::from_decimal(0, 5, 1), Term::div(1, 2));
+    /// assert_eq!(Term::from_decimal(0, 5, 2), Term::div(1, 20));
+    /// ```
+    pub fn from_decimal(integer_part: u32, decimal_part: u32, decimal_digits: u32) -> Self {
+        Term::from(integer_part) + Term::div(decimal_part, 10u32.pow(decimal_digits))
+    }
+
+    /// Creates the fraction with a denominator of at most `max_denominator` closest to the given value.
+    ///
+    /// Returns `None` if the value is not finite or its absolute value does not fit into a `u32`.
+    /// Denominators are limited to `u32::MAX`, and at least 1 is always allowed.
+    /// See [`approximate_as_simple_fraction`](Term::approximate_as_simple_fraction).
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// assert_eq!(Term::try_from_float(0.75, 100), Some(Term::div(3, 4)));
+    /// assert_eq!(Term::try_from_float(3.0, 100), Some(Term::from(3)));
+    /// assert_eq!(Term::try_from_float(-0.3333, 10), Some(-Term::div(1, 3)));
+    /// assert_eq!(Term::try_from_float(f64::NAN, 10), None);
+    /// ```
+    pub fn try_from_float(value: f64, max_denominator: u64) -> Option<Self> {
+        if !value.is_finite() || value.abs() > u32::MAX as f64 {
+            return None;
+        }
+        let max_denominator = u32::try_from(max_denominator).unwrap_or(u32::MAX).max(1);
+        Some(Term::from(value).approximate_as_simple_fraction(max_denominator))
+    }
+
+    /// Calculates every term using 64 bit floating point numbers.
+    ///
+    /// Panics if any of the terms contains variables. See [`try_into_f64_vec`](Term::try_into_f64_vec).
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let terms = vec![Term::from(2), Term::div(1, 4), -Term::from(3)];
+    /// assert_eq!(Term::into_f64_vec(terms), vec![2.0, 0.25, -3.0]);
+    /// ```
+    pub fn into_f64_vec(terms: Vec<Term<u32>>) -> Vec<f64> {
+        terms.iter().map(Term::calc).collect()
+    }
+
+    /// Calculates every term using 64 bit integers. Divisions are rounded towards zero.
+    ///
+    /// Panics if any of the terms contains variables.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let terms = vec![Term::from(2), Term::from(7) / Term::from(2), -Term::from(3)];
+    /// assert_eq!(Term::into_i64_vec(terms), vec![2, 3, -3]);
+    /// ```
+    pub fn into_i64_vec(terms: Vec<Term<u32>>) -> Vec<i64> {
+        terms.iter().map(Term::calc).collect()
+    }
+
+    /// Calculates every term using 64 bit floating point numbers.
+    ///
+    /// Returns an error containing the variables of the first term which contains any.
+    ///
+    /// ```rust
+    /// # use crem::*;
+    /// let terms = vec![Term::from(2), Term::div(1, 4)];
+    /// assert_eq!(Term::try_into_f64_vec(terms), Ok(vec![2.0, 0.25]));
+    ///
+    /// let terms = vec![Term::from(2), Term::var("x")];
+    /// assert_eq!(
+    ///     Term::try_into_f64_vec(terms),
+    ///     Err(UnboundVariableError {
+    ///         variables: vec![String::from("x")]
+    ///     })
+    /// );
+    /// ```
+    pub fn try_into_f64_vec(terms: Vec<Term<u32>>) -> Result<Vec<f64>, UnboundVariableError> {
+        terms.iter().map(Term::try_calc).collect()
+    }
+
+    /// Serializes the term into a compact binary format, described in `serialization.md`.
+    ///
+    /// Every operation is written as a one byte tag followed by its data and children.
+    ///
+    /// ```rust
+    /// # use crem::*;
+    /// let term = Term::from(2) * Term::var("x") + Term::from(1);
+    /// let bytes = term.serialize_to_bytes();
+    /// assert_eq!(Term::deserialize_from_bytes(&bytes)?, term);
+    /// assert_eq!(Term::from(5).serialize_to_bytes(), vec![0x01, 5, 0, 0, 0]);
+    /// # Ok::<(), SerdeError>(())
+    /// ```
+    pub fn serialize_to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        serialize_operation(&self.operation, &mut bytes);
+        bytes
+    }
+
+    /// Deserializes a term written by [`serialize_to_bytes`](Term::serialize_to_bytes).
+    ///
+    /// Returns an error if the bytes are corrupt.
+    ///
+    /// ```rust
+    /// # use crem::*;
+    /// assert_eq!(
+    ///     Term::deserialize_from_bytes(&[0x06, 0x01, b'x'])?,
+    ///     Term::var("x")
+    /// );
+    /// assert_eq!(
+    ///     Term::deserialize_from_bytes(&[0xff]),
+    ///     Err(SerdeError::UnknownTag(0xff))
+    /// );
+    /// # Ok::<(), SerdeError>(())
+    /// ```
+    pub fn deserialize_from_bytes(bytes: &[u8]) -> Result<Self, SerdeError> {
+        deserialize_term(bytes)
+    }
+
+    /// Creates a term from an expression tree. Simplifies if possible.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the tree contains a division by zero.
+    ///
+    /// ```rust
+    /// # use crem::*;
+    /// // (x + 3) * 2
+    /// let node = ExprNode::Mul(
+    ///     Box::new(ExprNode::Add(
+    ///         Box::new(ExprNode::Var(String::from("x"))),
+    ///         Box::new(ExprNode::Num(3)),
+    ///     )),
+    ///     Box::new(ExprNode::Num(2)),
+    /// );
+    /// assert_eq!(
+    ///     Term::from_expression_tree(node),
+    ///     (Term::var("x") + Term::from(3)) * Term::from(2)
+    /// );
+    /// ```
+    pub fn from_expression_tree(node: ExprNode) -> Self {
+        term_from_expr_node(node)
+    }
+
+    /// Converts the term into an expression tree, the inverse of [`from_expression_tree`](Term::from_expression_tree).
+    ///
+    /// Sums and products are written as nested binary nodes, from left to right.
+    /// Returns `None` if the term contains powers, floor divisions, modular exponentiations or bitwise operations,
+    /// as these cannot be represented.
+    ///
+    /// Only simplified expression trees survive the round trip, as [`from_expression_tree`](Term::from_expression_tree) simplifies.
+    /// E.g. `a * 1 + 0` comes back as `a * 1`.
+    ///
+    /// ```rust
+    /// # use crem::*;
+    /// let term = Term::var("x") / Term::from(2);
+    /// assert_eq!(
+    ///     term.clone().into_expression_tree(),
+    ///     Some(ExprNode::Div(
+    ///         Box::new(ExprNode::Var(String::from("x"))),
+    ///         Box::new(ExprNode::Num(2)),
+    ///     ))
+    /// );
+    /// assert_eq!(
+    ///     term.clone().into_expression_tree().map(Term::from_expression_tree),
+    ///     Some(term)
+    /// );
+    ///
+    /// assert_eq!(Term::var("x").pow_rational(1, 2).into_expression_tree(), None);
+    /// ```
+    pub fn into_expression_tree(self) -> Option<ExprNode> {
+        expr_node_from_operation(self.operation)
+    }
+}
+
+impl Term<i64> {
+    /// Parses a formular using signed integers, so that large subtractions cannot overflow.
+    ///
+    /// Every number written within the formular has to fit into a `u32`. Bitwise operators are not supported.
+    /// Negative results are negations, just like the difference of two numbers.
+    ///
+    /// ```rust
+    /// # use crem::*;
+    /// assert_eq!(Term::from_str_i64("3 - 5")?, Term::from(3i64) - Term::from(5));
+    /// assert_eq!(Term::from_str_i64("3 - 5")?.calc::<i64>(), -2);
+    /// assert_eq!(Term::from_str_i64("(3 - 5) * 4")?.calc::<i64>(), -8);
+    /// # Ok::<(), TryFromStrError>(())
+    /// ```
+    pub fn from_str_i64(value: &str) -> Result<Self, TryFromStrError> {
+        parse_string_i64(value)
+    }
 }
 
 impl<
@@ -61,6 +421,8 @@ impl<
     > Term<Num>
 {
     /// Converts the internal number type.
+    ///
+    /// Bitwise operations which are left cannot be evaluated afterwards, as the new number type might not support them.
     pub fn convert<
         T: Add<Output = T>
             + Sub<Output = T>
@@ -75,11 +437,17 @@ impl<
         self,
     ) -> Term<T> {
         Term {
-            operation: self.operation.convert(),
+            operation: self.operation.convert(&mut T::from),
         }
     }
 
     /// Calculates the result of the term.
+    ///
+    /// Panics if the term contains a rational power which is irrational or still contains variables,
+    /// use [`calc_float`](Term::calc_float) for those.
+    ///
+    /// Fused multiply-adds are calculated as a multiplication followed by an addition,
+    /// use [`calc_float`](Term::calc_float) to calculate them without rounding the product.
     pub fn calc<
         Output: Add<Output = Output>
             + Sub<Output = Output>
@@ -93,6 +461,88 @@ impl<
         self.operation.calc()
     }
 
+    /// Calculates the result of the term, if it contains no variables.
+    ///
+    /// # Panics
+    ///
+    /// Panics in the same cases as [`calc`](Term::calc), even though the term contains no variables:
+    /// If the term contains irrational powers, or floor divisions, modular exponentiations or bitwise operations
+    /// which could not be simplified to a number, e.g. shifts by too many bits.
+    /// Use [`calc_float`](Term::calc_float) for those.
+    ///
+    /// ```rust
+    /// # use crem::*;
+    /// assert_eq!(Term::from(3u32).try_calc::<f64>(), Ok(3.0));
+    /// assert_eq!(
+    ///     (Term::<u32>::var("x") * Term::var("y")).try_calc::<f64>(),
+    ///     Err(UnboundVariableError {
+    ///         variables: vec![String::from("x"), String::from("y")]
+    ///     })
+    /// );
+    /// ```
+    pub fn try_calc<
+        Output: Add<Output = Output>
+            + Sub<Output = Output>
+            + Mul<Output = Output>
+            + Div<Output = Output>
+            + Neg<Output = Output>
+            + From<Num>,
+    >(
+        &self,
+    ) -> Result<Output, UnboundVariableError> {
+        let variables = self.variable_names();
+        if variables.is_empty() {
+            Ok(self.calc())
+        } else {
+            Err(UnboundVariableError { variables })
+        }
+    }
+
+    /// Calculates the result of the term using floating point numbers.
+    ///
+    /// Unlike [`calc`](Term::calc), this is able to calculate rational powers.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// assert_eq!(Term::from(8u32).pow_rational(1, 3).calc_float::<f64>(), 2.0);
+    /// ```
+    pub fn calc_float<Output: Float + From<Num>>(&self) -> Output {
+        self.operation.calc_float()
+    }
+
+    /// Evaluates the term with the given level of precision.
+    ///
+    /// [`Float64`](crate::Float64) and [`Float32`](crate::Float32) calculate a floating point result,
+    /// [`Exact`](crate::Exact) returns the term itself and [`Rational`](crate::Rational) returns an exact fraction if the `num-rational` feature is enabled.
+    ///
+    /// ```rust
+    /// # use crem::*;
+    /// let term = Term::from(1u32) / Term::from(3);
+    /// assert_eq!(term.evaluate_with_precision::<Float64>(), 1.0 / 3.0);
+    /// assert_eq!(term.evaluate_with_precision::<Float32>(), 1.0 / 3.0);
+    /// assert_eq!(term.evaluate_with_precision::<Exact>(), Term::div(1, 3));
+    /// ```
+    pub fn evaluate_with_precision<P: Precision<Num>>(&self) -> P::Output {
+        P::evaluate(self)
+    }
+
+    /// Calculates the result of the term for many values of a single variable at once.
+    ///
+    /// The term is traversed only once, calculating every node for all values before moving on to its parent.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let term = Term::<u32>::var("x") * Term::var("x") + Term::from(1);
+    /// assert_eq!(term.calc_batch("x", &[0.0, 1.0, 2.0, 3.0]), [1.0, 2.0, 5.0, 10.0]);
+    /// ```
+    pub fn calc_batch<Output: Float + From<Num>, const N: usize>(
+        &self,
+        var: &str,
+        values: &[Output; N],
+    ) -> [Output; N] {
+        self.operation.calc_batch(var, values)
+    }
+
     /// Replaces all matching variables with the given term, and calculates the result.
     pub fn use_var<
         Output: Add<Output = Output>
@@ -109,96 +559,4094 @@ impl<
         self.operation.set_vars(&[(name, &term.operation)]).calc()
     }
 
-    /// Replaces all matching variables with the given term.
-    pub fn with_var(&self, name: &str, term: &Term<Num>) -> Self {
-        Term {
-            operation: self.operation.set_vars(&[(name, &term.operation)]),
+    /// Replaces all matching variables with the given numbers, and calculates the result.
+    ///
+    /// Returns `default` instead if any variables are left without a value.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let term = Term::var("x") * Term::from(2) + Term::var("y");
+    /// assert_eq!(term.evaluate_with_fallback::<f64>(&[("x", 3), ("y", 1)], 0.0), 7.0);
+    /// assert_eq!(term.evaluate_with_fallback::<f64>(&[("x", 3)], 0.0), 0.0);
+    /// ```
+    pub fn evaluate_with_fallback<
+        Output: Add<Output = Output>
+            + Sub<Output = Output>
+            + Mul<Output = Output>
+            + Div<Output = Output>
+            + Neg<Output = Output>
+            + From<Num>,
+    >(
+        &self,
+        vars: &[(&str, Num)],
+        default: Output,
+    ) -> Output {
+        let values: Vec<Operation<Num>> = vars
+            .iter()
+            .map(|(_, value)| Operation::from(value.clone()))
+            .collect();
+        let vars_as_ops: Vec<(&str, &Operation<Num>)> = vars
+            .iter()
+            .zip(&values)
+            .map(|((name, _), value)| (*name, value))
+            .collect();
+
+        let term = Term {
+            operation: self.operation.set_vars(&vars_as_ops),
+        };
+        if term.variable_names().is_empty() {
+            term.calc()
+        } else {
+            default
+        }
+    }
+
+    /// Replaces every variable with zero, and returns the result if it is a single number.
+    ///
+    /// Returns `None` if a divisor or modulus is zero at the origin, or if the result is not a single number,
+    /// e.g. a fraction, or a negation for unsigned number types. See [`try_into_constant`](Term::try_into_constant).
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let x = || Term::<u32>::var("x");
+    /// assert_eq!((Term::from(2) * x() + Term::from(5)).eval_at_origin(), Some(5));
+    /// assert_eq!((Term::from(5) / (x() + Term::var("y"))).eval_at_origin(), None);
+    /// assert_eq!((x() + Term::from(1) / Term::from(2)).eval_at_origin(), None);
+    ///
+    /// assert_eq!((Term::<i32>::var("x") - Term::from(3)).eval_at_origin(), Some(-3));
+    /// ```
+    pub fn eval_at_origin(&self) -> Option<Num>
+    where
+        Num: Bounded,
+    {
+        let names = self.variable_names();
+        let zero = Operation::default();
+        let zeros: Vec<(&str, &Operation<Num>)> =
+            names.iter().map(|name| (name.as_str(), &zero)).collect();
+
+        // divisors are visited before the divisions containing them, so they can be set to zero safely
+        let mut divides_by_zero = false;
+        self.operation.walk(&mut |op| {
+            let divisor = match op {
+                Operation::Division(div) => &div.divisor,
+                Operation::FloorDivision(div) => &div.divisor,
+                Operation::PowMod(pow_mod) => &pow_mod.modulus,
+                _ => return,
+            };
+            if !divides_by_zero && divisor.set_vars(&zeros) == zero {
+                divides_by_zero = true;
+            }
+        });
+        if divides_by_zero {
+            return None;
+        }
+
+        constant_number(&self.operation.set_vars(&zeros))
+    }
+
+    /// Replaces all matching variables with the given term.
+    pub fn with_var(&self, name: &str, term: &Term<Num>) -> Self {
+        Term {
+            operation: self.operation.set_vars(&[(name, &term.operation)]),
+        }
+    }
+
+    /// Replaces all matching variables with the given term.
+    pub fn set_var(&mut self, name: &str, term: &Term<Num>) -> &Self {
+        self.operation = self.operation.set_vars(&[(name, &term.operation)]);
+        self
+    }
+
+    /// Replaces all matching variables with the given terms, and calculates the result.
+    pub fn use_vars<
+        Output: Add<Output = Output>
+            + Sub<Output = Output>
+            + Mul<Output = Output>
+            + Div<Output = Output>
+            + Neg<Output = Output>
+            + From<Num>,
+    >(
+        &self,
+        variables: &[(&str, &Term<Num>)],
+    ) -> Output {
+        let vars_as_ops: Vec<(&str, &Operation<Num>)> = variables
+            .iter()
+            .map(|var| (var.0, &var.1.operation))
+            .collect();
+
+        self.operation.set_vars(&vars_as_ops).calc()
+    }
+
+    /// Replaces the variables with the given numbers, and calculates the result.
+    ///
+    /// Unlike [`use_vars`](Term::use_vars), the bindings are kept on the stack, avoiding allocations for them.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let term = Term::<u32>::var("x") * Term::var("y") + Term::var("z");
+    /// assert_eq!(term.evaluate_multi::<3, f64>([("x", 2), ("y", 3), ("z", 4)]), 10.0);
+    /// ```
+    pub fn evaluate_multi<
+        const N: usize,
+        Output: Add<Output = Output>
+            + Sub<Output = Output>
+            + Mul<Output = Output>
+            + Div<Output = Output>
+            + Neg<Output = Output>
+            + From<Num>,
+    >(
+        &self,
+        vars: [(&str, Num); N],
+    ) -> Output {
+        let operations = vars.map(|(name, value)| (name, Operation::from(value)));
+        let vars_as_ops = operations
+            .each_ref()
+            .map(|(name, operation)| (*name, operation));
+
+        self.operation.set_vars(&vars_as_ops).calc()
+    }
+
+    /// Replaces the variable with the given number in every term, and calculates the results.
+    ///
+    /// With the `rayon` feature enabled, the terms are evaluated in parallel.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let terms = [Term::<u32>::var("x") + Term::from(1), Term::var("x") * Term::from(3)];
+    /// assert_eq!(Term::parallel_eval::<f64>(&terms, "x", 2), vec![3.0, 6.0]);
+    /// ```
+    #[cfg(feature = "rayon")]
+    pub fn parallel_eval<
+        Output: Add<Output = Output>
+            + Sub<Output = Output>
+            + Mul<Output = Output>
+            + Div<Output = Output>
+            + Neg<Output = Output>
+            + From<Num>
+            + Send,
+    >(
+        terms: &[Term<Num>],
+        var: &str,
+        value: Num,
+    ) -> Vec<Output>
+    where
+        Num: Send + Sync,
+    {
+        use rayon::prelude::*;
+
+        let value = Term::from(value);
+        terms
+            .par_iter()
+            .map(|term| term.use_var(var, &value))
+            .collect()
+    }
+
+    /// Replaces the variable with the given number in every term, and calculates the results.
+    ///
+    /// With the `rayon` feature enabled, the terms are evaluated in parallel.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let terms = [Term::<u32>::var("x") + Term::from(1), Term::var("x") * Term::from(3)];
+    /// assert_eq!(Term::parallel_eval::<f64>(&terms, "x", 2), vec![3.0, 6.0]);
+    /// ```
+    #[cfg(not(feature = "rayon"))]
+    pub fn parallel_eval<
+        Output: Add<Output = Output>
+            + Sub<Output = Output>
+            + Mul<Output = Output>
+            + Div<Output = Output>
+            + Neg<Output = Output>
+            + From<Num>
+            + Send,
+    >(
+        terms: &[Term<Num>],
+        var: &str,
+        value: Num,
+    ) -> Vec<Output>
+    where
+        Num: Send + Sync,
+    {
+        let value = Term::from(value);
+        terms.iter().map(|term| term.use_var(var, &value)).collect()
+    }
+
+    /// Replaces all matching variables with the given terms.
+    pub fn with_vars(&self, variables: &[(&str, &Term<Num>)]) -> Self {
+        let vars_as_ops: Vec<(&str, &Operation<Num>)> = variables
+            .iter()
+            .map(|var| (var.0, &var.1.operation))
+            .collect();
+
+        Term {
+            operation: self.operation.set_vars(&vars_as_ops),
+        }
+    }
+
+    /// Replaces all matching variables with the given owned terms, and calculates the result.
+    pub fn use_vars_owned<
+        Output: Add<Output = Output>
+            + Sub<Output = Output>
+            + Mul<Output = Output>
+            + Div<Output = Output>
+            + Neg<Output = Output>
+            + From<Num>,
+    >(
+        &self,
+        variables: Vec<(String, Term<Num>)>,
+    ) -> Output {
+        let vars_as_ops: Vec<(&str, &Operation<Num>)> = variables
+            .iter()
+            .map(|var| (var.0.as_str(), &var.1.operation))
+            .collect();
+
+        self.operation.set_vars(&vars_as_ops).calc()
+    }
+
+    /// Replaces all matching variables with the given owned terms.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let term = Term::var("x") + Term::var("y");
+    /// let variables = vec![
+    ///     (String::from("x"), Term::from(2)),
+    ///     (String::from("y"), Term::from(3)),
+    /// ];
+    /// assert_eq!(term.with_vars_owned(variables), Term::from(5));
+    /// ```
+    pub fn with_vars_owned(&self, variables: Vec<(String, Term<Num>)>) -> Self {
+        let vars_as_ops: Vec<(&str, &Operation<Num>)> = variables
+            .iter()
+            .map(|var| (var.0.as_str(), &var.1.operation))
+            .collect();
+
+        Term {
+            operation: self.operation.set_vars(&vars_as_ops),
+        }
+    }
+
+    /// Replaces all matching variables with the given terms, and simplifies the whole term again afterwards.
+    ///
+    /// Simplifications only made possible by the combination of several substituted values are applied as well.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let term = Term::<u32>::var("x") * (Term::from(2) + Term::var("y"));
+    /// assert_eq!(
+    ///     term.substitute_and_simplify(&[("x", Term::from(0)), ("y", Term::from(3))]),
+    ///     Term::from(0)
+    /// );
+    /// ```
+    pub fn substitute_and_simplify(&self, substitutions: &[(&str, Term<Num>)]) -> Self {
+        let vars_as_ops: Vec<(&str, &Operation<Num>)> = substitutions
+            .iter()
+            .map(|var| (var.0, &var.1.operation))
+            .collect();
+
+        // substituting no variables rebuilds every operation, simplifying it again
+        Term {
+            operation: self.operation.set_vars(&vars_as_ops).set_vars(&[]),
+        }
+    }
+
+    /// Checks whether the term is in its simplified form, i.e. whether simplifying it again leaves it unchanged.
+    ///
+    /// Terms only built using the operators and constructors of `Term` are always simplified,
+    /// unless simplification was limited using [`simplification_depth_limit`](Term::simplification_depth_limit).
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let term = Term::from(2u32) * Term::var("x") + Term::from(1);
+    /// assert!(term.is_simplified());
+    ///
+    /// let guard = Term::<u32>::with_config(Term::<u32>::simplification_depth_limit(0));
+    /// let unsimplified = Term::from(1u32) + Term::from(2);
+    /// drop(guard);
+    /// assert!(!unsimplified.is_simplified());
+    /// ```
+    pub fn is_simplified(&self) -> bool {
+        // substituting no variables rebuilds every operation, simplifying it again
+        self.operation.set_vars(&[]) == self.operation
+    }
+
+    /// Counts the nodes of the term, i.e. all of its operations, numbers and variables.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// // 2, x, 2 * x, 1, 2 * x + 1
+    /// assert_eq!((Term::from(2u32) * Term::var("x") + Term::from(1)).node_count(), 5);
+    /// ```
+    pub fn node_count(&self) -> usize {
+        let mut count = 0;
+        self.operation.walk(&mut |_| count += 1);
+        count
+    }
+
+    /// Counts the numbers, variables and other operations within the term in a single traversal.
+    ///
+    /// Every variable is counted, even multiple occurrences of the same one.
+    ///
+    /// ```rust
+    /// # use crem::*;
+    /// let term = Term::from(2u32) * Term::var("x") * Term::var("x") + Term::from(1);
+    /// assert_eq!(
+    ///     term.count_operations(),
+    ///     OperationCounts {
+    ///         numbers: 2,
+    ///         variables: 2,
+    ///         operations: 2,
+    ///     }
+    /// );
+    /// ```
+    pub fn count_operations(&self) -> OperationCounts {
+        let mut counts = OperationCounts::default();
+        self.operation.walk(&mut |op| match op {
+            Operation::Number(_) => counts.numbers += 1,
+            Operation::Variable(_) => counts.variables += 1,
+            _ => counts.operations += 1,
+        });
+        counts
+    }
+
+    /// Counts the numbers within the term. See [`count_operations`](Term::count_operations).
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// assert_eq!((Term::from(2u32) * Term::var("x") + Term::from(1)).number_count(), 2);
+    /// ```
+    pub fn number_count(&self) -> usize {
+        self.count_operations().numbers
+    }
+
+    /// Counts the variables within the term. Every occurrence is counted, even of the same variable.
+    /// See [`count_operations`](Term::count_operations).
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let x = || Term::<u32>::var("x");
+    /// assert_eq!((Term::from(2) * x() * x() + Term::var("y")).variable_count(), 3);
+    /// ```
+    pub fn variable_count(&self) -> usize {
+        self.count_operations().variables
+    }
+
+    /// Calculates by which share the number of nodes shrank from `before` to `after`.
+    ///
+    /// A ratio of `0.5` means the term was halved, while a negative ratio means it grew.
+    /// See [`node_count`](Term::node_count).
+    ///
+    /// ```rust
+    /// # use crem::*;
+    /// let mut before = Term::<u32>::var("x") * Term::from(2) / Term::from(3);
+    /// before.walk_mut(|op| {
+    ///     if let Operation::Number(num) = op {
+    ///         num.value = 1;
+    ///     }
+    /// });
+    /// let after = before.simplify();
+    /// assert_eq!(Term::tree_size_reduction_ratio(&before, &after), 0.8);
+    /// ```
+    pub fn tree_size_reduction_ratio(before: &Term<Num>, after: &Term<Num>) -> f64 {
+        1.0 - after.node_count() as f64 / before.node_count() as f64
+    }
+
+    /// Prints the node counts and depths of the terms before and after simplifying them,
+    /// and by which share they shrank, to the standard error.
+    ///
+    /// See [`tree_size_reduction_ratio`](Term::tree_size_reduction_ratio).
+    pub fn print_simplification_stats(before: &Term<Num>, after: &Term<Num>) {
+        eprintln!(
+            "nodes: {} -> {}, depth: {} -> {}, reduction: {:.1}%",
+            before.node_count(),
+            after.node_count(),
+            before.operation.depth(),
+            after.operation.depth(),
+            Term::tree_size_reduction_ratio(before, after) * 100.0
+        );
+    }
+
+    /// Panics if the term is not in its simplified form. Does nothing in release builds.
+    ///
+    /// See [`is_simplified`](Term::is_simplified).
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// (Term::from(2u32) * Term::var("x") + Term::from(1)).assert_simplified();
+    /// ```
+    pub fn assert_simplified(&self)
+    where
+        Num: Debug,
+    {
+        debug_assert!(
+            self.is_simplified(),
+            "Term is not simplified: {:?}, simplified it would be {:?}",
+            self,
+            Term {
+                operation: self.operation.set_vars(&[])
+            }
+        );
+    }
+
+    /// Replaces all matching variables with the given terms.
+    pub fn set_vars(&mut self, variables: &[(&str, &Term<Num>)]) -> &Self {
+        let vars_as_ops: Vec<(&str, &Operation<Num>)> = variables
+            .iter()
+            .map(|var| (var.0, &var.1.operation))
+            .collect();
+
+        self.operation = self.operation.set_vars(&vars_as_ops);
+        self
+    }
+
+    /// Visits every node of the term in post-order, allowing it to be modified in place.
+    ///
+    /// The term is not simplified afterwards.
+    ///
+    /// ```rust
+    /// # use crem::*;
+    /// let mut term = Term::var("x") + Term::from(3);
+    /// term.walk_mut(|op| {
+    ///     if let Operation::Number(num) = op {
+    ///         num.value *= 2;
+    ///     }
+    /// });
+    /// assert_eq!(term, Term::var("x") + Term::from(6));
+    /// ```
+    pub fn walk_mut<F: FnMut(&mut Operation<Num>)>(&mut self, mut f: F) {
+        self.operation.walk_mut(&mut f);
+    }
+
+    /// Differentiates the term symbolically with respect to the given variable.
+    ///
+    /// Floor divisions are treated as constant, as their derivative is zero wherever it exists.
+    /// Panics if a bitwise operation depends on the variable, use [`try_diff`](Term::try_diff) to avoid that.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let term = Term::from(3u32) * Term::var("x") * Term::var("x") + Term::var("y");
+    /// assert_eq!(term.diff("x").with_var("x", &Term::from(2)), Term::from(12));
+    /// assert_eq!(term.diff("y"), Term::from(1));
+    /// ```
+    pub fn diff(&self, var: &str) -> Self
+    where
+        Num: From<u8>,
+    {
+        Term {
+            operation: self.operation.diff(var),
+        }
+    }
+
+    /// Differentiates the term symbolically with respect to the given variable.
+    ///
+    /// Returns `None` if a bitwise operation depends on the variable, as those cannot be differentiated.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let term = (Term::<u32>::var("x") & Term::from(3)) + Term::var("y");
+    /// assert_eq!(term.try_diff("y"), Some(Term::from(1)));
+    /// assert_eq!(term.try_diff("x"), None);
+    /// ```
+    pub fn try_diff(&self, var: &str) -> Option<Self>
+    where
+        Num: From<u8>,
+    {
+        let mut differentiable = true;
+        self.operation.walk(&mut |operation| {
+            if let Operation::BitAnd(bit)
+            | Operation::BitOr(bit)
+            | Operation::BitXor(bit)
+            | Operation::Shl(bit)
+            | Operation::Shr(bit) = operation
+            {
+                bit.walk(&mut |child| {
+                    if matches!(child, Operation::Variable(variable) if variable.name == var) {
+                        differentiable = false;
+                    }
+                });
+            }
+        });
+
+        differentiable.then(|| self.diff(var))
+    }
+
+    /// Differentiates the term `n` times with respect to the given variable.
+    ///
+    /// The term is simplified again after every step.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let x = || Term::<u32>::var("x");
+    /// let term = x() * x() * x();
+    /// assert_eq!(term.diff_nth("x", 0), term);
+    /// assert_eq!(term.diff_nth("x", 2).with_var("x", &Term::from(5)), Term::from(30));
+    /// assert_eq!(term.diff_nth("x", 3), Term::from(6));
+    /// ```
+    pub fn diff_nth(&self, var: &str, n: u32) -> Self
+    where
+        Num: From<u8>,
+    {
+        let mut result = self.clone();
+        for _ in 0..n {
+            result = result.diff(var).substitute_and_simplify(&[]);
+        }
+        result
+    }
+
+    /// Calculates the gradient of the term, i.e. its partial derivatives with respect to each of the given variables.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let x = || Term::<u32>::var("x");
+    /// let y = || Term::<u32>::var("y");
+    /// let term = x() * x() * y() + Term::from(3) * y();
+    /// let gradient = term.partial_derivative_gradient(&["x", "y"]);
+    /// assert_eq!(gradient.len(), 2);
+    /// let at = [("x", Term::from(2)), ("y", Term::from(5))];
+    /// assert_eq!(gradient[0].substitute_and_simplify(&at), Term::from(20));
+    /// assert_eq!(gradient[1].substitute_and_simplify(&at), Term::from(7));
+    /// ```
+    pub fn partial_derivative_gradient(&self, vars: &[&str]) -> Vec<Self>
+    where
+        Num: From<u8>,
+    {
+        vars.iter().map(|var| self.diff(var)).collect()
+    }
+
+    /// Calculates the laplacian of the term, i.e. the sum of its second partial derivatives with respect to each of the given variables.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let x = || Term::<u32>::var("x");
+    /// let y = || Term::<u32>::var("y");
+    /// assert_eq!((x() * x() + y() * y()).laplacian(&["x", "y"]), Term::from(4));
+    /// ```
+    pub fn laplacian(&self, vars: &[&str]) -> Self
+    where
+        Num: From<u8>,
+    {
+        Term::sum(vars.iter().map(|var| self.diff_nth(var, 2))).substitute_and_simplify(&[])
+    }
+
+    /// Calculates the jacobian matrix of the given terms.
+    /// Entry `[i][j]` is the partial derivative of `output_terms[i]` with respect to `input_vars[j]`,
+    /// so every row is the [gradient](Term::partial_derivative_gradient) of one of the terms.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let x = || Term::<u32>::var("x");
+    /// let y = || Term::<u32>::var("y");
+    /// let jacobian = Term::jacobian(&["x", "y"], &[x() * y(), x() + Term::from(2) * y()]);
+    /// assert_eq!(
+    ///     jacobian,
+    ///     vec![vec![y(), x()], vec![Term::from(1), Term::from(2)]]
+    /// );
+    /// ```
+    pub fn jacobian(input_vars: &[&str], output_terms: &[Self]) -> Vec<Vec<Self>>
+    where
+        Num: From<u8>,
+    {
+        output_terms
+            .iter()
+            .map(|term| term.partial_derivative_gradient(input_vars))
+            .collect()
+    }
+
+    /// Calculates the total differential of the term, i.e. the sum of its partial derivatives
+    /// with respect to each of the variables, multiplied with the variable named by the matching entry of `differential_vars`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `vars` and `differential_vars` are not of the same length.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let x = || Term::<u32>::var("x");
+    /// let y = || Term::<u32>::var("y");
+    /// assert_eq!(
+    ///     (x() * y()).diff_total(&["x", "y"], &["dx", "dy"]),
+    ///     y() * Term::var("dx") + x() * Term::var("dy")
+    /// );
+    /// ```
+    pub fn diff_total(&self, vars: &[&str], differential_vars: &[&str]) -> Self
+    where
+        Num: From<u8>,
+    {
+        assert_eq!(
+            vars.len(),
+            differential_vars.len(),
+            "Every variable needs exactly one differential."
+        );
+        Term::sum(
+            vars.iter()
+                .zip(differential_vars)
+                .map(|(var, differential)| self.diff(var) * Term::var(*differential)),
+        )
+    }
+
+    /// Approximates the term by its Taylor polynomial of the given order around `var = center`.
+    ///
+    /// The result is `f(center) + f'(center) * (var - center) + f''(center) / 2! * (var - center)^2 + ...`.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// // 1 / (1 - x) = 1 + x + x^2 + ... around zero
+    /// let term = Term::from(1i64) / (Term::from(1) - Term::var("x"));
+    /// let approx = term.taylor_approx("x", 0, 3);
+    /// assert_eq!(approx.with_var("x", &Term::from(2)), Term::from(15));
+    /// ```
+    pub fn taylor_approx(&self, var: &str, center: Num, order: u32) -> Self
+    where
+        Num: From<u8>,
+    {
+        let center = Term::from(center);
+        let offset = Term::var(var) - center.clone();
+
+        // dividing by k after every differentiation instead of calculating k! avoids overflowing factorials
+        let mut result = Term::default();
+        let mut derivative = self.clone();
+        for k in 0..=order {
+            if k > 0 {
+                derivative = derivative.diff(var) / Term::from(number_from_u32::<Num>(k));
+            }
+            result += derivative.with_var(var, &center) * offset.pow_u32(k);
+        }
+        result
+    }
+
+    /// Finds a root of the term numerically using Newton's method, starting at the initial guess.
+    ///
+    /// Returns `None` if no value `x` with `|f(x)| < tolerance` was found within `max_iter` iterations,
+    /// if the method diverged, or if the term contains other variables.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let term = Term::<u32>::var("x") * Term::var("x") - Term::from(2);
+    /// let root = term.newton_solve("x", 1.0, 1e-12, 20).unwrap();
+    /// assert!((root - 2f64.sqrt()).abs() < 1e-9);
+    ///
+    /// let no_root = Term::<u32>::var("x") * Term::var("x") + Term::from(1);
+    /// assert_eq!(no_root.newton_solve("x", 1.0, 1e-12, 20), None);
+    /// ```
+    pub fn newton_solve(
+        &self,
+        var: &str,
+        initial_guess: f64,
+        tolerance: f64,
+        max_iter: u32,
+    ) -> Option<f64>
+    where
+        f64: From<Num>,
+    {
+        let mut other_variables = false;
+        self.operation.walk(&mut |op| {
+            if let Operation::Variable(other) = op {
+                other_variables |= other.name != var;
+            }
+        });
+        if other_variables {
+            return None;
+        }
+
+        let term: Term<f64> = self.clone().convert();
+        let derivative = term.diff(var);
+        let at = |term: &Term<f64>, x: f64| term.with_var(var, &Term::from(x)).calc_float::<f64>();
+
+        let mut x = initial_guess;
+        for _ in 0..=max_iter {
+            let value = at(&term, x);
+            if !value.is_finite() {
+                return None;
+            }
+            if value.abs() < tolerance {
+                return Some(x);
+            }
+
+            let slope = at(&derivative, x);
+            if slope == 0.0 || !slope.is_finite() {
+                return None;
+            }
+            x -= value / slope;
+        }
+        None
+    }
+
+    /// Approximates the derivative with respect to the given variable numerically.
+    ///
+    /// The returned function calculates the central difference `(f(x+h) - f(x-h)) / 2h` using the given step `h`.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let square = Term::<u32>::var("x") * Term::var("x");
+    /// let derivative = square.diff_approx("x", 0.001);
+    /// assert!((derivative(3.0) - 6.0).abs() < 1e-6);
+    /// ```
+    pub fn diff_approx(&self, var: &str, step: f64) -> impl Fn(f64) -> f64
+    where
+        f64: From<Num>,
+    {
+        let term: Term<f64> = self.clone().convert();
+        let var = var.to_string();
+
+        move |x| {
+            let after: f64 = term.use_var(&var, &Term::from(x + step));
+            let before: f64 = term.use_var(&var, &Term::from(x - step));
+            (after - before) / (2.0 * step)
+        }
+    }
+
+    /// Raises the term to the power of the given exponent.
+    ///
+    /// Numbers are calculated using exponentiation by squaring. Other terms are multiplied out for small exponents,
+    /// while large exponents are kept as a power, so that the size of the term does not grow with the exponent.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// assert_eq!(Term::from(3).pow_u32(2), Term::from(9));
+    /// assert_eq!(Term::from(3).pow_u32(0), Term::from(1));
+    /// assert_eq!(Term::<u32>::var("x").pow_u32(3), Term::var("x") * Term::var("x") * Term::var("x"));
+    /// assert_eq!(Term::<u32>::var("x").pow_u32(1000).to_prefix_notation(), "(^ x 1000 1)");
+    /// ```
+    pub fn pow_u32(&self, exp: u32) -> Self
+    where
+        Num: From<u8>,
+    {
+        if exp == 0 {
+            return Term::from(Num::from(1));
+        }
+        Term {
+            operation: integral_power(self.operation.clone(), exp),
+        }
+    }
+
+    /// Raises the term to the power of `numer / denom`.
+    ///
+    /// The result can only be calculated using [`calc_float`](Term::calc_float).
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let cube_root = Term::<u32>::var("x").pow_rational(1, 3);
+    /// assert_eq!(cube_root.with_var("x", &Term::from(8)).calc_float::<f64>(), 2.0);
+    /// assert_eq!(Term::from(5).pow_rational(3, 3), Term::from(5));
+    /// assert_eq!(Term::from(5).pow_rational(4, 2), Term::from(25));
+    /// ```
+    pub fn pow_rational(&self, numer: u32, denom: u32) -> Self
+    where
+        Num: From<u8>,
+    {
+        if denom == 0 {
+            panic!("Cannot divide by zero.");
+        }
+
+        let gcd = greatest_common_divisor(numer, denom);
+        let (numer, denom) = (numer / gcd, denom / gcd);
+
+        if numer == 0 {
+            Term::from(Num::from(1))
+        } else if numer == denom {
+            self.clone()
+        } else if denom == 1 {
+            self.pow_u32(numer)
+        } else if let Some(root) = self.exact_root(denom) {
+            root.pow_u32(numer)
+        } else {
+            Term {
+                operation: RationalPower::with_base(self.operation.clone(), numer, denom),
+            }
+        }
+    }
+
+    /// Takes the `n`-th root of the term.
+    ///
+    /// Roots of constant perfect powers are calculated immediately. Anything else becomes a rational power,
+    /// which can only be calculated using [`calc_float`](Term::calc_float).
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// assert_eq!(Term::from(8u32).root_n(3), Term::from(2));
+    /// assert_eq!(Term::from(9u32).root_n(2), Term::from(3));
+    /// assert_eq!(Term::from(2u32).root_n(2), Term::from(2).pow_rational(1, 2));
+    /// ```
+    pub fn root_n(&self, n: u32) -> Self
+    where
+        Num: From<u8>,
+    {
+        self.pow_rational(1, n)
+    }
+
+    /// Serializes the term using prefix notation, e.g. `(+ (* 2 x) 1)`.
+    ///
+    /// Every node is written as `(op arg1 arg2 ...)`. Rational powers are written as `(^ base numer denom)`.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let term = Term::from(2) * Term::var("x") + Term::from(1);
+    /// assert_eq!(term.to_prefix_notation(), "(+ (* 2 x) 1)");
+    /// ```
+    pub fn to_prefix_notation(&self) -> String
+    where
+        Num: Display,
+    {
+        self.operation.to_prefix_notation()
+    }
+
+    /// Writes the term in infix notation, without putting the whole term in parentheses.
+    ///
+    /// Unlike the [`Display`] implementation, which puts every term except single numbers and variables in parentheses
+    /// so it can be safely embedded into other formulars, this is meant for showing a term on its own.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let term = Term::from(2u32) * (Term::var("x") + Term::from(1));
+    /// assert_eq!(term.to_string_no_outer_parens(), "2 * (x + 1)");
+    /// assert_eq!(term.to_string(), "(2 * (x + 1))");
+    /// ```
+    pub fn to_string_no_outer_parens(&self) -> String
+    where
+        Num: Display,
+    {
+        infix_notation(&self.operation, true)
+    }
+
+    /// Writes the term as a Rust expression, which calculates the same result as [`calc`](Term::calc).
+    ///
+    /// All numbers are suffixed with `var_type`, e.g. `f64` or `i64`, and variables are written as their names.
+    /// Rational powers and floor divisions use methods of floating point numbers, while bitwise operations only work on integers.
+    /// Fused multiply-adds use `mul_add` if `var_type` is `f32` or `f64`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the term contains modular exponentiations.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// assert_eq!(Term::div(1u32, 2u32).to_rust_expr("f64"), "(1_f64 / 2_f64)");
+    ///
+    /// let term = -(Term::from(3u32) * Term::var("x"));
+    /// assert_eq!(term.to_rust_expr("i64"), "(-(3_i64 * x))");
+    ///
+    /// let x = 2_i64;
+    /// assert_eq!((-(3_i64 * x)), term.with_var("x", &Term::from(2)).calc::<i64>());
+    /// ```
+    pub fn to_rust_expr(&self, var_type: &str) -> String
+    where
+        Num: Display,
+    {
+        rust_expr(&self.operation, var_type)
+    }
+
+    /// Writes the term in the SMT-LIB2 format used by SMT solvers like Z3 and CVC5.
+    ///
+    /// Every variable is declared as a constant of the given sort, e.g. `Real` or `Int`, in order of its first appearance.
+    /// Variable names which are not valid SMT-LIB2 symbols are quoted, e.g. `|x y|`.
+    /// The term itself is written on the last line, after the declarations.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the term contains powers, floor divisions, modular exponentiations or bitwise operations,
+    /// as they have no standard representation.
+    /// Also panics if the term contains divisions and the sort is not `Real`, as `/` is only defined for reals,
+    /// or if a variable name contains `|` or `\`, which cannot be quoted.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// assert_eq!(Term::div(1u32, 2).to_smtlib2("Real"), "(/ 1 2)");
+    ///
+    /// let term = Term::from(2u32) * Term::var("x") - Term::var("y");
+    /// assert_eq!(
+    ///     term.to_smtlib2("Real"),
+    ///     "(declare-fun x () Real)\n(declare-fun y () Real)\n(+ (* 2 x) (- y))"
+    /// );
+    ///
+    /// assert_eq!(
+    ///     Term::<u32>::var("x y").to_smtlib2("Int"),
+    ///     "(declare-fun |x y| () Int)\n|x y|"
+    /// );
+    /// ```
+    pub fn to_smtlib2(&self, var_type: &str) -> String
+    where
+        Num: Display,
+    {
+        let mut lines: Vec<String> = self
+            .variable_names()
+            .into_iter()
+            .map(|name| format!("(declare-fun {} () {var_type})", smtlib2_symbol(&name)))
+            .collect();
+        lines.push(smtlib2_expr(&self.operation, var_type == "Real"));
+        lines.join("\n")
+    }
+
+    /// Writes the term as a directed graph in the DOT language, which can be rendered using Graphviz, e.g. `dot -Tpng`.
+    ///
+    /// Every operation is a node labeled with its operator, number or variable name, with edges pointing to its children in order.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let term = Term::from(2u32) * Term::var("x") + Term::from(1);
+    /// assert_eq!(
+    ///     term.to_dot_graph(),
+    ///     "digraph term {
+    ///     n0 [label=\"+\"];
+    ///     n1 [label=\"*\"];
+    ///     n2 [label=\"2\"];
+    ///     n1 -> n2;
+    ///     n3 [label=\"x\"];
+    ///     n1 -> n3;
+    ///     n0 -> n1;
+    ///     n4 [label=\"1\"];
+    ///     n0 -> n4;
+    /// }"
+    /// );
+    /// ```
+    pub fn to_dot_graph(&self) -> String
+    where
+        Num: Display,
+    {
+        let mut lines = vec![String::from("digraph term {")];
+        dot_graph_node(&self.operation, &mut lines, &mut 0);
+        lines.push(String::from("}"));
+        lines.join("\n")
+    }
+
+    /// Replaces the first sub-term matching the pattern with the replacement.
+    ///
+    /// Variables within the pattern match any sub-term. The matched sub-terms are then inserted into the replacement.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let term = Term::<u32>::var("y") + Term::var("x") * Term::var("x");
+    /// let pattern = Term::var("a") * Term::var("a");
+    /// let replacement = Term::var("a");
+    /// assert_eq!(
+    ///     term.apply_rule(&pattern, &replacement),
+    ///     Term::var("y") + Term::var("x")
+    /// );
+    /// ```
+    pub fn apply_rule(&self, pattern: &Term<Num>, replacement: &Term<Num>) -> Self {
+        match self
+            .operation
+            .apply_rule(&pattern.operation, &replacement.operation)
+        {
+            Some(operation) => Term { operation },
+            None => self.clone(),
+        }
+    }
+
+    /// Finds the variable bindings which make `a` structurally equal to `b`.
+    ///
+    /// Variables in `a` may be bound to any sub-term of `b`. Variables in `b` cannot be bound.
+    /// Returns `None` if no such bindings exist.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let a = Term::<u32>::var("x") * Term::var("y");
+    /// let b = Term::from(2) * Term::var("z");
+    /// let bindings = Term::unify(&a, &b).unwrap();
+    /// assert_eq!(bindings["x"], Term::from(2));
+    /// assert_eq!(bindings["y"], Term::var("z"));
+    ///
+    /// assert_eq!(Term::unify(&b, &a), None);
+    /// ```
+    pub fn unify(a: &Term<Num>, b: &Term<Num>) -> Option<HashMap<String, Term<Num>>> {
+        let mut bindings = Vec::new();
+        if a.operation.unify(&b.operation, &mut bindings) {
+            Some(
+                bindings
+                    .into_iter()
+                    .map(|(name, operation)| (name, Term { operation }))
+                    .collect(),
+            )
+        } else {
+            None
+        }
+    }
+
+    /// Checks whether the terms are equal up to a consistent renaming of their variables.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let a = Term::from(2) * Term::var("x") + Term::from(1);
+    /// let b = Term::from(2) * Term::var("y") + Term::from(1);
+    /// assert!(Term::alpha_equivalent(&a, &b));
+    ///
+    /// let c = Term::<u32>::var("x") * Term::var("y");
+    /// let d = Term::<u32>::var("z") * Term::var("z");
+    /// assert!(!Term::alpha_equivalent(&c, &d));
+    /// ```
+    pub fn alpha_equivalent(a: &Term<Num>, b: &Term<Num>) -> bool {
+        let mut bindings = Vec::new();
+        if !a.operation.unify(&b.operation, &mut bindings) {
+            return false;
+        }
+
+        // every variable has to be renamed to a variable, and no two variables to the same one
+        let mut renamed = Vec::new();
+        for (_, operation) in &bindings {
+            match operation {
+                Operation::Variable(var) if !renamed.contains(&&var.name) => {
+                    renamed.push(&var.name)
+                }
+                _ => return false,
+            }
+        }
+        true
+    }
+
+    /// Compares the terms structurally, recording which sub-terms were changed, added or removed.
+    ///
+    /// See [`TermDiff`].
+    pub fn diff_terms(old: &Term<Num>, new: &Term<Num>) -> TermDiff<Num> {
+        TermDiff::new(old, new)
+    }
+
+    /// Checks whether two terms are likely mathematically equal, by evaluating both at `test_points`
+    /// pseudo-random assignments of their variables and comparing the results.
+    ///
+    /// Results agree if they are equal within a relative tolerance of `1e-9`. Terms which always agree are equal,
+    /// while polynomials which differ only agree at few points, so false positives are very unlikely
+    /// given about 20 test points. The assignments are the same on every call.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the terms contain bitwise operations.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let x = || Term::<u32>::var("x");
+    /// let squared = (x() + Term::from(1)) * (x() + Term::from(1));
+    /// let expanded = x() * x() + Term::from(2) * x() + Term::from(1);
+    /// assert_ne!(squared, expanded);
+    /// assert!(Term::verify_equality(&squared, &expanded, 20));
+    /// assert!(!Term::verify_equality(&squared, &(x() * x()), 20));
+    /// ```
+    pub fn verify_equality(a: &Term<Num>, b: &Term<Num>, test_points: usize) -> bool
+    where
+        f64: From<Num>,
+    {
+        let a = a.clone().convert::<f64>();
+        let b = b.clone().convert::<f64>();
+        let mut names = a.variable_names();
+        for name in b.variable_names() {
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+
+        let mut state = 0x2545_f491_4f6c_dd1d;
+        (0..test_points).all(|_| {
+            let values: Vec<Term<f64>> = names
+                .iter()
+                .map(|_| Term::from(random_test_value(&mut state)))
+                .collect();
+            let vars: Vec<(&str, &Term<f64>)> =
+                names.iter().map(String::as_str).zip(&values).collect();
+
+            let a = a.with_vars(&vars).calc_float::<f64>();
+            let b = b.with_vars(&vars).calc_float::<f64>();
+            a == b
+                || (a.is_nan() && b.is_nan())
+                || (a - b).abs() <= 1e-9 * a.abs().max(b.abs()).max(1.0)
+        })
+    }
+
+    /// Finds the exact `n`-th root of a constant term, if there is one.
+    fn exact_root(&self, n: u32) -> Option<Self>
+    where
+        Num: From<u8>,
+    {
+        match &self.operation {
+            Operation::Number(num) => exact_root(num.value.clone(), n).map(Term::from),
+            Operation::Division(div) => match (&*div.divident, &*div.divisor) {
+                (Operation::Number(divident), Operation::Number(divisor)) => {
+                    let divident = exact_root(divident.value.clone(), n)?;
+                    let divisor = exact_root(divisor.value.clone(), n)?;
+                    Some(Term::from(divident) / Term::from(divisor))
+                }
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Calculates the exact square root of a constant term, if it is rational.
+    ///
+    /// Returns `None` for terms containing variables and for numbers which are not perfect squares.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// assert_eq!(Term::from(49u32).checked_sqrt(), Some(Term::from(7)));
+    /// assert_eq!(Term::div(9u32, 4).checked_sqrt(), Some(Term::div(3, 2)));
+    /// assert_eq!(Term::from(50u32).checked_sqrt(), None);
+    /// assert_eq!(Term::<u32>::var("x").checked_sqrt(), None);
+    /// ```
+    pub fn checked_sqrt(&self) -> Option<Self>
+    where
+        Num: From<u8>,
+    {
+        self.exact_root(2)
+    }
+
+    /// Returns the coefficients of the term as a polynomial in the given variable, starting with the constant one.
+    ///
+    /// Trailing zero coefficients are omitted.
+    /// Returns `None` if the term is not a polynomial in the variable, e.g. if the variable appears in a divisor.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let x = Term::<u32>::var("x");
+    /// let term = Term::from(3) * x.clone() * x.clone() + Term::from(2) * x.clone() + Term::from(1);
+    /// assert_eq!(
+    ///     term.to_polynomial_coefficients("x"),
+    ///     Some(vec![Term::from(1), Term::from(2), Term::from(3)])
+    /// );
+    /// assert_eq!((Term::from(1) / x).to_polynomial_coefficients("x"), None);
+    /// ```
+    pub fn to_polynomial_coefficients(&self, var: &str) -> Option<Vec<Self>>
+    where
+        Num: From<u8>,
+    {
+        let mut coefficients = self.operation.to_polynomial_coefficients(var)?;
+        trim_coefficients(&mut coefficients);
+        Some(
+            coefficients
+                .into_iter()
+                .map(|operation| Term { operation })
+                .collect(),
+        )
+    }
+
+    /// Returns the degree of the term as a polynomial in the given variable.
+    ///
+    /// Returns `None` if the term is not a polynomial in the variable, e.g. if the variable appears in a divisor.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let x = Term::<u32>::var("x");
+    /// assert_eq!((x.clone() * x.clone() + Term::var("y")).polynomial_degree("x"), Some(2));
+    /// assert_eq!(Term::<u32>::var("y").polynomial_degree("x"), Some(0));
+    /// assert_eq!((Term::from(1) / x).polynomial_degree("x"), None);
+    /// ```
+    pub fn polynomial_degree(&self, var: &str) -> Option<u32>
+    where
+        Num: From<u8>,
+    {
+        let coefficients = self.to_polynomial_coefficients(var)?;
+        Some(coefficients.len().saturating_sub(1) as u32)
+    }
+
+    /// Checks whether the term is a polynomial of degree 1 in the given variable.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let x = Term::<u32>::var("x");
+    /// assert!((Term::from(3) * x.clone() + Term::from(1)).is_linear("x"));
+    /// assert!(!(x.clone() * x.clone()).is_linear("x"));
+    /// assert!(!(Term::from(1) / x).is_linear("x"));
+    /// ```
+    pub fn is_linear(&self, var: &str) -> bool
+    where
+        Num: From<u8>,
+    {
+        self.polynomial_degree(var) == Some(1)
+    }
+
+    /// Solves the quadratic equation `term = 0` for the given variable using the quadratic formula.
+    ///
+    /// Returns `None` if the term is not a polynomial of degree 2 in the variable, or if there are no real solutions.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let x = Term::<i32>::var("x");
+    /// let term = x.clone() * x.clone() - Term::from(5) * x.clone() + Term::from(6);
+    /// assert_eq!(term.solve_quadratic("x"), Some((Term::from(3), Term::from(2))));
+    /// ```
+    pub fn solve_quadratic(&self, var: &str) -> Option<(Self, Self)>
+    where
+        Num: From<u8>,
+    {
+        let coefficients = self.to_polynomial_coefficients(var)?;
+        if coefficients.len() != 3 {
+            return None;
+        }
+        let [c, b, a]: [Self; 3] = coefficients.try_into().ok()?;
+
+        let four = || Term::from(Num::from(4));
+        let discriminant = b.clone() * b.clone() - four() * a.clone() * c.clone();
+
+        // constant discriminants are often left as unsimplified sums of fractions, so their sign is calculated separately
+        let fraction = |term: &Self| {
+            term.constant_fraction()
+                .map(|(negated, numerator, denominator)| {
+                    let numerator = Term::from(numerator);
+                    (
+                        if negated { -numerator } else { numerator },
+                        Term::from(denominator),
+                    )
+                })
+        };
+        let negative = match (fraction(&a), fraction(&b), fraction(&c)) {
+            (Some((an, ad)), Some((bn, bd)), Some((cn, cd))) => {
+                // b^2 - 4ac = (bn^2 * ad * cd - 4 * an * cn * bd^2) / (bd^2 * ad * cd)
+                let numerator = bn.clone() * bn * ad.clone() * cd.clone()
+                    - four() * an * cn * bd.clone() * bd.clone();
+                let denominator = bd.clone() * bd * ad * cd;
+                numerator != Term::default() && numerator.is_negative() != denominator.is_negative()
+            }
+            _ => discriminant.is_negative(),
+        };
+        if negative {
+            return None;
+        }
+        let root = discriminant.pow_rational(1, 2);
+        let divisor = Term::from(Num::from(2)) * a;
+
+        Some((
+            (-b.clone() + root.clone()) / divisor.clone(),
+            (-b - root) / divisor,
+        ))
+    }
+
+    /// Decomposes a rational function in the given variable into partial fractions `A/(x-r1) + B/(x-r2) + ...`.
+    ///
+    /// Only denominators which are products of distinct linear factors (and constants) are supported,
+    /// and the degree of the numerator has to be lower than the number of linear factors.
+    /// Returns `None` otherwise. The summands are returned in the order of the factors of the denominator.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let x = || Term::<i32>::var("x");
+    /// let term = (Term::from(3) * x() + Term::from(5)) / ((x() + Term::from(1)) * (x() + Term::from(2)));
+    /// assert_eq!(
+    ///     term.partial_fraction_decompose("x"),
+    ///     Some(vec![
+    ///         Term::from(2) / (x() + Term::from(1)),
+    ///         Term::from(1) / (x() + Term::from(2)),
+    ///     ])
+    /// );
+    /// ```
+    pub fn partial_fraction_decompose(&self, var: &str) -> Option<Vec<Self>>
+    where
+        Num: From<u8>,
+    {
+        let div = match &self.operation {
+            Operation::Division(div) => div,
+            Operation::Negation(neg) => {
+                let parts = Term {
+                    operation: (*neg.value).clone(),
+                }
+                .partial_fraction_decompose(var)?;
+                return Some(parts.into_iter().map(|part| -part).collect());
+            }
+            _ => return None,
+        };
+
+        let numerator = Term {
+            operation: (*div.divident).clone(),
+        };
+        let factors: Vec<Self> = match &*div.divisor {
+            Operation::Multiplication(mul) => mul.multipliers.clone(),
+            divisor => vec![divisor.clone()],
+        }
+        .into_iter()
+        .map(|operation| Term { operation })
+        .collect();
+
+        // the roots of the linear factors, alongside the index of their factor
+        let mut roots: Vec<(usize, Self)> = Vec::new();
+        for (index, factor) in factors.iter().enumerate() {
+            match factor.to_polynomial_coefficients(var)?.as_slice() {
+                [_] => (),
+                [constant, linear] => {
+                    let root = -constant.clone() / linear.clone();
+                    if roots
+                        .iter()
+                        .any(|(_, other)| other.clone() - root.clone() == Term::default())
+                    {
+                        return None;
+                    }
+                    roots.push((index, root));
+                }
+                _ => return None,
+            }
+        }
+
+        let numerator_degree = numerator.polynomial_degree(var)?;
+        if roots.is_empty() || numerator_degree as usize >= roots.len() {
+            return None;
+        }
+
+        // cover-up method: the coefficient of each factor is the rest of the fraction evaluated at its root
+        Some(
+            roots
+                .into_iter()
+                .map(|(index, root)| {
+                    let rest = Term::product(
+                        factors
+                            .iter()
+                            .enumerate()
+                            .filter(|(other, _)| *other != index)
+                            .map(|(_, factor)| factor.with_var(var, &root)),
+                    );
+                    numerator.with_var(var, &root) / rest / factors[index].clone()
+                })
+                .collect(),
+        )
+    }
+
+    /// Calculates the greatest common divisor of two terms.
+    ///
+    /// Numbers use the euclidean algorithm directly.
+    /// Polynomials in the same variable use it on polynomial long division, the result is scaled to a leading coefficient of one.
+    /// Any other terms are considered coprime, so one is returned.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// assert_eq!(Term::symbolic_gcd(&Term::from(12u32), &Term::from(18)), Term::from(6));
+    ///
+    /// let x = || Term::<i32>::var("x");
+    /// // (x - 1)(x + 1) and (x + 1)(x + 1)
+    /// let a = x() * x() - Term::from(1);
+    /// let b = x() * x() + Term::from(2) * x() + Term::from(1);
+    /// assert_eq!(
+    ///     Term::symbolic_gcd(&a, &b).to_polynomial_coefficients("x"),
+    ///     Some(vec![Term::from(1), Term::from(1)])
+    /// );
+    /// ```
+    pub fn symbolic_gcd(a: &Term<Num>, b: &Term<Num>) -> Self
+    where
+        Num: From<u8>,
+    {
+        if let (Operation::Number(a), Operation::Number(b)) = (&a.operation, &b.operation) {
+            return Term::from(greatest_common_divisor(a.value.clone(), b.value.clone()));
+        }
+
+        let mut names = a.variable_names();
+        for name in b.variable_names() {
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+        let (Some(var), 1) = (names.first(), names.len()) else {
+            return Term::from(Num::from(1));
+        };
+        let (Some(mut a), Some(mut b)) = (
+            a.to_polynomial_coefficients(var),
+            b.to_polynomial_coefficients(var),
+        ) else {
+            return Term::from(Num::from(1));
+        };
+
+        // euclidean algorithm
+        while !b.is_empty() {
+            let remainder = polynomial_remainder(a, &b);
+            a = b;
+            b = remainder;
+        }
+
+        match a.last().cloned() {
+            Some(leading) => Term::horner_eval(
+                &a.into_iter()
+                    .map(|coefficient| coefficient / leading.clone())
+                    .collect::<Vec<_>>(),
+                var,
+            ),
+            None => Term::default(),
+        }
+    }
+
+    /// Returns whether a number or a fraction of numbers is negated, alongside its numerator and denominator.
+    ///
+    /// The negation is returned separately instead of being moved onto the numerator, so that unsigned numbers don't underflow.
+    pub(crate) fn constant_fraction(&self) -> Option<(bool, Num, Num)>
+    where
+        Num: From<u8>,
+    {
+        match &self.operation {
+            Operation::Number(num) => Some((false, num.value.clone(), Num::from(1))),
+            Operation::Division(div) => match (&*div.divident, &*div.divisor) {
+                (Operation::Number(divident), Operation::Number(divisor)) => {
+                    Some((false, divident.value.clone(), divisor.value.clone()))
+                }
+                _ => None,
+            },
+            Operation::Negation(neg) => {
+                let (negated, numerator, denominator) = Term {
+                    operation: (*neg.value).clone(),
+                }
+                .constant_fraction()?;
+                Some((!negated, numerator, denominator))
+            }
+            _ => None,
+        }
+    }
+
+    /// Checks whether the term is a negative constant.
+    fn is_negative(&self) -> bool {
+        match &self.operation {
+            Operation::Number(num) => num.value < Num::default(),
+            Operation::Negation(neg) => {
+                matches!(&*neg.value, Operation::Number(num) if num.value > Num::default())
+            }
+            _ => false,
+        }
+    }
+
+    /// Lists the names of all variables within the term, in order of their first appearance.
+    fn variable_names(&self) -> Vec<String> {
+        let mut names = Vec::new();
+        self.operation.walk(&mut |op| {
+            if let Operation::Variable(var) = op {
+                if !names.contains(&var.name) {
+                    names.push(var.name.clone());
+                }
+            }
+        });
+        names
+    }
+
+    /// Describes the variables of the term as a JSON schema of an object, having every variable as a required property.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let schema = (Term::<u32>::var("width") * Term::var("height")).to_json_schema();
+    /// assert_eq!(schema["type"], "object");
+    /// assert_eq!(schema["required"], serde_json::json!(["width", "height"]));
+    /// assert_eq!(schema["properties"]["width"]["type"], "number");
+    /// ```
+    #[cfg(feature = "serde_json")]
+    pub fn to_json_schema(&self) -> serde_json::Value {
+        let names = self.variable_names();
+        let properties: serde_json::Map<String, serde_json::Value> = names
+            .iter()
+            .map(|name| {
+                (
+                    name.clone(),
+                    serde_json::json!({
+                        "type": "number",
+                        "description": format!("The value of the variable `{name}`."),
+                    }),
+                )
+            })
+            .collect();
+
+        serde_json::json!({
+            "type": "object",
+            "properties": properties,
+            "required": names,
+        })
+    }
+
+    /// Extracts the value of the term, if it is a single number.
+    ///
+    /// ```rust
+    /// # use crem::*;
+    /// assert_eq!(Term::from(3).try_into_constant(), Ok(3));
+    /// assert_eq!(
+    ///     (Term::var("x") + Term::from(3)).try_into_constant(),
+    ///     Err(NotAConstantError {
+    ///         had_variables: vec![String::from("x")]
+    ///     })
+    /// );
+    /// ```
+    pub fn try_into_constant(self) -> Result<Num, NotAConstantError> {
+        match self.operation {
+            Operation::Number(num) => Ok(num.value),
+            operation => Err(NotAConstantError {
+                had_variables: Term { operation }.variable_names(),
+            }),
+        }
+    }
+
+    /// Replaces the given variables once, so that the remaining variable can be set repeatedly.
+    ///
+    /// See [`BoundTerm`].
+    pub fn bind_lazy(&self, fixed_vars: &[(&str, Term<Num>)]) -> BoundTerm<Num> {
+        let variables: Vec<(&str, &Term<Num>)> =
+            fixed_vars.iter().map(|var| (var.0, &var.1)).collect();
+
+        BoundTerm::from(self.with_vars(&variables))
+    }
+
+    /// Formats the term as a fraction, if it is a rational number.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// assert_eq!(Term::div(7, 2).format_as_fraction(), Some(String::from("7/2")));
+    /// assert_eq!((-Term::div(7, 2)).format_as_fraction(), Some(String::from("-7/2")));
+    /// assert_eq!(Term::from(3).format_as_fraction(), Some(String::from("3")));
+    /// assert_eq!(Term::<u32>::var("x").format_as_fraction(), None);
+    /// ```
+    pub fn format_as_fraction(&self) -> Option<String>
+    where
+        Num: Display,
+    {
+        match &self.operation {
+            Operation::Number(num) => Some(num.value.to_string()),
+            Operation::Division(div) => match (&*div.divident, &*div.divisor) {
+                (Operation::Number(divident), Operation::Number(divisor)) => {
+                    Some(format!("{}/{}", divident.value, divisor.value))
+                }
+                _ => None,
+            },
+            Operation::Negation(neg) => Term {
+                operation: (*neg.value).clone(),
+            }
+            .format_as_fraction()
+            .map(|fraction| format!("-{fraction}")),
+            _ => None,
+        }
+    }
+
+    /// Splits a fraction into its numerator and its denominator, moving any sign onto the numerator so the denominator is positive.
+    ///
+    /// Negated fractions are split as well. Returns `None` if the term is not a fraction or if the denominator contains variables.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let x = || Term::<i32>::var("x");
+    /// assert_eq!((x() / Term::from(4)).to_fraction_components(), Some((x(), Term::from(4))));
+    /// assert_eq!(
+    ///     (x() / Term::from(-4)).to_fraction_components(),
+    ///     Some((-x(), Term::from(4)))
+    /// );
+    /// assert_eq!((Term::from(4) / x()).to_fraction_components(), None);
+    /// assert_eq!(x().to_fraction_components(), None);
+    /// ```
+    pub fn to_fraction_components(&self) -> Option<(Self, Self)> {
+        let (mut negative, fraction) = match &self.operation {
+            Operation::Negation(neg) => (true, &*neg.value),
+            operation => (false, operation),
+        };
+        let Operation::Division(div) = fraction else {
+            return None;
+        };
+
+        let mut denominator = Term {
+            operation: (*div.divisor).clone(),
+        };
+        if !denominator.variable_names().is_empty() {
+            return None;
+        }
+        if denominator.is_negative() {
+            negative = !negative;
+            denominator = match denominator.operation {
+                Operation::Number(num) => Term::from(Num::default() - num.value),
+                operation => -Term { operation },
+            };
+        }
+
+        let numerator = Term {
+            operation: (*div.divident).clone(),
+        };
+        Some((if negative { -numerator } else { numerator }, denominator))
+    }
+
+    /// Expands a constant term into a continued fraction, returning at most `max_terms` of its coefficients.
+    ///
+    /// Each coefficient is the whole part of the remaining value, the rest of which is flipped for the next one.
+    /// Negative values have all of their coefficients negated.
+    /// Returns an empty list if the term is not a number or a fraction of numbers,
+    /// or if it is negative and its coefficients cannot be negated (as with unsigned numbers).
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// assert_eq!(Term::div(415, 93).to_continued_fraction(10), vec![4, 2, 6, 7]);
+    /// assert_eq!(Term::div(415, 93).to_continued_fraction(2), vec![4, 2]);
+    /// assert_eq!((-Term::<u32>::div(1, 3)).to_continued_fraction(5), Vec::<u32>::new());
+    /// ```
+    pub fn to_continued_fraction(&self, max_terms: usize) -> Vec<Num>
+    where
+        Num: From<u8> + Bounded,
+    {
+        let Some((mut negative, mut numerator, mut denominator)) = self.constant_fraction() else {
+            return Vec::new();
+        };
+        if denominator < Num::default() {
+            numerator = Num::default() - numerator;
+            denominator = Num::default() - denominator;
+        }
+        if numerator < Num::default() {
+            negative = !negative;
+            numerator = Num::default() - numerator;
+        }
+        // unsigned numbers cannot hold the negated coefficients
+        if negative && numerator != Num::default() && Num::min_value() >= Num::default() {
+            return Vec::new();
+        }
+
+        let mut coefficients = Vec::new();
+        while coefficients.len() < max_terms && denominator != Num::default() {
+            let remainder = numerator.clone() % denominator.clone();
+            let whole = (numerator - remainder.clone()) / denominator.clone();
+            coefficients.push(if negative {
+                Num::default() - whole
+            } else {
+                whole
+            });
+            numerator = denominator;
+            denominator = remainder;
+        }
+        coefficients
+    }
+
+    /// Calculates the convergents of the continued fraction of a constant term, using at most `max_terms` coefficients.
+    ///
+    /// The convergents are the best rational approximations of the term with denominators up to their own.
+    /// Returns an empty list if the continued fraction cannot be calculated.
+    ///
+    /// See [`to_continued_fraction`](Term::to_continued_fraction).
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let pi = Term::div(3141592653u64, 1000000000);
+    /// assert_eq!(
+    ///     pi.to_continued_fraction_convergents(4),
+    ///     vec![Term::from(3), Term::div(22, 7), Term::div(333, 106), Term::div(355, 113)]
+    /// );
+    /// ```
+    pub fn to_continued_fraction_convergents(&self, max_terms: usize) -> Vec<Self>
+    where
+        Num: From<u8> + Bounded,
+    {
+        let (mut numerators, mut denominators) = (
+            (Num::default(), Num::from(1)),
+            (Num::from(1), Num::default()),
+        );
+
+        self.to_continued_fraction(max_terms)
+            .into_iter()
+            .map(|coefficient| {
+                numerators = (
+                    numerators.1.clone(),
+                    coefficient.clone() * numerators.1.clone() + numerators.0.clone(),
+                );
+                denominators = (
+                    denominators.1.clone(),
+                    coefficient * denominators.1.clone() + denominators.0.clone(),
+                );
+                Term::div(numerators.1.clone(), denominators.1.clone())
+            })
+            .collect()
+    }
+
+    /// Finds the fraction with a denominator of at most `max_denominator` closest to the value of the term.
+    ///
+    /// The value is calculated using floating point numbers, so this also works for irrational terms.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_denominator` is zero, if the term contains variables,
+    /// or if its value is not finite or its absolute value does not fit into a `u32`.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let third = Term::from(1.0) / Term::from(3.0);
+    /// assert_eq!(third.approximate_as_simple_fraction(10), Term::div(1, 3));
+    ///
+    /// let root = Term::from(2u32).pow_rational(1, 2);
+    /// assert_eq!(root.approximate_as_simple_fraction(100), Term::div(140, 99));
+    /// assert_eq!((-root).approximate_as_simple_fraction(10), -Term::div(7, 5));
+    /// ```
+    pub fn approximate_as_simple_fraction(&self, max_denominator: u32) -> Term<u32>
+    where
+        f64: From<Num>,
+    {
+        assert!(
+            max_denominator > 0,
+            "The maximum denominator has to be positive."
+        );
+        let value = self.calc_float::<f64>();
+        assert!(
+            value.is_finite() && value.abs() <= u32::MAX as f64,
+            "Cannot approximate {value} using a fraction of u32."
+        );
+
+        // the convergents of the continued fraction, as in `to_continued_fraction_convergents`
+        let max_denominator = u64::from(max_denominator);
+        let (mut numerators, mut denominators) = ((0u64, 1u64), (1u64, 0u64));
+        let mut rest = value.abs();
+        loop {
+            let coefficient = rest.floor();
+            let next = (coefficient as u64)
+                .saturating_mul(denominators.1)
+                .saturating_add(denominators.0);
+            if next > max_denominator {
+                break;
+            }
+            numerators = (
+                numerators.1,
+                coefficient as u64 * numerators.1 + numerators.0,
+            );
+            denominators = (denominators.1, next);
+
+            let fraction = rest - coefficient;
+            if fraction <= f64::EPSILON {
+                break;
+            }
+            rest = 1.0 / fraction;
+        }
+
+        // the best semiconvergent with an allowed denominator may be closer than the last convergent
+        let steps = (max_denominator - denominators.0) / denominators.1;
+        let semiconvergent = (
+            numerators.0 + steps * numerators.1,
+            denominators.0 + steps * denominators.1,
+        );
+        let distance = |(numerator, denominator): (u64, u64)| {
+            (numerator as f64 / denominator as f64 - value.abs()).abs()
+        };
+        let convergent = (numerators.1, denominators.1);
+        let (numerator, denominator) = if distance(semiconvergent) < distance(convergent) {
+            semiconvergent
+        } else {
+            convergent
+        };
+
+        // rounding up may exceed the largest u32, which the value itself is still below
+        let fraction = Term::div(
+            u32::try_from(numerator).unwrap_or(u32::MAX),
+            denominator as u32,
+        );
+        if value < 0.0 {
+            -fraction
+        } else {
+            fraction
+        }
+    }
+
+    /// Formats the term as a fraction using unicode vulgar fraction characters where available, if it is a rational number.
+    ///
+    /// Falls back to the notation of [`format_as_fraction`](Term::format_as_fraction) otherwise.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// assert_eq!(Term::div(1, 2).format_rational(), Some(String::from("½")));
+    /// assert_eq!((-Term::div(3, 4)).format_rational(), Some(String::from("-¾")));
+    /// assert_eq!(Term::div(7, 11).format_rational(), Some(String::from("7/11")));
+    /// assert_eq!(Term::<u32>::var("x").format_rational(), None);
+    /// ```
+    pub fn format_rational(&self) -> Option<String>
+    where
+        Num: Display,
+    {
+        const VULGAR_FRACTIONS: [(&str, &str); 18] = [
+            ("1/2", "½"),
+            ("1/3", "⅓"),
+            ("2/3", "⅔"),
+            ("1/4", "¼"),
+            ("3/4", "¾"),
+            ("1/5", "⅕"),
+            ("2/5", "⅖"),
+            ("3/5", "⅗"),
+            ("4/5", "⅘"),
+            ("1/6", "⅙"),
+            ("5/6", "⅚"),
+            ("1/7", "⅐"),
+            ("1/8", "⅛"),
+            ("3/8", "⅜"),
+            ("5/8", "⅝"),
+            ("7/8", "⅞"),
+            ("1/9", "⅑"),
+            ("1/10", "⅒"),
+        ];
+
+        let fraction = self.format_as_fraction()?;
+        let (sign, unsigned) = match fraction.strip_prefix('-') {
+            Some(unsigned) => ("-", unsigned),
+            None => ("", fraction.as_str()),
+        };
+        match VULGAR_FRACTIONS
+            .iter()
+            .find(|(plain, _)| *plain == unsigned)
+        {
+            Some((_, vulgar)) => Some(format!("{sign}{vulgar}")),
+            None => Some(fraction),
+        }
+    }
+
+    /// Formats the term as a mixed number, if it is a positive improper fraction.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// assert_eq!(Term::div(7, 2).format_as_mixed_number(), Some(String::from("3 1/2")));
+    /// assert_eq!(Term::div(1, 2).format_as_mixed_number(), None);
+    /// assert_eq!((-Term::div(7, 2)).format_as_mixed_number(), None);
+    /// ```
+    pub fn format_as_mixed_number(&self) -> Option<String>
+    where
+        Num: Display,
+    {
+        match &self.operation {
+            Operation::Division(div) => match (&*div.divident, &*div.divisor) {
+                (Operation::Number(divident), Operation::Number(divisor))
+                    if divident.value > divisor.value && divisor.value > Num::default() =>
+                {
+                    let whole = divident.value.clone() / divisor.value.clone();
+                    let remainder = divident.value.clone() % divisor.value.clone();
+                    Some(format!("{} {}/{}", whole, remainder, divisor.value))
+                }
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Formats the approximate value of the term, with just enough decimal places to be within `epsilon` of the exact value.
+    ///
+    /// The number of decimal places is `ceil(-log10(epsilon))`. Values larger than `1 / epsilon` are written in scientific notation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `epsilon` is not positive.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// assert_eq!(Term::div(1, 3).approximate_with_epsilon(1e-3), "0.333");
+    /// assert_eq!(Term::div(2, 3).approximate_with_epsilon(0.05), "0.67");
+    /// assert_eq!(Term::from(123456).approximate_with_epsilon(1e-3), "1.235e5");
+    /// ```
+    pub fn approximate_with_epsilon(&self, epsilon: f64) -> String
+    where
+        f64: From<Num>,
+    {
+        if epsilon <= 0.0 || epsilon.is_nan() {
+            panic!("Epsilon has to be positive.");
+        }
+
+        let value = self.calc_float::<f64>();
+        let digits = (-epsilon.log10()).ceil().max(0.0) as usize;
+
+        if value.abs() > 1.0 / epsilon {
+            format!("{value:.digits$e}")
+        } else {
+            format!("{value:.digits$}")
+        }
+    }
+
+    /// Reduces all fractions of constant numbers within the term to their lowest terms.
+    ///
+    /// ```rust
+    /// # use crem::*;
+    /// let mut unreduced = Term::<u32>::div(1, 2).pow_rational(1, 3);
+    /// unreduced.walk_mut(|op| {
+    ///     if let Operation::Number(num) = op {
+    ///         num.value *= 3;
+    ///     }
+    /// });
+    /// assert_ne!(unreduced, Term::div(1, 2).pow_rational(1, 3));
+    /// assert_eq!(unreduced.reduce_to_lowest_terms(), Term::div(1, 2).pow_rational(1, 3));
+    /// ```
+    pub fn reduce_to_lowest_terms(&self) -> Self {
+        let mut term = self.clone();
+        term.walk_mut(|op| {
+            if let Operation::Division(div) = op {
+                if let (Operation::Number(_), Operation::Number(_)) =
+                    (&*div.divident, &*div.divisor)
+                {
+                    let divident = std::mem::take(&mut *div.divident);
+                    let divisor = std::mem::take(&mut *div.divisor);
+                    *op = divident / divisor;
+                }
+            }
+        });
+        term
+    }
+
+    /// Calculates a deterministic hash of the structure of the term.
+    ///
+    /// Structurally identical terms always produce the same hash,
+    /// so comparing hashes is a fast way to rule out equality of large terms.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let a = Term::from(2) * Term::var("x") + Term::from(1);
+    /// let b = Term::from(2) * Term::var("x") + Term::from(1);
+    /// let c = Term::from(2) * Term::var("y") + Term::from(1);
+    ///
+    /// assert_eq!(a.structural_hash(), b.structural_hash());
+    /// assert_ne!(a.structural_hash(), c.structural_hash());
+    /// ```
+    pub fn structural_hash(&self) -> u64
+    where
+        Num: Hash,
+    {
+        let mut hasher = FnvHasher::default();
+
+        // the nodes are visited in post-order, so hashing the arity of every node identifies the tree uniquely
+        self.operation.walk(&mut |op| match op {
+            Operation::Addition(add) => {
+                hasher.write_u8(0);
+                hasher.write_usize(add.summands.len());
+            }
+            Operation::Multiplication(mul) => {
+                hasher.write_u8(1);
+                hasher.write_usize(mul.multipliers.len());
+            }
+            Operation::Division(_) => hasher.write_u8(2),
+            Operation::Negation(_) => hasher.write_u8(3),
+            Operation::Number(num) => {
+                hasher.write_u8(4);
+                num.value.hash(&mut hasher);
+            }
+            Operation::Variable(var) => {
+                hasher.write_u8(5);
+                var.name.hash(&mut hasher);
+            }
+            Operation::RationalPower(pow) => {
+                hasher.write_u8(6);
+                hasher.write_u32(pow.numer);
+                hasher.write_u32(pow.denom);
+            }
+            Operation::FloorDivision(_) => hasher.write_u8(7),
+            Operation::MulAdd(_) => hasher.write_u8(8),
+            Operation::BitAnd(_) => hasher.write_u8(9),
+            Operation::BitOr(_) => hasher.write_u8(10),
+            Operation::BitXor(_) => hasher.write_u8(11),
+            Operation::Shl(_) => hasher.write_u8(12),
+            Operation::Shr(_) => hasher.write_u8(13),
+            Operation::PowMod(_) => hasher.write_u8(14),
+        });
+
+        hasher.finish()
+    }
+
+    /// Splits the term into the kind of its outermost operation and its direct children.
+    ///
+    /// The children of a division are its divident and its divisor, the only child of a power is its base.
+    /// Numbers and variables have no children, use [`leaf_value`](Term::leaf_value) to get their value.
+    ///
+    /// ```rust
+    /// # use crem::*;
+    /// let (variant, children) = (Term::<u32>::var("x") / Term::var("y")).into_parts();
+    /// assert_eq!(variant, OperationVariant::Division);
+    /// assert_eq!(children, vec![Term::var("x"), Term::var("y")]);
+    ///
+    /// let (variant, children) = Term::from(3).into_parts();
+    /// assert_eq!(variant, OperationVariant::Number);
+    /// assert!(children.is_empty());
+    /// ```
+    pub fn into_parts(self) -> (OperationVariant, Vec<Term<Num>>) {
+        let (variant, children) = match self.operation {
+            Operation::Addition(add) => (OperationVariant::Addition, add.summands),
+            Operation::Multiplication(mul) => (OperationVariant::Multiplication, mul.multipliers),
+            Operation::Division(div) => (
+                OperationVariant::Division,
+                vec![*div.divident, *div.divisor],
+            ),
+            Operation::Negation(neg) => (OperationVariant::Negation, vec![*neg.value]),
+            Operation::Number(_) => (OperationVariant::Number, Vec::new()),
+            Operation::Variable(_) => (OperationVariant::Variable, Vec::new()),
+            Operation::RationalPower(pow) => (OperationVariant::Power, vec![*pow.base]),
+            Operation::FloorDivision(div) => (
+                OperationVariant::FloorDivision,
+                vec![*div.divident, *div.divisor],
+            ),
+            Operation::MulAdd(fma) => (
+                OperationVariant::MulAdd,
+                vec![*fma.multiplicand, *fma.multiplier, *fma.summand],
+            ),
+            Operation::BitAnd(bit) => (OperationVariant::BitAnd, vec![*bit.lhs, *bit.rhs]),
+            Operation::BitOr(bit) => (OperationVariant::BitOr, vec![*bit.lhs, *bit.rhs]),
+            Operation::BitXor(bit) => (OperationVariant::BitXor, vec![*bit.lhs, *bit.rhs]),
+            Operation::Shl(bit) => (OperationVariant::Shl, vec![*bit.lhs, *bit.rhs]),
+            Operation::Shr(bit) => (OperationVariant::Shr, vec![*bit.lhs, *bit.rhs]),
+            Operation::PowMod(pow_mod) => (
+                OperationVariant::PowMod,
+                vec![*pow_mod.base, *pow_mod.exp, *pow_mod.modulus],
+            ),
+        };
+        (
+            variant,
+            children
+                .into_iter()
+                .map(|operation| Term { operation })
+                .collect(),
+        )
+    }
+
+    /// Combines two terms of the same shape by applying `op` to each pair of corresponding numbers and variables.
+    ///
+    /// If the shapes of the terms differ, `op` is applied to the whole terms instead.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let a = Term::from(2u32) * Term::var("x") + Term::from(1);
+    /// let b = Term::from(3u32) * Term::var("y") + Term::from(4);
+    ///
+    /// let zipped = Term::zip(&a, &b, |first, second| first + second);
+    /// assert_eq!(zipped, Term::from(5) * (Term::var("x") + Term::var("y")) + Term::from(5));
+    ///
+    /// let mismatched = Term::zip(&a, &Term::var("z"), |first, second| first * second);
+    /// assert_eq!(mismatched, a.clone() * Term::var("z"));
+    /// ```
+    pub fn zip(
+        a: &Term<Num>,
+        b: &Term<Num>,
+        op: impl Fn(Term<Num>, Term<Num>) -> Term<Num>,
+    ) -> Self {
+        Term {
+            operation: zip_operations(&a.operation, &b.operation, &op),
+        }
+    }
+
+    /// Returns the value of the term if it is a single number or variable.
+    ///
+    /// ```rust
+    /// # use crem::*;
+    /// assert_eq!(Term::from(3).leaf_value(), Some(LeafValue::Number(3)));
+    /// assert_eq!(Term::<u32>::var("x").leaf_value(), Some(LeafValue::Variable(String::from("x"))));
+    /// assert_eq!((Term::<u32>::var("x") + Term::from(1)).leaf_value(), None);
+    /// ```
+    pub fn leaf_value(&self) -> Option<LeafValue<Num>> {
+        match &self.operation {
+            Operation::Number(num) => Some(LeafValue::Number(num.value.clone())),
+            Operation::Variable(var) => Some(LeafValue::Variable(var.name.clone())),
+            _ => None,
+        }
+    }
+
+    /// Creates a configuration limiting the number of nested simplification steps to `n`.
+    ///
+    /// Once the limit is reached, operations are no longer simplified,
+    /// leaving the term partially simplified but valid.
+    pub fn simplification_depth_limit(n: usize) -> TermConfig {
+        TermConfig {
+            simplification_depth_limit: Some(n),
+        }
+    }
+
+    /// Applies the configuration to all terms created on the current thread until the returned guard is dropped.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let guard = Term::<u32>::with_config(Term::<u32>::simplification_depth_limit(0));
+    /// let unsimplified = Term::from(1u32) + Term::from(2);
+    /// drop(guard);
+    ///
+    /// assert_ne!(unsimplified, Term::from(3));
+    /// assert_eq!(unsimplified.calc::<f64>(), 3.0);
+    /// ```
+    pub fn with_config(config: TermConfig) -> ConfigGuard {
+        config.apply()
+    }
+
+    /// Adds all terms, collecting them into a single flat sum.
+    ///
+    /// Numbers, negations and divisions are still combined as when using `+`.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let terms = vec![Term::<u32>::var("x"), Term::from(2), Term::var("y"), Term::from(3)];
+    /// assert_eq!(Term::sum(terms), Term::var("x") + Term::var("y") + Term::from(5));
+    /// assert_eq!(Term::<u32>::sum(vec![]), Term::from(0));
+    /// ```
+    pub fn sum(terms: impl IntoIterator<Item = Term<Num>>) -> Self {
+        let mut pending: Vec<Operation<Num>> =
+            terms.into_iter().map(|term| term.operation).collect();
+        pending.reverse();
+
+        let mut summands = Vec::new();
+        let mut rest = Operation::default();
+        while let Some(operation) = pending.pop() {
+            match operation {
+                Operation::Addition(add) => pending.extend(add.summands.into_iter().rev()),
+                Operation::Number(_) | Operation::Negation(_) | Operation::Division(_) => {
+                    rest = rest + operation
+                }
+                any => summands.push(any),
+            }
+        }
+
+        let flat = match summands.len() {
+            0 => Operation::default(),
+            1 => summands.remove(0),
+            _ => Operation::Addition(Addition { summands }),
+        };
+        Term {
+            operation: flat + rest,
+        }
+    }
+
+    /// Multiplies all terms, collecting them into a single flat product.
+    ///
+    /// Numbers, negations and divisions are still combined as when using `*`.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let terms = vec![Term::<u32>::var("x"), Term::from(2), Term::var("y"), Term::from(3)];
+    /// assert_eq!(Term::product(terms), Term::var("x") * Term::var("y") * Term::from(6));
+    /// assert_eq!(Term::<u32>::product(vec![Term::var("x"), Term::from(0)]), Term::from(0));
+    /// ```
+    pub fn product(terms: impl IntoIterator<Item = Term<Num>>) -> Self
+    where
+        Num: From<u8>,
+    {
+        let mut pending: Vec<Operation<Num>> =
+            terms.into_iter().map(|term| term.operation).collect();
+        pending.reverse();
+
+        let mut multipliers = Vec::new();
+        let mut rest = Operation::from(Num::from(1));
+        while let Some(operation) = pending.pop() {
+            match operation {
+                Operation::Multiplication(mul) => pending.extend(mul.multipliers.into_iter().rev()),
+                Operation::Number(_) | Operation::Negation(_) | Operation::Division(_) => {
+                    rest = rest * operation
+                }
+                any => multipliers.push(any),
+            }
+        }
+
+        let flat = match multipliers.len() {
+            0 => return Term { operation: rest },
+            1 => multipliers.remove(0),
+            _ => Operation::Multiplication(Multiplication { multipliers }),
+        };
+        Term {
+            operation: if rest == Operation::from(Num::from(1)) {
+                flat
+            } else {
+                flat * rest
+            },
+        }
+    }
+
+    /// Adds each variable multiplied with its coefficient, collecting them into a single flat sum.
+    ///
+    /// Pairs with a coefficient of zero are left out.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// assert_eq!(
+    ///     Term::from_pairs_add([(3u32, "x"), (0, "y"), (1, "z")]),
+    ///     Term::from(3) * Term::var("x") + Term::var("z")
+    /// );
+    /// ```
+    pub fn from_pairs_add(pairs: impl IntoIterator<Item = (Num, impl Into<String>)>) -> Self
+    where
+        Num: From<u8>,
+    {
+        Term::sum(
+            pairs
+                .into_iter()
+                .filter(|(coefficient, _)| *coefficient != Num::default())
+                .map(|(coefficient, name)| Term {
+                    operation: multiply_skipping_one(
+                        Operation::from(coefficient),
+                        Term::var(name).operation,
+                    ),
+                }),
+        )
+    }
+
+    /// Adds a variable for each of the names, collecting them into a single flat sum.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// assert_eq!(
+    ///     Term::<u32>::sum_of_vars(&["x", "y", "z"]),
+    ///     Term::var("x") + Term::var("y") + Term::var("z")
+    /// );
+    /// assert_eq!(Term::<u32>::sum_of_vars(&[]), Term::from(0));
+    /// ```
+    pub fn sum_of_vars(names: &[&str]) -> Self {
+        Term::sum(names.iter().map(|name| Term::var(*name)))
+    }
+
+    /// Multiplies a variable for each of the names, collecting them into a single flat product.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// assert_eq!(
+    ///     Term::<u32>::product_of_vars(&["x", "y", "z"]),
+    ///     Term::var("x") * Term::var("y") * Term::var("z")
+    /// );
+    /// assert_eq!(Term::<u32>::product_of_vars(&[]), Term::from(1));
+    /// ```
+    pub fn product_of_vars(names: &[&str]) -> Self
+    where
+        Num: From<u8>,
+    {
+        Term::product(names.iter().map(|name| Term::var(*name)))
+    }
+
+    /// Separates the constant number added to the term from the rest of the term.
+    ///
+    /// Returns the remaining term and the constant, or `None` as the constant if no number is added to the term.
+    /// Subtracted numbers are part of the constant, unless the number type is unsigned.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let term = (Term::from(2u32) + Term::from(3)) * Term::var("x") + Term::from(4) + Term::from(5);
+    /// assert_eq!(term.constant_fold(), (Term::from(5) * Term::var("x"), Some(9)));
+    ///
+    /// assert_eq!(Term::from(7u32).constant_fold(), (Term::from(0), Some(7)));
+    /// assert_eq!(Term::<u32>::var("x").constant_fold(), (Term::var("x"), None));
+    ///
+    /// let difference = Term::<i32>::var("x") - Term::from(3);
+    /// assert_eq!(difference.constant_fold(), (Term::var("x"), Some(-3)));
+    /// let difference = Term::<u32>::var("x") - Term::from(3);
+    /// assert_eq!(difference.constant_fold(), (difference.clone(), None));
+    /// ```
+    pub fn constant_fold(&self) -> (Self, Option<Num>)
+    where
+        Num: Bounded,
+    {
+        match &self.operation {
+            Operation::Number(num) => (Term::default(), Some(num.value.clone())),
+            Operation::Negation(_) => match constant_number(&self.operation) {
+                Some(value) => (Term::default(), Some(value)),
+                None => (self.clone(), None),
+            },
+            Operation::Addition(add) => {
+                let mut constant = None;
+                let mut rest = Vec::new();
+                for summand in &add.summands {
+                    match constant_number(summand) {
+                        Some(value) => {
+                            constant = Some(match constant {
+                                Some(constant) => constant + value,
+                                None => value,
+                            })
+                        }
+                        None => rest.push(Term {
+                            operation: summand.clone(),
+                        }),
+                    }
+                }
+                if constant.is_none() {
+                    (self.clone(), None)
+                } else {
+                    (Term::sum(rest), constant)
+                }
+            }
+            _ => (self.clone(), None),
+        }
+    }
+
+    /// Collects the values of all numbers within the term, from left to right.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let term = Term::from(2u32) * Term::var("x") + Term::from(5) / Term::var("y");
+    /// assert_eq!(term.collect_constants(), vec![2, 5]);
+    /// ```
+    pub fn collect_constants(&self) -> Vec<Num> {
+        let mut constants = Vec::new();
+        // only leaves are collected, which are visited in the same order by any depth-first traversal
+        self.operation.walk(&mut |op| {
+            if let Operation::Number(num) = op {
+                constants.push(num.value.clone());
+            }
+        });
+        constants
+    }
+
+    /// Sums the values of all numbers within the term.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let term = Term::from(2u32) * Term::var("x") + Term::from(5) / Term::var("y");
+    /// assert_eq!(term.sum_constants(), 7);
+    /// ```
+    pub fn sum_constants(&self) -> Num {
+        self.collect_constants()
+            .into_iter()
+            .fold(Num::default(), |acc, constant| acc + constant)
+    }
+
+    /// Returns the largest value of all numbers within the term, or `None` if there are none.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let term = Term::from(2u32) * Term::var("x") + Term::from(5) / Term::var("y");
+    /// assert_eq!(term.max_constant(), Some(5));
+    /// assert_eq!(Term::<u32>::var("x").max_constant(), None);
+    /// ```
+    pub fn max_constant(&self) -> Option<Num> {
+        self.collect_constants()
+            .into_iter()
+            .reduce(|max, constant| if constant > max { constant } else { max })
+    }
+
+    /// Returns the smallest value of all numbers within the term, or `None` if there are none.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let term = Term::from(2u32) * Term::var("x") + Term::from(5) / Term::var("y");
+    /// assert_eq!(term.min_constant(), Some(2));
+    /// assert_eq!(Term::<u32>::var("x").min_constant(), None);
+    /// ```
+    pub fn min_constant(&self) -> Option<Num> {
+        self.collect_constants()
+            .into_iter()
+            .reduce(|min, constant| if constant < min { constant } else { min })
+    }
+
+    /// Moves negations of divisions onto their divident, and negations of multiplications onto their first multiplier.
+    ///
+    /// Afterwards, negations only appear on the numerators of fractions, on the first multiplier of multiplications or directly on other operations.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let x = || Term::<i32>::var("x");
+    /// let y = || Term::<i32>::var("y");
+    /// assert_eq!(
+    ///     (-(x() / y())).canonicalize_negations().to_prefix_notation(),
+    ///     "(/ (- x) y)"
+    /// );
+    /// assert_eq!(
+    ///     (-(x() * y())).canonicalize_negations().to_prefix_notation(),
+    ///     "(* (- x) y)"
+    /// );
+    /// ```
+    pub fn canonicalize_negations(&self) -> Self {
+        let mut term = self.clone();
+        term.walk_mut(|operation| *operation = take(operation).canonicalize_negation());
+        term
+    }
+
+    /// Reorders the summands of all additions, which tends to improve the accuracy of [`calc_float`](Term::calc_float).
+    ///
+    /// Constant summands come first, from the smallest to the largest absolute value,
+    /// followed by the other summands from the least to the most deeply nested.
+    /// Fractions with a common divisor don't need to be grouped, as they are already combined when adding them.
+    ///
+    /// The result is mathematically equal to the term. As floating point addition is not associative, the
+    /// calculated result may differ slightly, but there is no guarantee that it is more accurate.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let x = || Term::<f64>::var("x");
+    /// let term = x() * x() + x() + Term::from(1e-3);
+    /// assert_eq!(
+    ///     term.optimize_for_float().to_prefix_notation(),
+    ///     "(+ 0.001 x (* x x))"
+    /// );
+    /// ```
+    pub fn optimize_for_float(&self) -> Self
+    where
+        f64: From<Num>,
+    {
+        let mut term = self.clone();
+        term.walk_mut(|operation| {
+            if let Operation::Addition(add) = operation {
+                let key = |summand: &Operation<Num>| match constant_sign(summand) {
+                    Some(_) => (0, summand.calc_float::<f64>().abs()),
+                    None => (1, summand.depth() as f64),
+                };
+                add.summands
+                    .sort_by(|a, b| key(a).partial_cmp(&key(b)).unwrap_or(Ordering::Equal));
+            }
+        });
+        term
+    }
+
+    /// Simplifies the term once more after it has been built, catching what simplification during construction missed.
+    ///
+    /// The whole term is rebuilt, folding constant sub-expressions. Afterwards, common factors of fractions are
+    /// cancelled using [`from_ratio_parts`](Term::from_ratio_parts), and multiplications by one are removed.
+    ///
+    /// ```rust
+    /// # use crem::*;
+    /// let x = || Term::<u32>::var("x");
+    /// let mut term = Term::from(2) * x() + x() / Term::from(3);
+    /// term.walk_mut(|op| {
+    ///     if let Operation::Number(num) = op {
+    ///         num.value = 1;
+    ///     }
+    /// });
+    /// assert_ne!(term, x() + x());
+    /// assert_eq!(term.constant_propagation(), x() + x());
+    /// ```
+    pub fn constant_propagation(&self) -> Self
+    where
+        Num: From<u8>,
+    {
+        let mut term = Term {
+            operation: self.operation.set_vars(&[]),
+        };
+        term.walk_mut(|operation| match operation {
+            Operation::Division(div) => {
+                *operation = Term::from_ratio_parts(
+                    Term {
+                        operation: take(&mut *div.divident),
+                    },
+                    Term {
+                        operation: take(&mut *div.divisor),
+                    },
+                )
+                .operation
+            }
+            Operation::Multiplication(mul) => {
+                *operation = take(&mut mul.multipliers)
+                    .into_iter()
+                    .fold(Operation::from(Num::from(1)), multiply_skipping_one)
+            }
+            _ => (),
+        });
+        term
+    }
+
+    /// Simplifies the term as far as possible by applying [`constant_propagation`](Term::constant_propagation)
+    /// until the term stops getting any simpler.
+    ///
+    /// ```rust
+    /// # use crem::*;
+    /// let x = || Term::<u32>::var("x");
+    /// let mut term = (x() + Term::from(2)) / (x() + Term::from(3));
+    /// term.walk_mut(|op| {
+    ///     if let Operation::Number(num) = op {
+    ///         num.value = 1;
+    ///     }
+    /// });
+    /// assert_eq!(term.simplify(), Term::from(1));
+    /// ```
+    pub fn simplify(&self) -> Self
+    where
+        Num: From<u8>,
+    {
+        let mut term = self.constant_propagation();
+        loop {
+            let next = term.constant_propagation();
+            if !next.operation.is_simpler_than(&term.operation) {
+                return term;
+            }
+            term = next;
+        }
+    }
+
+    /// Rewrites every sum containing fractions as a single fraction over the least common denominator.
+    ///
+    /// Numeric denominators are combined using their least common multiple, while other denominators are multiplied.
+    /// Common factors of the resulting fraction are cancelled using [`from_ratio_parts`](Term::from_ratio_parts).
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let guard = Term::<u32>::with_config(Term::<u32>::simplification_depth_limit(0));
+    /// let term = Term::<u32>::div(1, 2) + Term::div(1, 3) + Term::div(1, 6);
+    /// drop(guard);
+    /// assert_ne!(term, Term::from(1));
+    /// assert_eq!(term.collect_common_denominator(), Term::from(1));
+    ///
+    /// let x = || Term::<u32>::var("x");
+    /// let guard = Term::<u32>::with_config(Term::<u32>::simplification_depth_limit(0));
+    /// let term = Term::from(2) / x() + x() / Term::from(4);
+    /// drop(guard);
+    /// assert_eq!(
+    ///     term.collect_common_denominator().to_prefix_notation(),
+    ///     "(/ (+ (* x x) 8) (* x 4))"
+    /// );
+    /// ```
+    pub fn collect_common_denominator(&self) -> Self
+    where
+        Num: From<u8>,
+    {
+        let mut term = self.clone();
+        term.walk_mut(|operation| {
+            if let Operation::Addition(add) = operation {
+                *operation = over_common_denominator(take(&mut add.summands)).operation;
+            }
+        });
+        term
+    }
+
+    /// Combines equal multipliers of every multiplication into a power, e.g. `x * x * x` into `x^3`.
+    ///
+    /// Integer powers are usually written as repeated multiplications, so the result is not simplified.
+    /// Like other powers, it can only be calculated using [`calc_float`](Term::calc_float).
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let x = || Term::<u32>::var("x");
+    /// let y = || Term::<u32>::var("y");
+    /// let term = Term::from(2) * x() * y() * x() * x();
+    /// assert_eq!(term.exponent_collect().to_string(), "(2 * x^3 * y)");
+    ///
+    /// let values = [("x", &Term::from(3)), ("y", &Term::from(2))];
+    /// assert_eq!(term.exponent_collect().with_vars(&values).calc_float::<f64>(), 108.0);
+    /// ```
+    pub fn exponent_collect(&self) -> Self {
+        let mut term = self.clone();
+        term.walk_mut(|operation| {
+            if let Operation::Multiplication(mul) = operation {
+                let mut counted: Vec<(Operation<Num>, u32)> = Vec::new();
+                for multiplier in take(&mut mul.multipliers) {
+                    match counted.iter_mut().find(|(other, _)| *other == multiplier) {
+                        Some((_, count)) => *count += 1,
+                        None => counted.push((multiplier, 1)),
+                    }
+                }
+                mul.multipliers = counted
+                    .into_iter()
+                    .map(|(multiplier, count)| match count {
+                        1 => multiplier,
+                        count => RationalPower::with_base(multiplier, count, 1),
+                    })
+                    .collect();
+                if mul.multipliers.len() == 1 {
+                    *operation = mul.multipliers.remove(0);
+                }
+            }
+        });
+        term
+    }
+
+    /// Cancels common factors of the numerators and denominators of every multiplication containing fractions,
+    /// e.g. `(a/b) * (b/c)` into `a/c`.
+    ///
+    /// All numerators and denominators are collected into two flat lists. Equal factors are removed from both
+    /// and the numbers are reduced by their greatest common divisor. Single fractions are treated the same way,
+    /// so `(b*a) / b` becomes `a`.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let guard = Term::<u32>::with_config(Term::<u32>::simplification_depth_limit(0));
+    /// let term = Term::<u32>::div(2, 3) * Term::div(3, 5);
+    /// drop(guard);
+    /// assert_eq!(term.reduce_multiplication_by_division(), Term::div(2, 5));
+    ///
+    /// let a = || Term::<u32>::var("a");
+    /// let b = || Term::<u32>::var("b");
+    /// assert_eq!((a() / b() * b()).reduce_multiplication_by_division(), a());
+    /// ```
+    pub fn reduce_multiplication_by_division(&self) -> Self
+    where
+        Num: From<u8>,
+    {
+        let mut term = self.clone();
+        term.walk_mut(|operation| {
+            let has_fraction = match operation {
+                Operation::Multiplication(mul) => mul
+                    .multipliers
+                    .iter()
+                    .any(|multiplier| matches!(multiplier, Operation::Division(_))),
+                Operation::Division(_) => true,
+                _ => false,
+            };
+            if has_fraction {
+                *operation = cancel_common_factors(take(operation)).operation;
+            }
+        });
+        term
+    }
+
+    /// Multiplies every number within the term by the factor.
+    ///
+    /// This is the same as replacing each number `n` by `n * factor`, wherever it appears:
+    /// both parts of a fraction of numbers are scaled, as are numbers within powers, modular exponentiations and bitwise operations.
+    /// Terms without any numbers are left unchanged.
+    /// The result is simplified again using [`constant_propagation`](Term::constant_propagation), cancelling common factors of fractions.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let x = || Term::<u32>::var("x");
+    /// let term = Term::from(3) * x() + Term::from(5);
+    /// assert_eq!(term.scale_all_numbers(2), Term::from(6) * x() + Term::from(10));
+    /// assert_eq!((x() / Term::from(3)).scale_all_numbers(2), x() / Term::from(6));
+    /// assert_eq!(x().scale_all_numbers(2), x());
+    /// ```
+    pub fn scale_all_numbers(&self, factor: Num) -> Self
+    where
+        Num: From<u8>,
+    {
+        let mut term = self.clone();
+        term.walk_mut(|operation| {
+            if let Operation::Number(num) = operation {
+                num.value = num.value.clone() * factor.clone();
+            }
+        });
+        term.constant_propagation()
+    }
+
+    /// Calculates a lower and an upper bound of the term using interval arithmetic,
+    /// given the ranges the values of some variables lie within.
+    ///
+    /// Variables without a given range are kept, so the bounds may themselves contain variables.
+    /// Where bounds containing variables cannot be compared, all values are assumed to be non-negative.
+    /// Powers are assumed to have a non-negative base.
+    ///
+    /// Returns `None` if the term is unbounded, because a divisor may be zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the term contains floor divisions, modular exponentiations or bitwise operations.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// # use std::collections::HashMap;
+    /// let term = Term::<i32>::var("x") * Term::var("y") - Term::var("x");
+    /// let bounds = HashMap::from([("x", (1, 3)), ("y", (2, 4))]);
+    /// assert_eq!(
+    ///     term.evaluate_symbolically_with_bounds(&bounds),
+    ///     Some((-Term::from(1), Term::from(11)))
+    /// );
+    ///
+    /// let term = Term::<i32>::from(1) / Term::var("x");
+    /// assert_eq!(term.evaluate_symbolically_with_bounds(&bounds), Some((Term::div(1, 3), Term::from(1))));
+    /// let bounds = HashMap::from([("x", (-1, 1))]);
+    /// assert_eq!(term.evaluate_symbolically_with_bounds(&bounds), None);
+    /// ```
+    pub fn evaluate_symbolically_with_bounds(
+        &self,
+        bounds: &HashMap<&str, (Num, Num)>,
+    ) -> Option<(Self, Self)> {
+        let (low, high) = operation_bounds(&self.operation, bounds)?;
+        Some((Term { operation: low }, Term { operation: high }))
+    }
+
+    /// Checks whether the term is fully expanded, i.e. no multiplication has a sum as one of its multipliers.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let x = || Term::<u32>::var("x");
+    /// let y = || Term::<u32>::var("y");
+    /// assert!((x() * y() + x()).is_distributively_expanded());
+    /// assert!(!(x() * (y() + Term::var("z"))).is_distributively_expanded());
+    /// ```
+    pub fn is_distributively_expanded(&self) -> bool {
+        let mut expanded = true;
+        self.operation.walk(&mut |op| {
+            if let Operation::Multiplication(mul) = op {
+                if mul
+                    .multipliers
+                    .iter()
+                    .any(|multiplier| matches!(multiplier, Operation::Addition(_)))
+                {
+                    expanded = false;
+                }
+            }
+        });
+        expanded
+    }
+
+    /// Calculates one divided by the term.
+    ///
+    /// Fractions are flipped directly, without being simplified again.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// assert_eq!(Term::from(4u32).reciprocal(), Term::div(1, 4));
+    /// assert_eq!(Term::div(2u32, 3).reciprocal(), Term::div(3, 2));
+    /// assert_eq!(Term::div(1u32, 3).reciprocal(), Term::from(3));
+    /// assert_eq!(Term::<u32>::var("x").reciprocal(), Term::from(1) / Term::var("x"));
+    /// ```
+    pub fn reciprocal(&self) -> Self
+    where
+        Num: From<u8>,
+    {
+        match &self.operation {
+            Operation::Number(num) => Term::div(Num::from(1), num.value.clone()),
+            Operation::Division(div) => match &*div.divident {
+                Operation::Number(num) if num.value == Num::from(1) => Term {
+                    operation: (*div.divisor).clone(),
+                },
+                _ => {
+                    let mut div = div.clone();
+                    swap(&mut div.divident, &mut div.divisor);
+                    Term {
+                        operation: Operation::Division(div),
+                    }
+                }
+            },
+            _ => Term::from(Num::from(1)) / self.clone(),
+        }
+    }
+
+    /// Creates a polynomial in the given variable from its coefficients (starting with the constant one) using Horner's scheme.
+    ///
+    /// `a0 + a1*x + a2*x^2 + a3*x^3` is built as `a0 + x*(a1 + x*(a2 + x*a3))`,
+    /// which needs fewer multiplications and only grows linearly in depth.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let coefficients = [Term::from(1u32), Term::from(2), Term::from(3)];
+    /// let term = Term::horner_eval(&coefficients, "x");
+    /// assert_eq!(term.to_prefix_notation(), "(+ 1 (* x (+ 2 (* x 3))))");
+    /// assert_eq!(term.with_var("x", &Term::from(2)), Term::from(17));
+    /// ```
+    pub fn horner_eval(coefficients: &[Term<Num>], var: &str) -> Self {
+        coefficients
+            .iter()
+            .rev()
+            .cloned()
+            .reduce(|acc, coefficient| coefficient + Term::var(var) * acc)
+            .unwrap_or_default()
+    }
+
+    /// Estimates how many decimal digits of the result are exact after calculating the term using [`f64`].
+    ///
+    /// A bare constant is exact to 15 digits. Every floating point operation needed to calculate the term may introduce
+    /// a rounding error, losing the logarithm of the number of operations in digits. Each division by a constant
+    /// additionally loses the logarithm of its divisor, as the fraction can usually not be represented exactly.
+    ///
+    /// This is a rough estimate, not a guarantee.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// assert_eq!(Term::from(3).degree_of_precision(), 15);
+    /// assert_eq!(Term::div(1, 3).degree_of_precision(), 14);
+    /// assert_eq!(Term::div(1, 3000).degree_of_precision(), 11);
+    /// ```
+    pub fn degree_of_precision(&self) -> u32
+    where
+        f64: From<Num>,
+    {
+        let mut operations = 0usize;
+        let mut divisor_loss = 0.0;
+        self.operation.walk(&mut |op| match op {
+            Operation::Addition(add) => operations += add.summands.len() - 1,
+            Operation::Multiplication(mul) => operations += mul.multipliers.len() - 1,
+            Operation::Division(div) => {
+                operations += 1;
+                let mut constant = true;
+                div.divisor.walk(&mut |op| {
+                    if let Operation::Variable(_) = op {
+                        constant = false;
+                    }
+                });
+                if constant {
+                    divisor_loss += div.divisor.calc_float::<f64>().abs().log10().max(0.0);
+                }
+            }
+            Operation::RationalPower(_) | Operation::FloorDivision(_) => operations += 1,
+            Operation::MulAdd(_) | Operation::PowMod(_) => operations += 2,
+            _ => (),
+        });
+
+        let loss = ((operations + 1) as f64).log10() + divisor_loss;
+        (f64::DIGITS as f64 - loss.ceil()).max(0.0) as u32
+    }
+
+    /// Composes two functions, substituting the only variable of `f` with `g`.
+    ///
+    /// Returns an error if `f` does not have exactly one variable.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let f = Term::<u32>::var("x") * Term::var("x");
+    /// let g = Term::var("y") + Term::from(1);
+    /// let composed = Term::compose_two(&f, &g)?;
+    /// assert_eq!(composed.with_var("y", &Term::from(2)), Term::from(9));
+    /// assert!(Term::compose_two(&Term::from(3), &g).is_err());
+    /// # Ok::<(), &'static str>(())
+    /// ```
+    pub fn compose_two(f: &Term<Num>, g: &Term<Num>) -> Result<Self, &'static str> {
+        match f.variable_names().as_slice() {
+            [name] => Ok(f.with_var(name, g)),
+            _ => Err("f must have exactly one free variable"),
+        }
+    }
+
+    /// Composes a function with several others, substituting each given variable of `f` with its term.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let f = Term::<u32>::var("x") * Term::var("y");
+    /// let composed = Term::compose(&f, &[("x", &Term::var("t")), ("y", &(Term::var("t") + Term::from(1)))]);
+    /// assert_eq!(composed.with_var("t", &Term::from(3)), Term::from(12));
+    /// ```
+    pub fn compose(f: &Term<Num>, substitutions: &[(&str, &Term<Num>)]) -> Self {
+        f.with_vars(substitutions)
+    }
+
+    /// Creates a new variable.
+    pub fn var(name: impl Into<String>) -> Self {
+        Term {
+            operation: Operation::Variable(Variable::from(name.into())),
+        }
+    }
+
+    /// Creates a division rounding towards negative infinity.
+    ///
+    /// The result is calculated immediately if both terms are rational constants.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// assert_eq!(Term::floor_div(Term::from(7), Term::from(2)), Term::from(3));
+    /// assert_eq!(Term::floor_div(Term::from(-7), Term::from(2)), Term::from(-4));
+    /// assert_eq!(Term::floor_div(-Term::from(7u32), Term::from(2)), -Term::from(4));
+    /// assert_eq!(Term::floor_div(Term::var("x"), Term::from(2)).with_var("x", &Term::from(9)), Term::from(4));
+    /// ```
+    pub fn floor_div(a: Term<Num>, b: Term<Num>) -> Self {
+        Term {
+            operation: FloorDivision::divide(a.operation, b.operation),
+        }
+    }
+
+    /// Raises the base to the power of the exponent, modulo the modulus.
+    ///
+    /// The result is calculated immediately using square-and-multiply if all three are numbers,
+    /// in which case it lies between zero and the modulus.
+    /// Otherwise it can only be calculated using [`calc_float`](Term::calc_float).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the modulus is zero, or if the exponent is a negative number.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// assert_eq!(Term::pow_mod_term(Term::from(4), Term::from(13), Term::from(497)), Term::from(445));
+    ///
+    /// let term = Term::pow_mod_term(Term::var("x"), Term::from(3), Term::from(5));
+    /// assert_eq!(term.to_string(), "powmod(x, 3, 5)");
+    /// assert_eq!(term.with_var("x", &Term::from(2)), Term::from(3));
+    /// ```
+    pub fn pow_mod_term(base: Term<Num>, exp: Term<Num>, modulus: Term<Num>) -> Self {
+        Term {
+            operation: PowMod::reduce(base.operation, exp.operation, modulus.operation),
+        }
+    }
+
+    /// Creates a fused multiply-add, calculating `a * b + c`.
+    ///
+    /// Unlike `a * b + c`, the product is not rounded before the addition when calculating a floating point result.
+    /// The result is calculated immediately if both factors are numbers.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// assert_eq!(Term::mul_add(Term::from(2), Term::from(3), Term::from(4)), Term::from(10));
+    ///
+    /// let fma = Term::mul_add(Term::var("x"), Term::from(3), Term::from(4));
+    /// assert_ne!(fma, Term::var("x") * Term::from(3) + Term::from(4));
+    /// assert_eq!(fma.with_var("x", &Term::from(2)), Term::from(10));
+    ///
+    /// let root = Term::from(2u32).pow_rational(1, 2);
+    /// let result = Term::mul_add(root.clone(), root, Term::from(1)).calc_float::<f64>();
+    /// assert!((result - 3.0).abs() < 1e-12);
+    /// ```
+    pub fn mul_add(a: Term<Num>, b: Term<Num>, c: Term<Num>) -> Self {
+        Term {
+            operation: MulAdd::fuse(a.operation, b.operation, c.operation),
+        }
+    }
+
+    /// Adds the terms, returning `None` if an overflow occurs while simplifying.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// assert_eq!(Term::from(3u8).checked_add(Term::from(4)), Some(Term::from(7)));
+    /// assert_eq!(Term::from(200u8).checked_add(Term::from(100)), None);
+    /// ```
+    pub fn checked_add(self, rhs: Term<Num>) -> Option<Self>
+    where
+        Num: CheckedAdd + CheckedSub + CheckedMul + 'static,
+    {
+        self.checked_operation(rhs, |a, b| a + b)
+    }
+
+    /// Subtracts the terms, returning `None` if an overflow occurs while simplifying.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// assert_eq!(Term::from(3u8).checked_sub(Term::from(4)), Some(-Term::from(1)));
+    /// assert_eq!(Term::from(-100i8).checked_sub(Term::from(100)), None);
+    /// ```
+    pub fn checked_sub(self, rhs: Term<Num>) -> Option<Self>
+    where
+        Num: CheckedAdd + CheckedSub + CheckedMul + 'static,
+    {
+        self.checked_operation(rhs, |a, b| a - b)
+    }
+
+    /// Multiplies the terms, returning `None` if an overflow occurs while simplifying.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// assert_eq!(Term::from(3u8).checked_mul(Term::from(4)), Some(Term::from(12)));
+    /// assert_eq!(Term::from(16u8).checked_mul(Term::from(16)), None);
+    /// ```
+    pub fn checked_mul(self, rhs: Term<Num>) -> Option<Self>
+    where
+        Num: CheckedAdd + CheckedSub + CheckedMul + 'static,
+    {
+        self.checked_operation(rhs, |a, b| a * b)
+    }
+
+    /// Raises the term to the power of the given exponent, returning `None` if an overflow occurs while simplifying.
+    ///
+    /// Uses exponentiation by squaring, so only about `2 * log2(exp)` multiplications are needed.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// assert_eq!(Term::from(2u8).checked_pow_u32(7), Some(Term::from(128)));
+    /// assert_eq!(Term::from(2u8).checked_pow_u32(8), None);
+    /// ```
+    pub fn checked_pow_u32(&self, exp: u32) -> Option<Self>
+    where
+        Num: CheckedAdd + CheckedSub + CheckedMul + 'static + From<u8>,
+    {
+        let mut base = self.clone().into_checked();
+        let mut result = None;
+        let mut exp = exp;
+        while exp > 0 {
+            if exp % 2 == 1 {
+                result = Some(match result {
+                    Some(result) => result * base.clone(),
+                    None => base.clone(),
+                });
+            }
+            exp /= 2;
+            // the base is only squared if it is still needed, so it cannot overflow needlessly
+            if exp > 0 {
+                base = base.clone() * base;
+            }
+        }
+        Term::from_checked(result.unwrap_or_else(|| Term::from(Checked::from(Num::from(1)))))
+    }
+
+    /// Divides the terms, returning `None` if an overflow occurs while simplifying.
+    pub(crate) fn checked_div(self, rhs: Term<Num>) -> Option<Self>
+    where
+        Num: CheckedAdd + CheckedSub + CheckedMul + 'static,
+    {
+        self.checked_operation(rhs, |a, b| a / b)
+    }
+
+    /// Applies the operation using numbers which detect overflows.
+    fn checked_operation(
+        self,
+        rhs: Term<Num>,
+        operation: impl FnOnce(Term<Checked<Num>>, Term<Checked<Num>>) -> Term<Checked<Num>>,
+    ) -> Option<Self>
+    where
+        Num: CheckedAdd + CheckedSub + CheckedMul + 'static,
+    {
+        Term::from_checked(operation(self.into_checked(), rhs.into_checked()))
+    }
+
+    /// Converts the term to use numbers which detect overflows, keeping the evaluators of bitwise operations.
+    pub(crate) fn into_checked(self) -> Term<Checked<Num>>
+    where
+        Num: CheckedAdd + CheckedSub + CheckedMul + 'static,
+    {
+        Term {
+            operation: convert_keeping_evaluators(self.operation, Checked::from, |evaluate| {
+                Arc::new(move |lhs: Checked<Num>, rhs: Checked<Num>| {
+                    let overflowed = lhs.overflowed || rhs.overflowed;
+                    evaluate(lhs.value, rhs.value).map(|value| Checked { value, overflowed })
+                })
+            }),
+        }
+    }
+
+    /// Converts a term built using numbers which detect overflows back, returning `None` if any overflow occurred.
+    ///
+    /// The evaluators of bitwise operations are kept.
+    pub(crate) fn from_checked(term: Term<Checked<Num>>) -> Option<Self>
+    where
+        Num: CheckedAdd + CheckedSub + CheckedMul + 'static,
+    {
+        let mut overflowed = false;
+        let operation = convert_keeping_evaluators(
+            term.operation,
+            |num: Checked<Num>| {
+                overflowed |= num.overflowed;
+                num.value
+            },
+            |evaluate| {
+                Arc::new(move |lhs, rhs| {
+                    evaluate(Checked::from(lhs), Checked::from(rhs)).map(|num| num.value)
+                })
+            },
+        );
+
+        if overflowed {
+            None
+        } else {
+            Some(Term { operation })
+        }
+    }
+
+    /// Creates a division. Simplifies if possible.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// assert_eq!(Term::div(2,6), Term::div(1,3));
+    /// assert_eq!(Term::div(4,2), Term::from(2));
+    /// assert_eq!(Term::div(1,2).calc::<f64>(), 0.5);
+    /// ```
+    pub fn div(divident: Num, divisor: Num) -> Self {
+        Self::from(divident) / Self::from(divisor)
+    }
+
+    /// Creates a division of two terms. Simplifies if possible.
+    ///
+    /// Like [`div`](Term::div), but accepts any terms as numerator and denominator.
+    /// Common factors are cancelled, fractions within the parts are combined
+    /// and the numeric coefficients of both parts are reduced by their greatest common divisor.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let x = || Term::<u32>::var("x");
+    /// assert_eq!(
+    ///     Term::from_ratio_parts(Term::from(4) * x(), Term::from(6) * x()),
+    ///     Term::div(2, 3)
+    /// );
+    /// assert_eq!(
+    ///     Term::from_ratio_parts(Term::div(1, 2), Term::div(1, 3)),
+    ///     Term::div(3, 2)
+    /// );
+    /// assert_eq!(
+    ///     Term::from_ratio_parts(Term::from(6) * x(), Term::from(4)),
+    ///     Term::from(3) * x() / Term::from(2)
+    /// );
+    /// ```
+    pub fn from_ratio_parts(numer: Term<Num>, denom: Term<Num>) -> Self
+    where
+        Num: From<u8>,
+    {
+        let (numer_coefficient, numer) = split_coefficient(numer.operation);
+        let (denom_coefficient, denom) = split_coefficient(denom.operation);
+
+        // dividing the numbers on their own reduces them by their greatest common divisor
+        let coefficient = Term::div(numer_coefficient, denom_coefficient);
+        let one = Operation::from(Num::from(1));
+        let fraction = if numer == denom {
+            one
+        } else if denom == one {
+            numer
+        } else {
+            numer / denom
+        };
+
+        match coefficient.operation {
+            Operation::Division(div) => {
+                Term {
+                    operation: multiply_skipping_one(*div.divident, fraction),
+                } / Term {
+                    operation: *div.divisor,
+                }
+            }
+            operation => Term {
+                operation: multiply_skipping_one(operation, fraction),
+            },
+        }
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > From<Num> for Term<Num>
+{
+    fn from(value: Num) -> Self {
+        Term {
+            operation: Operation::from(value),
+        }
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > From<(Num, Num)> for Term<Num>
+{
+    /// Creates the fraction `divident / divisor`. See [`Term::div`].
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// assert_eq!(Term::from((1u32, 3u32)), Term::div(1, 3));
+    /// assert_eq!(Term::from((4u32, 2u32)), Term::from(2));
+    /// assert_eq!(Term::from((-6i64, 4i64)), Term::div(-3, 2));
+    /// ```
+    fn from((divident, divisor): (Num, Num)) -> Self {
+        Term::div(divident, divisor)
+    }
+}
+
+/// Writes an operation in infix notation. Used in `impl Display for Term` and `Term::to_string_no_outer_parens`.
+///
+/// If `top_level` is false, the operation is put in parentheses unless it is a single number or variable.
+fn infix_notation<
+    Num: Add<Output = Num>
+        + Sub<Output = Num>
+        + Mul<Output = Num>
+        + Div<Output = Num>
+        + Rem<Output = Num>
+        + Clone
+        + Default
+        + PartialOrd
+        + Display,
+>(
+    operation: &Operation<Num>,
+    top_level: bool,
+) -> String {
+    // Writes a child, putting it in parentheses if it binds looser than the given precedence (or equally tight, if `inclusive`).
+    let child = |child: &Operation<Num>, precedence: u8, inclusive: bool| {
+        let parens =
+            child.precedence() < precedence || (inclusive && child.precedence() == precedence);
+        infix_notation(child, !parens)
+    };
+
+    let infix = match operation {
+        Operation::Addition(add) => {
+            let mut infix = child(&add.summands[0], 1, false);
+            for summand in &add.summands[1..] {
+                match summand {
+                    Operation::Negation(neg) => {
+                        infix += &format!(" - {}", child(&neg.value, 1, true));
+                    }
+                    summand => infix += &format!(" + {}", child(summand, 1, false)),
+                }
+            }
+            infix
+        }
+        Operation::Multiplication(mul) => mul
+            .multipliers
+            .iter()
+            .map(|multiplier| child(multiplier, 2, false))
+            .collect::<Vec<_>>()
+            .join(" * "),
+        Operation::Division(div) => format!(
+            "{} / {}",
+            child(&div.divident, 2, true),
+            child(&div.divisor, 2, true)
+        ),
+        Operation::Negation(neg) => format!("-{}", child(&neg.value, 4, true)),
+        Operation::Number(num) => num.value.to_string(),
+        Operation::Variable(var) => var.name.clone(),
+        Operation::RationalPower(pow) if pow.denom == 1 => {
+            format!("{}^{}", child(&pow.base, 3, true), pow.numer)
+        }
+        Operation::RationalPower(pow) => format!(
+            "{}^({}/{})",
+            child(&pow.base, 3, true),
+            pow.numer,
+            pow.denom
+        ),
+        Operation::FloorDivision(div) => format!(
+            "{} // {}",
+            child(&div.divident, 2, true),
+            child(&div.divisor, 2, true)
+        ),
+        Operation::MulAdd(fma) => format!(
+            "{} * {} + {}",
+            child(&fma.multiplicand, 2, false),
+            child(&fma.multiplier, 2, false),
+            child(&fma.summand, 1, false)
+        ),
+        Operation::BitAnd(bit)
+        | Operation::BitOr(bit)
+        | Operation::BitXor(bit)
+        | Operation::Shl(bit)
+        | Operation::Shr(bit) => format!(
+            "{} {} {}",
+            child(&bit.lhs, 0, true),
+            bit.operator.symbol(),
+            child(&bit.rhs, 0, true)
+        ),
+        Operation::PowMod(pow_mod) => format!(
+            "powmod({}, {}, {})",
+            infix_notation(&pow_mod.base, true),
+            infix_notation(&pow_mod.exp, true),
+            infix_notation(&pow_mod.modulus, true)
+        ),
+    };
+
+    if top_level || operation.precedence() == 5 {
+        infix
+    } else {
+        format!("({infix})")
+    }
+}
+
+/// Writes an operation as an SMT-LIB2 expression. Used in `Term::to_smtlib2`.
+fn smtlib2_expr<
+    Num: Add<Output = Num>
+        + Sub<Output = Num>
+        + Mul<Output = Num>
+        + Div<Output = Num>
+        + Rem<Output = Num>
+        + Clone
+        + Default
+        + PartialOrd
+        + Display,
+>(
+    operation: &Operation<Num>,
+    is_real: bool,
+) -> String {
+    let apply = |operator: &str, children: &[&Operation<Num>]| {
+        let children: Vec<String> = children
+            .iter()
+            .map(|child| smtlib2_expr(child, is_real))
+            .collect();
+        format!("({operator} {})", children.join(" "))
+    };
+
+    match operation {
+        Operation::Addition(add) => apply("+", &add.summands.iter().collect::<Vec<_>>()),
+        Operation::Multiplication(mul) => apply("*", &mul.multipliers.iter().collect::<Vec<_>>()),
+        Operation::Division(div) if is_real => apply("/", &[&div.divident, &div.divisor]),
+        Operation::Division(_) => panic!("Cannot write divisions in SMT-LIB2 for sorts other than Real."),
+        Operation::Negation(neg) => apply("-", &[&neg.value]),
+        Operation::Number(num) => {
+            let number = num.value.to_string();
+            // SMT-LIB2 has no negative literals
+            match number.strip_prefix('-') {
+                Some(absolute) => format!("(- {absolute})"),
+                None => number,
+            }
+        }
+        Operation::Variable(var) => smtlib2_symbol(&var.name),
+        Operation::MulAdd(fma) => format!(
+            "(+ {} {})",
+            apply("*", &[&fma.multiplicand, &fma.multiplier]),
+            smtlib2_expr(&fma.summand, is_real)
+        ),
+        _ => panic!("Cannot write powers, floor divisions, modular exponentiations or bitwise operations in SMT-LIB2."),
+    }
+}
+
+/// Writes a variable name as an SMT-LIB2 symbol. Used in `Term::to_smtlib2`.
+///
+/// Names which are not simple symbols, e.g. because they contain spaces, start with a digit or are reserved words,
+/// are quoted using `|`.
+fn smtlib2_symbol(name: &str) -> String {
+    const RESERVED: [&str; 13] = [
+        "_",
+        "!",
+        "as",
+        "let",
+        "exists",
+        "forall",
+        "match",
+        "par",
+        "BINARY",
+        "DECIMAL",
+        "HEXADECIMAL",
+        "NUMERAL",
+        "STRING",
+    ];
+
+    let is_simple = !name.is_empty()
+        && !name.starts_with(|c: char| c.is_ascii_digit())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "~!@$%^&*_-+=<>.?/".contains(c))
+        && !RESERVED.contains(&name);
+
+    if is_simple {
+        name.to_string()
+    } else if name.contains(['|', '\\']) {
+        panic!("Cannot write the variable name {name:?} as an SMT-LIB2 symbol.")
+    } else {
+        format!("|{name}|")
+    }
+}
+
+/// Writes an operation as a Rust expression, suffixing all numbers with `var_type`. Used in `Term::to_rust_expr`.
+///
+/// Every operation is put in parentheses, so no precedence rules have to be considered.
+fn rust_expr<
+    Num: Add<Output = Num>
+        + Sub<Output = Num>
+        + Mul<Output = Num>
+        + Div<Output = Num>
+        + Rem<Output = Num>
+        + Clone
+        + Default
+        + PartialOrd
+        + Display,
+>(
+    operation: &Operation<Num>,
+    var_type: &str,
+) -> String {
+    let join = |children: &[Operation<Num>], operator: &str| {
+        let children: Vec<String> = children
+            .iter()
+            .map(|child| rust_expr(child, var_type))
+            .collect();
+        format!("({})", children.join(operator))
+    };
+
+    match operation {
+        Operation::Addition(add) => join(&add.summands, " + "),
+        Operation::Multiplication(mul) => join(&mul.multipliers, " * "),
+        Operation::Division(div) => format!(
+            "({} / {})",
+            rust_expr(&div.divident, var_type),
+            rust_expr(&div.divisor, var_type)
+        ),
+        Operation::Negation(neg) => format!("(-{})", rust_expr(&neg.value, var_type)),
+        Operation::Number(num) => {
+            let number = format!("{}_{var_type}", num.value);
+            // negative literals would otherwise bind looser than method calls
+            if number.starts_with('-') {
+                format!("({number})")
+            } else {
+                number
+            }
+        }
+        Operation::Variable(var) => var.name.clone(),
+        Operation::RationalPower(pow) => format!(
+            "{}.powf({}_{var_type} / {}_{var_type})",
+            rust_expr(&pow.base, var_type),
+            pow.numer,
+            pow.denom
+        ),
+        Operation::FloorDivision(div) => format!(
+            "({} / {}).floor()",
+            rust_expr(&div.divident, var_type),
+            rust_expr(&div.divisor, var_type)
+        ),
+        Operation::MulAdd(fma) if var_type == "f32" || var_type == "f64" => format!(
+            "{}.mul_add({}, {})",
+            rust_expr(&fma.multiplicand, var_type),
+            rust_expr(&fma.multiplier, var_type),
+            rust_expr(&fma.summand, var_type)
+        ),
+        Operation::MulAdd(fma) => format!(
+            "({} * {} + {})",
+            rust_expr(&fma.multiplicand, var_type),
+            rust_expr(&fma.multiplier, var_type),
+            rust_expr(&fma.summand, var_type)
+        ),
+        Operation::BitAnd(bit)
+        | Operation::BitOr(bit)
+        | Operation::BitXor(bit)
+        | Operation::Shl(bit)
+        | Operation::Shr(bit) => format!(
+            "({} {} {})",
+            rust_expr(&bit.lhs, var_type),
+            bit.operator.symbol(),
+            rust_expr(&bit.rhs, var_type)
+        ),
+        Operation::PowMod(_) => {
+            panic!("Cannot write modular exponentiation as a Rust expression.")
+        }
+    }
+}
+
+/// Writes the node of an operation and all of its children in DOT language, returning the ID of the node. Used in `Term::to_dot_graph`.
+fn dot_graph_node<
+    Num: Add<Output = Num>
+        + Sub<Output = Num>
+        + Mul<Output = Num>
+        + Div<Output = Num>
+        + Rem<Output = Num>
+        + Clone
+        + Default
+        + PartialOrd
+        + Display,
+>(
+    operation: &Operation<Num>,
+    lines: &mut Vec<String>,
+    next_id: &mut usize,
+) -> usize {
+    let id = *next_id;
+    *next_id += 1;
+
+    let (label, children): (String, Vec<&Operation<Num>>) = match operation {
+        Operation::Addition(add) => ("+".into(), add.summands.iter().collect()),
+        Operation::Multiplication(mul) => ("*".into(), mul.multipliers.iter().collect()),
+        Operation::Division(div) => ("/".into(), vec![&div.divident, &div.divisor]),
+        Operation::Negation(neg) => ("-".into(), vec![&neg.value]),
+        Operation::Number(num) => (num.value.to_string(), Vec::new()),
+        Operation::Variable(var) => (var.name.clone(), Vec::new()),
+        Operation::RationalPower(pow) => {
+            (format!("^ {}/{}", pow.numer, pow.denom), vec![&pow.base])
+        }
+        Operation::FloorDivision(div) => ("//".into(), vec![&div.divident, &div.divisor]),
+        Operation::MulAdd(fma) => (
+            "fma".into(),
+            vec![&fma.multiplicand, &fma.multiplier, &fma.summand],
+        ),
+        Operation::BitAnd(bit)
+        | Operation::BitOr(bit)
+        | Operation::BitXor(bit)
+        | Operation::Shl(bit)
+        | Operation::Shr(bit) => (bit.operator.symbol().into(), vec![&bit.lhs, &bit.rhs]),
+        Operation::PowMod(pow_mod) => (
+            "powmod".into(),
+            vec![&pow_mod.base, &pow_mod.exp, &pow_mod.modulus],
+        ),
+    };
+
+    lines.push(format!(
+        "    n{id} [label=\"{}\"];",
+        label.replace('\\', "\\\\").replace('"', "\\\"")
+    ));
+    for child in children {
+        let child_id = dot_graph_node(child, lines, next_id);
+        lines.push(format!("    n{id} -> n{child_id};"));
+    }
+
+    id
+}
+
+/// Returns the value of a number or a negated number, or `None` if it is neither or the number type cannot hold the result.
+///
+/// Negated numbers can only be held by number types which have negative values.
+fn constant_number<
+    Num: Add<Output = Num>
+        + Sub<Output = Num>
+        + Mul<Output = Num>
+        + Div<Output = Num>
+        + Rem<Output = Num>
+        + Clone
+        + Default
+        + PartialOrd
+        + Bounded,
+>(
+    operation: &Operation<Num>,
+) -> Option<Num> {
+    match operation {
+        Operation::Number(num) => Some(num.value.clone()),
+        Operation::Negation(neg) => match &*neg.value {
+            Operation::Number(num) if Num::min_value() < Num::default() => {
+                Some(Num::default() - num.value.clone())
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Returns the sign of a constant operation compared to zero, or `None` if it cannot be determined.
+fn constant_sign<
+    Num: Add<Output = Num>
+        + Sub<Output = Num>
+        + Mul<Output = Num>
+        + Div<Output = Num>
+        + Rem<Output = Num>
+        + Clone
+        + Default
+        + PartialOrd,
+>(
+    operation: &Operation<Num>,
+) -> Option<Ordering> {
+    match operation {
+        Operation::Number(num) => num.value.partial_cmp(&Num::default()),
+        Operation::Negation(neg) => constant_sign(&neg.value).map(Ordering::reverse),
+        Operation::Division(div) => {
+            match (constant_sign(&div.divident)?, constant_sign(&div.divisor)?) {
+                (_, Ordering::Equal) => None,
+                (Ordering::Equal, _) => Some(Ordering::Equal),
+                (divident, divisor) if divident == divisor => Some(Ordering::Greater),
+                _ => Some(Ordering::Less),
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Picks the smallest and largest of the corners of an interval operation. Used in `Term::evaluate_symbolically_with_bounds`.
+///
+/// If the corners cannot be compared, all values are assumed to be non-negative,
+/// in which case the first corner is the smallest and the last corner is the largest.
+fn corner_bounds<
+    Num: Add<Output = Num>
+        + Sub<Output = Num>
+        + Mul<Output = Num>
+        + Div<Output = Num>
+        + Rem<Output = Num>
+        + Clone
+        + Default
+        + PartialOrd,
+>(
+    corners: [Operation<Num>; 4],
+) -> (Operation<Num>, Operation<Num>) {
+    let compare = |a: &Operation<Num>, b: &Operation<Num>| constant_sign(&(a.clone() - b.clone()));
+    if corners
+        .iter()
+        .all(|corner| compare(corner, &corners[0]).is_some())
+    {
+        let mut low = &corners[0];
+        let mut high = &corners[0];
+        for corner in &corners[1..] {
+            if compare(corner, low) == Some(Ordering::Less) {
+                low = corner;
+            }
+            if compare(corner, high) == Some(Ordering::Greater) {
+                high = corner;
+            }
+        }
+        (low.clone(), high.clone())
+    } else {
+        let [low, _, _, high] = corners;
+        (low, high)
+    }
+}
+
+/// Calculates the lower and upper bound of an operation. Used in `Term::evaluate_symbolically_with_bounds`.
+///
+/// Returns `None` if the operation is unbounded, because a divisor may be zero.
+fn operation_bounds<
+    Num: Add<Output = Num>
+        + Sub<Output = Num>
+        + Mul<Output = Num>
+        + Div<Output = Num>
+        + Rem<Output = Num>
+        + Clone
+        + Default
+        + PartialOrd,
+>(
+    operation: &Operation<Num>,
+    bounds: &HashMap<&str, (Num, Num)>,
+) -> Option<(Operation<Num>, Operation<Num>)> {
+    let multiply = |(a_low, a_high): (Operation<Num>, Operation<Num>),
+                    (b_low, b_high): (Operation<Num>, Operation<Num>)| {
+        corner_bounds([
+            a_low.clone() * b_low.clone(),
+            a_low * b_high.clone(),
+            a_high.clone() * b_low,
+            a_high * b_high,
+        ])
+    };
+    let all_bounds = |operations: &[Operation<Num>]| {
+        operations
+            .iter()
+            .map(|operation| operation_bounds(operation, bounds))
+            .collect::<Option<Vec<_>>>()
+    };
+
+    Some(match operation {
+        Operation::Number(_) => (operation.clone(), operation.clone()),
+        Operation::Variable(var) => match bounds.get(var.name.as_str()) {
+            Some((low, high)) => (Operation::from(low.clone()), Operation::from(high.clone())),
+            None => (operation.clone(), operation.clone()),
+        },
+        Operation::Addition(add) => all_bounds(&add.summands)?
+            .into_iter()
+            .reduce(|(acc_low, acc_high), (low, high)| (acc_low + low, acc_high + high))
+            .unwrap_or_default(),
+        Operation::Multiplication(mul) => all_bounds(&mul.multipliers)?
+            .into_iter()
+            .reduce(multiply)
+            .unwrap_or_default(),
+        Operation::Negation(neg) => {
+            let (low, high) = operation_bounds(&neg.value, bounds)?;
+            (-high, -low)
+        }
+        Operation::Division(div) => {
+            let (a_low, a_high) = operation_bounds(&div.divident, bounds)?;
+            let (b_low, b_high) = operation_bounds(&div.divisor, bounds)?;
+            let low_sign = constant_sign(&b_low);
+            let high_sign = constant_sign(&b_high);
+            if low_sign.is_some_and(|sign| sign != Ordering::Greater)
+                && high_sign.is_some_and(|sign| sign != Ordering::Less)
+            {
+                return None;
+            }
+            corner_bounds([
+                a_low.clone() / b_high.clone(),
+                a_low / b_low.clone(),
+                a_high.clone() / b_high,
+                a_high / b_low,
+            ])
+        }
+        Operation::MulAdd(fma) => {
+            let (low, high) = multiply(
+                operation_bounds(&fma.multiplicand, bounds)?,
+                operation_bounds(&fma.multiplier, bounds)?,
+            );
+            let (summand_low, summand_high) = operation_bounds(&fma.summand, bounds)?;
+            (low + summand_low, high + summand_high)
+        }
+        Operation::RationalPower(pow) => {
+            let (low, high) = operation_bounds(&pow.base, bounds)?;
+            (
+                RationalPower::with_base(low, pow.numer, pow.denom),
+                RationalPower::with_base(high, pow.numer, pow.denom),
+            )
+        }
+        Operation::FloorDivision(_)
+        | Operation::PowMod(_)
+        | Operation::BitAnd(_)
+        | Operation::BitOr(_)
+        | Operation::BitXor(_)
+        | Operation::Shl(_)
+        | Operation::Shr(_) => {
+            panic!("Cannot bound floor divisions, modular exponentiations and bitwise operations.")
+        }
+    })
+}
+
+/// Calculates the remainder of the polynomial long division of `divident` by `divisor`. Used in `Term::symbolic_gcd`.
+///
+/// Both polynomials are given by their coefficients, starting with the constant one, without trailing zeros.
+fn polynomial_remainder<
+    Num: Add<Output = Num>
+        + Sub<Output = Num>
+        + Mul<Output = Num>
+        + Div<Output = Num>
+        + Rem<Output = Num>
+        + Clone
+        + Default
+        + PartialOrd,
+>(
+    mut divident: Vec<Term<Num>>,
+    divisor: &[Term<Num>],
+) -> Vec<Term<Num>> {
+    let Some(leading) = divisor.last() else {
+        panic!("Cannot divide by zero.");
+    };
+
+    while divident.len() >= divisor.len() {
+        let factor = divident[divident.len() - 1].clone() / leading.clone();
+        let shift = divident.len() - divisor.len();
+        for (index, coefficient) in divisor.iter().enumerate() {
+            divident[shift + index] =
+                divident[shift + index].clone() - factor.clone() * coefficient.clone();
+        }
+
+        // the leading coefficient is eliminated
+        divident.pop();
+        while divident.last() == Some(&Term::default()) {
+            divident.pop();
+        }
+    }
+
+    divident
+}
+
+/// Splits an operation into the product of its numeric factors and the rest. Used in `Term::from_ratio_parts`.
+fn split_coefficient<
+    Num: Add<Output = Num>
+        + Sub<Output = Num>
+        + Mul<Output = Num>
+        + Div<Output = Num>
+        + Rem<Output = Num>
+        + Clone
+        + Default
+        + PartialOrd
+        + From<u8>,
+>(
+    operation: Operation<Num>,
+) -> (Num, Operation<Num>) {
+    match operation {
+        Operation::Number(num) => (num.value, Operation::from(Num::from(1))),
+        Operation::Multiplication(mul) => {
+            let mut coefficient = Num::from(1);
+            let mut rest = Operation::from(Num::from(1));
+            for multiplier in mul.multipliers {
+                match multiplier {
+                    Operation::Number(num) => coefficient = coefficient * num.value,
+                    multiplier => rest = multiply_skipping_one(rest, multiplier),
+                }
+            }
+            (coefficient, rest)
+        }
+        operation => (Num::from(1), operation),
+    }
+}
+
+/// Generates a pseudo-random number between 0.5 and 2.5 using splitmix64. Used in `Term::verify_equality`.
+fn random_test_value(state: &mut u64) -> f64 {
+    *state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^= z >> 31;
+    // the upper 53 bits are exactly representable
+    0.5 + 2.0 * (z >> 11) as f64 / (1u64 << 53) as f64
+}
+
+/// Adds the summands as a single fraction over their least common denominator. Used in `Term::collect_common_denominator`.
+fn over_common_denominator<
+    Num: Add<Output = Num>
+        + Sub<Output = Num>
+        + Mul<Output = Num>
+        + Div<Output = Num>
+        + Rem<Output = Num>
+        + Clone
+        + Default
+        + PartialOrd
+        + From<u8>,
+>(
+    summands: Vec<Operation<Num>>,
+) -> Term<Num> {
+    let one = || Operation::from(Num::from(1));
+
+    let fractions: Vec<(Operation<Num>, Operation<Num>)> = summands
+        .into_iter()
+        .map(|summand| match summand {
+            Operation::Division(div) => (*div.divident, *div.divisor),
+            Operation::Negation(neg) => match *neg.value {
+                Operation::Division(div) => (-*div.divident, *div.divisor),
+                value => (-value, one()),
+            },
+            summand => (summand, one()),
+        })
+        .collect();
+
+    // the least common multiple of the numeric denominators, and all distinct other denominators
+    let mut numeric = Num::from(1);
+    let mut symbolic: Vec<&Operation<Num>> = Vec::new();
+    for (_, denominator) in &fractions {
+        match denominator {
+            Operation::Number(num) => {
+                let gcd = greatest_common_divisor(numeric.clone(), num.value.clone());
+                numeric = numeric.clone() * (num.value.clone() / gcd);
+            }
+            denominator => {
+                if !symbolic.contains(&denominator) {
+                    symbolic.push(denominator);
+                }
+            }
+        }
+    }
+
+    let numerator = Term::sum(fractions.iter().map(|(numerator, denominator)| {
+        let mut factor = match denominator {
+            Operation::Number(num) => Operation::from(numeric.clone() / num.value.clone()),
+            _ => Operation::from(numeric.clone()),
+        };
+        for other in symbolic.iter().filter(|other| **other != denominator) {
+            factor = multiply_skipping_one(factor, (*other).clone());
+        }
+        Term {
+            operation: multiply_skipping_one(numerator.clone(), factor),
+        }
+    }));
+    let denominator = symbolic
+        .into_iter()
+        .fold(Operation::from(numeric), |acc, denominator| {
+            multiply_skipping_one(acc, denominator.clone())
+        });
+
+    Term::from_ratio_parts(
+        numerator,
+        Term {
+            operation: denominator,
+        },
+    )
+}
+
+/// Writes a product of fractions as a single fraction, cancelling equal factors and reducing the numbers.
+/// Used in `Term::reduce_multiplication_by_division`.
+fn cancel_common_factors<
+    Num: Add<Output = Num>
+        + Sub<Output = Num>
+        + Mul<Output = Num>
+        + Div<Output = Num>
+        + Rem<Output = Num>
+        + Clone
+        + Default
+        + PartialOrd
+        + From<u8>,
+>(
+    operation: Operation<Num>,
+) -> Term<Num> {
+    let mut numerators = Vec::new();
+    let mut denominators = Vec::new();
+    collect_factors(operation, &mut numerators, &mut denominators);
+
+    let mut remaining = Vec::new();
+    for denominator in denominators {
+        match numerators
+            .iter()
+            .position(|numerator| *numerator == denominator)
+        {
+            Some(index) if !matches!(denominator, Operation::Number(_)) => {
+                numerators.remove(index);
+            }
+            _ => remaining.push(denominator),
         }
     }
 
-    /// Replaces all matching variables with the given term.
-    pub fn set_var(&mut self, name: &str, term: &Term<Num>) -> &Self {
-        self.operation = self.operation.set_vars(&[(name, &term.operation)]);
-        self
-    }
-
-    /// Replaces all matching variables with the given terms, and calculates the result.
-    pub fn use_vars<
-        Output: Add<Output = Output>
-            + Sub<Output = Output>
-            + Mul<Output = Output>
-            + Div<Output = Output>
-            + Neg<Output = Output>
-            + From<Num>,
-    >(
-        &self,
-        variables: &[(&str, &Term<Num>)],
-    ) -> Output {
-        let vars_as_ops: Vec<(&str, &Operation<Num>)> = variables
-            .iter()
-            .map(|var| (var.0, &var.1.operation))
-            .collect();
+    // the numbers are multiplied here and reduced by their greatest common divisor in `from_ratio_parts`
+    let product = |factors: Vec<Operation<Num>>| Term {
+        operation: factors
+            .into_iter()
+            .fold(Operation::from(Num::from(1)), multiply_skipping_one),
+    };
+    Term::from_ratio_parts(product(numerators), product(remaining))
+}
 
-        self.operation.set_vars(&vars_as_ops).calc()
+/// Flattens nested multiplications and divisions into their numerator and denominator factors.
+fn collect_factors<
+    Num: Add<Output = Num>
+        + Sub<Output = Num>
+        + Mul<Output = Num>
+        + Div<Output = Num>
+        + Rem<Output = Num>
+        + Clone
+        + Default
+        + PartialOrd,
+>(
+    operation: Operation<Num>,
+    numerators: &mut Vec<Operation<Num>>,
+    denominators: &mut Vec<Operation<Num>>,
+) {
+    match operation {
+        Operation::Multiplication(mul) => {
+            for multiplier in mul.multipliers {
+                collect_factors(multiplier, numerators, denominators);
+            }
+        }
+        Operation::Division(div) => {
+            collect_factors(*div.divident, numerators, denominators);
+            collect_factors(*div.divisor, denominators, numerators);
+        }
+        operation => numerators.push(operation),
     }
+}
 
-    /// Replaces all matching variables with the given terms.
-    pub fn with_vars(&self, variables: &[(&str, &Term<Num>)]) -> Self {
-        let vars_as_ops: Vec<(&str, &Operation<Num>)> = variables
+/// Combines two operations of the same shape node by node, applying `op` to their leaves. Used in `Term::zip`.
+fn zip_operations<
+    Num: Add<Output = Num>
+        + Sub<Output = Num>
+        + Mul<Output = Num>
+        + Div<Output = Num>
+        + Rem<Output = Num>
+        + Clone
+        + Default
+        + PartialOrd,
+    F: Fn(Term<Num>, Term<Num>) -> Term<Num>,
+>(
+    a: &Operation<Num>,
+    b: &Operation<Num>,
+    op: &F,
+) -> Operation<Num> {
+    let zip_all = |first: &[Operation<Num>], second: &[Operation<Num>]| {
+        first
             .iter()
-            .map(|var| (var.0, &var.1.operation))
-            .collect();
+            .zip(second)
+            .map(|(first, second)| zip_operations(first, second, op))
+            .collect::<Vec<_>>()
+    };
 
-        Term {
-            operation: self.operation.set_vars(&vars_as_ops),
+    match (a, b) {
+        (Operation::Addition(first), Operation::Addition(second))
+            if first.summands.len() == second.summands.len() =>
+        {
+            zip_all(&first.summands, &second.summands)
+                .into_iter()
+                .reduce(|acc, summand| acc + summand)
+                .unwrap_or_default()
+        }
+        (Operation::Multiplication(first), Operation::Multiplication(second))
+            if first.multipliers.len() == second.multipliers.len() =>
+        {
+            zip_all(&first.multipliers, &second.multipliers)
+                .into_iter()
+                .reduce(|acc, multiplier| acc * multiplier)
+                .unwrap_or_default()
+        }
+        (Operation::Division(first), Operation::Division(second)) => {
+            zip_operations(&first.divident, &second.divident, op)
+                / zip_operations(&first.divisor, &second.divisor, op)
+        }
+        (Operation::Negation(first), Operation::Negation(second)) => {
+            -zip_operations(&first.value, &second.value, op)
+        }
+        (Operation::RationalPower(first), Operation::RationalPower(second))
+            if first.numer == second.numer && first.denom == second.denom =>
+        {
+            RationalPower::with_base(
+                zip_operations(&first.base, &second.base, op),
+                first.numer,
+                first.denom,
+            )
+        }
+        (Operation::FloorDivision(first), Operation::FloorDivision(second)) => {
+            FloorDivision::divide(
+                zip_operations(&first.divident, &second.divident, op),
+                zip_operations(&first.divisor, &second.divisor, op),
+            )
+        }
+        (Operation::MulAdd(first), Operation::MulAdd(second)) => MulAdd::fuse(
+            zip_operations(&first.multiplicand, &second.multiplicand, op),
+            zip_operations(&first.multiplier, &second.multiplier, op),
+            zip_operations(&first.summand, &second.summand, op),
+        ),
+        (Operation::PowMod(first), Operation::PowMod(second)) => PowMod::reduce(
+            zip_operations(&first.base, &second.base, op),
+            zip_operations(&first.exp, &second.exp, op),
+            zip_operations(&first.modulus, &second.modulus, op),
+        ),
+        (
+            Operation::BitAnd(first)
+            | Operation::BitOr(first)
+            | Operation::BitXor(first)
+            | Operation::Shl(first)
+            | Operation::Shr(first),
+            Operation::BitAnd(second)
+            | Operation::BitOr(second)
+            | Operation::BitXor(second)
+            | Operation::Shl(second)
+            | Operation::Shr(second),
+        ) if first.operator == second.operator => BitOperation::apply(
+            first.operator,
+            zip_operations(&first.lhs, &second.lhs, op),
+            zip_operations(&first.rhs, &second.rhs, op),
+            first.evaluate.clone(),
+        ),
+        (first, second) => {
+            op(
+                Term {
+                    operation: first.clone(),
+                },
+                Term {
+                    operation: second.clone(),
+                },
+            )
+            .operation
         }
     }
+}
 
-    /// Replaces all matching variables with the given terms.
-    pub fn set_vars(&mut self, variables: &[(&str, &Term<Num>)]) -> &Self {
-        let vars_as_ops: Vec<(&str, &Operation<Num>)> = variables
-            .iter()
-            .map(|var| (var.0, &var.1.operation))
-            .collect();
+/// The number of nodes of each kind within a term. Obtained using [`Term::count_operations`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct OperationCounts {
+    /// The number of numbers.
+    pub numbers: usize,
+    /// The number of variables, counting every occurrence.
+    pub variables: usize,
+    /// The number of all other operations, e.g. additions or divisions.
+    pub operations: usize,
+}
 
-        self.operation = self.operation.set_vars(&vars_as_ops);
-        self
-    }
+/// Error when extracting the value of a term which is not a single number.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NotAConstantError {
+    /// The names of all variables within the term.
+    pub had_variables: Vec<String>,
+}
 
-    /// Creates a new variable.
-    pub fn var(name: impl Into<String>) -> Self {
-        Term {
-            operation: Operation::Variable(Variable::from(name.into())),
-        }
+/// Error when calculating the result of a term which still contains variables.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UnboundVariableError {
+    /// The names of all variables within the term.
+    pub variables: Vec<String>,
+}
+
+/// Error when converting a term into a term using a number type which cannot represent all of its numbers.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NumberOutOfRangeError {
+    /// The first number which could not be represented.
+    pub value: i64,
+}
+
+/// The kind of the outermost operation of a term. Obtained using [`Term::into_parts`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum OperationVariant {
+    /// A sum of any number of summands.
+    Addition,
+    /// A product of any number of multipliers.
+    Multiplication,
+    /// A divident divided by a divisor.
+    Division,
+    /// A negated value.
+    Negation,
+    /// A constant number.
+    Number,
+    /// A named variable.
+    Variable,
+    /// A base raised to a rational exponent.
+    Power,
+    /// A divident divided by a divisor, rounded towards negative infinity.
+    FloorDivision,
+    /// A product of two factors plus a summand.
+    MulAdd,
+    /// A base raised to an integer exponent, modulo a modulus.
+    PowMod,
+    /// The bitwise and of two integers.
+    BitAnd,
+    /// The bitwise or of two integers.
+    BitOr,
+    /// The bitwise exclusive or of two integers.
+    BitXor,
+    /// An integer shifted to the left.
+    Shl,
+    /// An integer shifted to the right.
+    Shr,
+}
+
+/// The value of a term without children. Obtained using [`Term::leaf_value`].
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub enum LeafValue<Num> {
+    /// A constant number.
+    Number(Num),
+    /// The name of a variable.
+    Variable(String),
+}
+
+macro_rules! impl_try_from_term {
+    ($($num:ty),*) => {
+        $(
+            impl TryFrom<Term<$num>> for $num {
+                type Error = NotAConstantError;
+
+                /// Extracts the value of the term, if it is a single number.
+                fn try_from(value: Term<$num>) -> Result<Self, Self::Error> {
+                    value.try_into_constant()
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_term!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+impl From<Term<u32>> for Term<i64> {
+    /// Converts the term to use signed numbers.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let term: Term<i64> = Term::from(Term::<u32>::var("x") + Term::from(2u32));
+    /// assert_eq!(term.use_var::<i64>("x", &Term::from(-5)), -3);
+    /// ```
+    fn from(value: Term<u32>) -> Self {
+        value.convert()
     }
+}
 
-    /// Creates a division. Simplifies if possible.
+impl From<Term<u32>> for Term<f64> {
+    /// Converts the term to use floating point numbers.
     ///
     /// ```rust
     /// # use crem::Term;
-    /// assert_eq!(Term::div(2,6), Term::div(1,3));
-    /// assert_eq!(Term::div(4,2), Term::from(2));
-    /// assert_eq!(Term::div(1,2).calc::<f64>(), 0.5);
+    /// let term: Term<f64> = Term::from(Term::div(1u32, 4u32));
+    /// assert_eq!(term.calc::<f64>(), 0.25);
     /// ```
-    pub fn div(divident: Num, divisor: Num) -> Self {
-        Self::from(divident) / Self::from(divisor)
+    fn from(value: Term<u32>) -> Self {
+        value.convert()
     }
 }
 
-impl<
-        Num: Add<Output = Num>
-            + Sub<Output = Num>
-            + Mul<Output = Num>
-            + Div<Output = Num>
-            + Rem<Output = Num>
-            + Clone
-            + Default
-            + PartialOrd,
-    > From<Num> for Term<Num>
-{
-    fn from(value: Num) -> Self {
-        Term {
-            operation: Operation::from(value),
+impl TryFrom<Term<i64>> for Term<u32> {
+    type Error = NumberOutOfRangeError;
+
+    /// Converts the term to use unsigned numbers, failing if any number is negative or too large.
+    ///
+    /// ```rust
+    /// # use crem::*;
+    /// let term = Term::<i64>::var("x") * Term::from(3i64);
+    /// assert_eq!(Term::try_from(term), Ok(Term::var("x") * Term::from(3u32)));
+    /// assert_eq!(
+    ///     Term::<u32>::try_from(Term::from(-3i64)),
+    ///     Err(NumberOutOfRangeError { value: -3 })
+    /// );
+    /// ```
+    fn try_from(value: Term<i64>) -> Result<Self, Self::Error> {
+        let mut out_of_range = None;
+        value.operation.walk(&mut |operation| {
+            if let Operation::Number(num) = operation {
+                if out_of_range.is_none() && u32::try_from(num.value).is_err() {
+                    out_of_range = Some(num.value);
+                }
+            }
+        });
+
+        match out_of_range {
+            Some(value) => Err(NumberOutOfRangeError { value }),
+            None => Ok(Term {
+                operation: value.operation.convert(&mut |num| num as u32),
+            }),
         }
     }
 }
@@ -257,6 +4705,34 @@ impl TryFrom<&str> for Term<u32> {
     }
 }
 
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd
+            + Display,
+    > Display for Term<Num>
+{
+    /// Writes the term in infix notation.
+    ///
+    /// Every term except single numbers and variables is put in parentheses,
+    /// use [`to_string_no_outer_parens`](Term::to_string_no_outer_parens) to omit them.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let term = Term::<u32>::var("x") / (Term::var("y") + Term::from(3));
+    /// assert_eq!(format!("2 * {term}"), "2 * (x / (y + 3))");
+    /// assert_eq!(Term::<u32>::var("x").to_string(), "x");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", infix_notation(&self.operation, false))
+    }
+}
+
 impl<
         Num: Add<Output = Num>
             + Sub<Output = Num>
@@ -276,6 +4752,138 @@ impl<
     }
 }
 
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd
+            + BitAnd<Output = Num>,
+    > BitAnd for Term<Num>
+{
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        Term {
+            operation: BitOperation::apply(
+                BitOperator::And,
+                self.operation,
+                rhs.operation,
+                Some(bit_and_evaluator()),
+            ),
+        }
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd
+            + BitOr<Output = Num>,
+    > BitOr for Term<Num>
+{
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Term {
+            operation: BitOperation::apply(
+                BitOperator::Or,
+                self.operation,
+                rhs.operation,
+                Some(bit_or_evaluator()),
+            ),
+        }
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd
+            + BitXor<Output = Num>,
+    > BitXor for Term<Num>
+{
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        Term {
+            operation: BitOperation::apply(
+                BitOperator::Xor,
+                self.operation,
+                rhs.operation,
+                Some(bit_xor_evaluator()),
+            ),
+        }
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd
+            + CheckedShl
+            + ToPrimitive,
+    > Shl for Term<Num>
+{
+    type Output = Self;
+
+    fn shl(self, rhs: Self) -> Self::Output {
+        Term {
+            operation: BitOperation::apply(
+                BitOperator::Shl,
+                self.operation,
+                rhs.operation,
+                Some(shl_evaluator()),
+            ),
+        }
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd
+            + CheckedShr
+            + ToPrimitive,
+    > Shr for Term<Num>
+{
+    type Output = Self;
+
+    fn shr(self, rhs: Self) -> Self::Output {
+        Term {
+            operation: BitOperation::apply(
+                BitOperator::Shr,
+                self.operation,
+                rhs.operation,
+                Some(shr_evaluator()),
+            ),
+        }
+    }
+}
+
 impl<
         Num: Add<Output = Num>
             + Sub<Output = Num>
@@ -439,3 +5047,63 @@ impl<
         }
     }
 }
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > Neg for &Term<Num>
+{
+    type Output = Term<Num>;
+
+    /// Negates the term without consuming it.
+    ///
+    /// ```rust
+    /// # use crem::Term;
+    /// let terms = vec![Term::from(2), Term::var("x")];
+    /// let mut total = Term::<i32>::from(0);
+    /// for term in &terms {
+    ///     total += -term;
+    /// }
+    /// assert_eq!(total, -Term::from(2) - Term::var("x"));
+    /// ```
+    fn neg(self) -> Self::Output {
+        Term {
+            operation: -self.operation.clone(),
+        }
+    }
+}
+
+// Only references on both sides, as `Add<&Term> for Term` would break type inference of `term + value.into()`.
+macro_rules! impl_ref_operators {
+    ($($trait:ident $method:ident),*) => {
+        $(
+            impl<
+                    Num: Add<Output = Num>
+                        + Sub<Output = Num>
+                        + Mul<Output = Num>
+                        + Div<Output = Num>
+                        + Rem<Output = Num>
+                        + Clone
+                        + Default
+                        + PartialOrd,
+                > $trait<&Term<Num>> for &Term<Num>
+            {
+                type Output = Term<Num>;
+
+                fn $method(self, rhs: &Term<Num>) -> Self::Output {
+                    Term {
+                        operation: self.operation.clone().$method(rhs.operation.clone()),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_ref_operators!(Add add, Sub sub, Mul mul, Div div);
@@ -0,0 +1,189 @@
+use std::{
+    fmt::{self, Display},
+    ops::{Add, Div, Mul, Rem, Sub},
+};
+
+use crate::Term;
+
+/// The structural differences between two terms. Created using [`Term::diff_terms`].
+///
+/// Sub-terms are located by their path: the indices of the children leading to them,
+/// in the order given by [`Term::into_parts`]. The whole term has an empty path.
+///
+/// ```rust
+/// # use crem::*;
+/// let old = Term::from(2u32) * Term::var("x") + Term::var("y");
+/// let new = Term::from(3u32) * Term::var("x") + Term::var("y");
+/// let diff = Term::diff_terms(&old, &new);
+///
+/// assert!(diff.has_changes());
+/// assert_eq!(diff.to_string(), "changed at [0, 0]: 2 -> 3");
+/// ```
+#[derive(Debug, PartialEq, PartialOrd, Clone)]
+pub struct TermDiff<
+    Num: Add<Output = Num>
+        + Sub<Output = Num>
+        + Mul<Output = Num>
+        + Div<Output = Num>
+        + Rem<Output = Num>
+        + Clone
+        + Default
+        + PartialOrd,
+> {
+    changes: Vec<TermChange<Num>>,
+}
+
+/// A single difference between two terms, as part of a [`TermDiff`].
+#[derive(Debug, PartialEq, PartialOrd, Clone)]
+pub enum TermChange<
+    Num: Add<Output = Num>
+        + Sub<Output = Num>
+        + Mul<Output = Num>
+        + Div<Output = Num>
+        + Rem<Output = Num>
+        + Clone
+        + Default
+        + PartialOrd,
+> {
+    /// The sub-term at the path was replaced.
+    Changed {
+        /// The path to the sub-term.
+        path: Vec<usize>,
+        /// The sub-term within the old term.
+        old: Term<Num>,
+        /// The sub-term within the new term.
+        new: Term<Num>,
+    },
+    /// The sub-term at the path only exists within the new term.
+    Added {
+        /// The path to the sub-term.
+        path: Vec<usize>,
+        /// The added sub-term.
+        term: Term<Num>,
+    },
+    /// The sub-term at the path only exists within the old term.
+    Removed {
+        /// The path to the sub-term.
+        path: Vec<usize>,
+        /// The removed sub-term.
+        term: Term<Num>,
+    },
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd,
+    > TermDiff<Num>
+{
+    /// Compares the terms node by node.
+    pub(crate) fn new(old: &Term<Num>, new: &Term<Num>) -> Self {
+        let mut changes = Vec::new();
+        compare(old, new, &mut Vec::new(), &mut changes);
+        TermDiff { changes }
+    }
+
+    /// Checks whether the terms differ at all.
+    pub fn has_changes(&self) -> bool {
+        !self.changes.is_empty()
+    }
+
+    /// Returns all differences, in the order of their position within the terms.
+    pub fn changes(&self) -> &[TermChange<Num>] {
+        &self.changes
+    }
+}
+
+impl<
+        Num: Add<Output = Num>
+            + Sub<Output = Num>
+            + Mul<Output = Num>
+            + Div<Output = Num>
+            + Rem<Output = Num>
+            + Clone
+            + Default
+            + PartialOrd
+            + Display,
+    > Display for TermDiff<Num>
+{
+    /// Describes every difference on its own line, or writes `no changes`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.changes.is_empty() {
+            return write!(f, "no changes");
+        }
+
+        for (index, change) in self.changes.iter().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            match change {
+                TermChange::Changed { path, old, new } => {
+                    write!(f, "changed at {path:?}: {old} -> {new}")?
+                }
+                TermChange::Added { path, term } => write!(f, "added at {path:?}: {term}")?,
+                TermChange::Removed { path, term } => write!(f, "removed at {path:?}: {term}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Records the differences between the sub-terms at the path.
+///
+/// Terms of the same kind are compared child by child. If they still differ, e.g. in the exponent of a power,
+/// or if they are of different kinds, the whole sub-term is recorded as changed.
+fn compare<
+    Num: Add<Output = Num>
+        + Sub<Output = Num>
+        + Mul<Output = Num>
+        + Div<Output = Num>
+        + Rem<Output = Num>
+        + Clone
+        + Default
+        + PartialOrd,
+>(
+    old: &Term<Num>,
+    new: &Term<Num>,
+    path: &mut Vec<usize>,
+    changes: &mut Vec<TermChange<Num>>,
+) {
+    if old == new {
+        return;
+    }
+
+    let (old_variant, old_children) = old.clone().into_parts();
+    let (new_variant, new_children) = new.clone().into_parts();
+
+    let recorded = changes.len();
+    if old_variant == new_variant && !old_children.is_empty() {
+        for index in 0..old_children.len().max(new_children.len()) {
+            path.push(index);
+            match (old_children.get(index), new_children.get(index)) {
+                (Some(old), Some(new)) => compare(old, new, path, changes),
+                (None, Some(term)) => changes.push(TermChange::Added {
+                    path: path.clone(),
+                    term: term.clone(),
+                }),
+                (Some(term), None) => changes.push(TermChange::Removed {
+                    path: path.clone(),
+                    term: term.clone(),
+                }),
+                (None, None) => (),
+            }
+            path.pop();
+        }
+    }
+
+    if changes.len() == recorded {
+        changes.push(TermChange::Changed {
+            path: path.clone(),
+            old: old.clone(),
+            new: new.clone(),
+        });
+    }
+}
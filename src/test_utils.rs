@@ -0,0 +1,148 @@
+//! Utilities for comparing terms in tests.
+
+use std::{
+    fmt::Debug,
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+};
+
+use crate::Term;
+
+/// Checks whether both terms calculate to values at most `epsilon` apart.
+///
+/// ```rust
+/// # use crem::{test_utils::approx_eq, Term};
+/// let third = Term::div(1, 3);
+/// assert!(approx_eq(&third, &Term::div(333, 1000), 1e-3));
+/// assert!(!approx_eq(&third, &Term::div(333, 1000), 1e-6));
+/// ```
+pub fn approx_eq<
+    Num: Add<Output = Num>
+        + Sub<Output = Num>
+        + Mul<Output = Num>
+        + Div<Output = Num>
+        + Rem<Output = Num>
+        + Clone
+        + Default
+        + PartialOrd,
+>(
+    a: &Term<Num>,
+    b: &Term<Num>,
+    epsilon: f64,
+) -> bool
+where
+    f64: From<Num>,
+{
+    (a.calc::<f64>() - b.calc::<f64>()).abs() <= epsilon
+}
+
+/// Asserts that both terms calculate to values at most `epsilon` apart.
+///
+/// # Panics
+///
+/// Panics if the values are further apart, naming both terms and their values.
+///
+/// ```rust
+/// # use crem::{test_utils::assert_approx_eq, Term};
+/// assert_approx_eq(&Term::div(2, 3), &(Term::from(1) - Term::div(1, 3)), 1e-12);
+/// ```
+pub fn assert_approx_eq<
+    Num: Add<Output = Num>
+        + Sub<Output = Num>
+        + Mul<Output = Num>
+        + Div<Output = Num>
+        + Rem<Output = Num>
+        + Clone
+        + Default
+        + PartialOrd
+        + Debug,
+>(
+    a: &Term<Num>,
+    b: &Term<Num>,
+    epsilon: f64,
+) where
+    f64: From<Num>,
+{
+    let (a_value, b_value) = (a.calc::<f64>(), b.calc::<f64>());
+    assert!(
+        (a_value - b_value).abs() <= epsilon,
+        "terms are not approximately equal (epsilon {epsilon}):\n  left: {a:?} = {a_value}\n right: {b:?} = {b_value}"
+    );
+}
+
+/// Asserts that both terms calculate to the same value.
+///
+/// # Panics
+///
+/// Panics if the values differ, naming both terms and their values.
+///
+/// ```rust
+/// # use crem::{test_utils::assert_eq_calc, Term};
+/// assert_eq_calc::<u32, f64>(&Term::div(1, 2), &(Term::from(1) - Term::div(1, 2)));
+/// ```
+pub fn assert_eq_calc<
+    Num: Add<Output = Num>
+        + Sub<Output = Num>
+        + Mul<Output = Num>
+        + Div<Output = Num>
+        + Rem<Output = Num>
+        + Clone
+        + Default
+        + PartialOrd
+        + Debug,
+    Output: Add<Output = Output>
+        + Sub<Output = Output>
+        + Mul<Output = Output>
+        + Div<Output = Output>
+        + Neg<Output = Output>
+        + From<Num>
+        + PartialEq
+        + Debug,
+>(
+    a: &Term<Num>,
+    b: &Term<Num>,
+) {
+    let (a_value, b_value) = (a.calc::<Output>(), b.calc::<Output>());
+    assert!(
+        a_value == b_value,
+        "terms do not calculate to the same value:\n  left: {a:?} = {a_value:?}\n right: {b:?} = {b_value:?}"
+    );
+}
+
+/// Asserts that both terms calculate to different values.
+///
+/// # Panics
+///
+/// Panics if the values are equal, naming both terms and their value.
+///
+/// ```rust
+/// # use crem::{test_utils::assert_ne_calc, Term};
+/// assert_ne_calc::<u32, f64>(&Term::div(1, 2), &Term::div(1, 3));
+/// ```
+pub fn assert_ne_calc<
+    Num: Add<Output = Num>
+        + Sub<Output = Num>
+        + Mul<Output = Num>
+        + Div<Output = Num>
+        + Rem<Output = Num>
+        + Clone
+        + Default
+        + PartialOrd
+        + Debug,
+    Output: Add<Output = Output>
+        + Sub<Output = Output>
+        + Mul<Output = Output>
+        + Div<Output = Output>
+        + Neg<Output = Output>
+        + From<Num>
+        + PartialEq
+        + Debug,
+>(
+    a: &Term<Num>,
+    b: &Term<Num>,
+) {
+    let (a_value, b_value) = (a.calc::<Output>(), b.calc::<Output>());
+    assert!(
+        a_value != b_value,
+        "terms calculate to the same value:\n  left: {a:?} = {a_value:?}\n right: {b:?} = {b_value:?}"
+    );
+}
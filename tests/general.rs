@@ -38,6 +38,25 @@ mod tests {
         assert_eq!((Term::from(3) - Term::from(5)).calc::<f64>(), -2.0);
     }
 
+    #[test]
+    fn test_unsigned_subtraction() {
+        // equal numbers result in zero rather than a negated zero
+        assert_eq!(Term::from(3u32) - Term::from(3u32), Term::from(0u32));
+        assert_eq!(
+            (Term::from(3u32) - Term::from(3u32)).to_prefix_notation(),
+            "0"
+        );
+        assert_eq!(Term::from(0u32) - Term::from(0u32), Term::from(0u32));
+
+        // the smaller number is subtracted from the larger one, so nothing overflows
+        assert_eq!(Term::from(5u32) - Term::from(3u32), Term::from(2u32));
+        assert_eq!(Term::from(3u32) - Term::from(5u32), -Term::from(2u32));
+        assert_eq!(
+            Term::from(0u32) - Term::from(u32::MAX),
+            -Term::from(u32::MAX)
+        );
+    }
+
     #[test]
     fn test_division() {
         assert_eq!(Term::div(3, 6), Term::div(1, 2));
@@ -45,6 +64,44 @@ mod tests {
         assert_eq!(Term::div(3, 10).calc::<f64>(), 0.3);
     }
 
+    #[test]
+    fn test_subtract_divisions_with_different_divisors() {
+        let x = || Term::<u32>::var("x");
+        let y = || Term::<u32>::var("y");
+
+        let term = x() / Term::from(2) - y() / Term::from(3);
+        let values = [("x", &Term::from(10)), ("y", &Term::from(6))];
+        assert_eq!(term.with_vars(&values).calc::<f64>(), 3.0);
+
+        let term = x() / y() - y() / x();
+        let values = [("x", &Term::from(2)), ("y", &Term::from(1))];
+        assert_eq!(term.with_vars(&values).calc::<f64>(), 1.5);
+    }
+
+    #[test]
+    fn test_add_multiplications_with_common_factors() {
+        let x = || Term::<u32>::var("x");
+        let y = || Term::<u32>::var("y");
+        let z = || Term::<u32>::var("z");
+        let values = [
+            ("x", &Term::from(2)),
+            ("y", &Term::from(3)),
+            ("z", &Term::from(5)),
+        ];
+
+        // all factors are shared, which must not leave an empty multiplication behind
+        let term = x() * y() + y() * x();
+        assert_eq!(term.with_vars(&values).calc::<f64>(), 12.0);
+
+        // a shared factor after the other one on the left side
+        let term = z() * x() + x() * y();
+        assert_eq!(term.with_vars(&values).calc::<f64>(), 16.0);
+
+        // the same factor repeated on one side is only matched once
+        let term = x() * x() * z() + x() * y();
+        assert_eq!(term.with_vars(&values).calc::<f64>(), 26.0);
+    }
+
     #[test]
     fn test_multiplication() {
         assert_eq!(Term::from(2) * Term::from(3), Term::from(6));
@@ -166,9 +223,2718 @@ mod tests {
     }
 
     #[test]
-    fn test_convert() {
-        assert_eq!(Term::from(3i64), Term::from(3u32).convert());
-        assert_ne!(Term::from(2i64), Term::from(3u32).convert());
+    fn test_from_str_errors() {
+        assert_eq!(
+            Term::try_from("9999999999 + 1"),
+            Err(TryFromStrError::IntegerOverflow {
+                value: String::from("9999999999")
+            })
+        );
+        assert_eq!(
+            Term::try_from("0.12345678901"),
+            Err(TryFromStrError::IntegerOverflow {
+                value: String::from("0.12345678901")
+            })
+        );
+        assert_eq!(
+            Term::try_from("1 / (2 - 2)"),
+            Err(TryFromStrError::DivisionByZero)
+        );
+        assert_eq!(
+            Term::try_from("4294967296.5"),
+            Err(TryFromStrError::IntegerOverflow {
+                value: String::from("4294967296")
+            })
+        );
+        assert_eq!(
+            Term::try_from("4294967295 + 1"),
+            Err(TryFromStrError::IntegerOverflow {
+                value: String::from("4294967295 + 1")
+            })
+        );
+        assert_eq!(
+            Term::try_from("65536 * (2 + 65534)"),
+            Err(TryFromStrError::IntegerOverflow {
+                value: String::from("65536 * (2 + 65534)")
+            })
+        );
+        assert_eq!(
+            Term::try_from("1 / 65536 / 65536"),
+            Err(TryFromStrError::IntegerOverflow {
+                value: String::from("1 / 65536 / 65536")
+            })
+        );
+        assert_eq!(Term::try_from(".5").unwrap(), Term::div(1, 2));
+        assert_eq!(Term::try_from("3.").unwrap(), Term::from(3));
+    }
+
+    #[test]
+    fn test_rational_power() {
+        let root = Term::<u32>::var("x").pow_rational(1, 2);
+        assert_eq!(root.clone() * root.clone(), Term::var("x"));
+        assert_eq!(root.pow_rational(2, 3), Term::var("x").pow_rational(1, 3));
+        assert_eq!(Term::from(2).pow_rational(0, 5), Term::from(1));
+        assert_eq!(Term::from(4u32).pow_rational(3, 2).calc_float::<f64>(), 8.0);
+        assert_eq!(
+            Term::<u32>::var("x").pow_rational(2, 3).pow_rational(3, 2),
+            Term::var("x")
+        );
+        assert_eq!(
+            Term::<u32>::var("x")
+                .pow_rational(65536, 3)
+                .pow_rational(65537, 5)
+                .to_string(),
+            "((x^(65536/3))^(65537/5))"
+        );
+        let power = Term::<u32>::var("x").pow_rational(2, 3);
+        assert_eq!(power.evaluate_with_fallback::<f64>(&[("x", 8)], 0.0), 4.0);
+        assert_eq!(
+            power.with_var("x", &Term::from(8)).try_calc::<f64>(),
+            Ok(4.0)
+        );
+        assert_eq!(
+            Term::<u32>::var("x")
+                .pow_rational(3, 1)
+                .use_var::<f64>("x", &Term::from(2)),
+            8.0
+        );
+
+        // exponents are added without overflowing
+        let x = || Term::<u32>::var("x");
+        assert_eq!(
+            x().pow_rational(65537, 65536) * x().pow_rational(65535, 65536),
+            x() * x()
+        );
+        let product = x().pow_rational(1, 4294967291) * x().pow_rational(1, 4294967279);
+        assert_eq!(
+            product.to_prefix_notation(),
+            "(* (^ x 1 4294967291) (^ x 1 4294967279))"
+        );
+        assert_eq!(product.use_var::<f64>("x", &Term::from(1)), 1.0);
+
+        // large integral exponents are not multiplied out
+        let power = x().pow_rational(199, 2) * x().pow_rational(201, 2);
+        assert_eq!(power.to_prefix_notation(), "(^ x 200 1)");
+        assert_eq!(power.use_var::<f64>("x", &Term::from(1)), 1.0);
+        assert_eq!(power.with_var("x", &Term::from(1)).calc::<f64>(), 1.0);
+        assert_eq!(power.diff("x").to_prefix_notation(), "(* 200 (^ x 199 1))");
+    }
+
+    #[test]
+    fn test_prefix_notation() {
+        let term = (Term::var("x") - Term::from(3)) / Term::var("y");
+        assert_eq!(term.to_prefix_notation(), "(/ (+ x (- 3)) y)");
+        assert_eq!(
+            Term::from_prefix_notation(&term.to_prefix_notation()).unwrap(),
+            term
+        );
+        assert_eq!(
+            Term::from_prefix_notation("(^ x 1 3)").unwrap(),
+            Term::var("x").pow_rational(1, 3)
+        );
+        assert_eq!(Term::from_prefix_notation("(- 7 2 1)").unwrap(), 4.into());
+        assert_eq!(
+            Term::from_prefix_notation("(+ 1 2"),
+            Err(TryFromStrError::UnexpectedEof)
+        );
+        assert_eq!(
+            Term::from_prefix_notation("(% 1 2)"),
+            Err(TryFromStrError::UnexpectedCharacter('%'))
+        );
+
+        // large exponents are neither multiplied out nor allowed to overflow
+        assert_eq!(
+            Term::from_prefix_notation("(^ x 268435456 1)").map(|term| term.to_prefix_notation()),
+            Ok(String::from("(^ x 268435456 1)"))
+        );
+        assert_eq!(
+            Term::from_prefix_notation("(^ 2 268435456 1)"),
+            Err(TryFromStrError::IntegerOverflow {
+                value: String::from("(^ 2 268435456 1)")
+            })
+        );
+        assert_eq!(
+            Term::from_prefix_notation("(^ 2 31 1)"),
+            Ok(Term::from(2147483648))
+        );
+    }
+
+    #[test]
+    fn test_apply_rule() {
+        let pattern = Term::var("a") * Term::var("a");
+        let replacement = Term::var("a") + Term::var("a");
+
+        let mismatching = Term::<u32>::var("x") * Term::var("y");
+        assert_eq!(mismatching.apply_rule(&pattern, &replacement), mismatching);
+
+        let squares = -(Term::<u32>::var("x") * Term::var("x"));
+        assert_eq!(
+            squares.apply_rule(&pattern, &replacement),
+            -(Term::var("x") + Term::var("x"))
+        );
+    }
+
+    #[test]
+    fn test_solve_quadratic() {
+        let x = Term::<i32>::var("x");
+
+        let no_real_solutions = x.clone() * x.clone() + Term::from(1);
+        assert_eq!(no_real_solutions.solve_quadratic("x"), None);
+
+        let fractional_no_real_solutions =
+            x.clone() * x.clone() + x.clone() / Term::from(2) + Term::from(1);
+        assert_eq!(fractional_no_real_solutions.solve_quadratic("x"), None);
+
+        let fractional = x.clone() * x.clone() + x.clone() / Term::from(2) - Term::from(1);
+        assert!(fractional.solve_quadratic("x").is_some());
+
+        let linear = Term::from(2) * x.clone() + Term::from(1);
+        assert_eq!(linear.solve_quadratic("x"), None);
+
+        let irrational = x.clone() * x.clone() - Term::from(2);
+        let (first, second) = irrational.solve_quadratic("x").unwrap();
+        assert_eq!(first, Term::from(8).pow_rational(1, 2) / Term::from(2));
+        assert_eq!(first.calc_float::<f64>(), 2f64.sqrt());
+        assert_eq!(second.calc_float::<f64>(), -(2f64.sqrt()));
+
+        assert_eq!(Term::from(49).pow_rational(1, 2), Term::from(7));
+    }
+
+    #[test]
+    fn test_floor_div() {
+        assert_eq!(Term::floor_div(Term::div(7, 2), Term::div(1, 3)), 10.into());
+        assert_eq!(
+            Term::floor_div(-Term::div(7u32, 2), Term::from(2)),
+            -Term::from(2)
+        );
+        assert_eq!(Term::floor_div(Term::from(-8), Term::from(2)), (-4).into());
+        assert_eq!(Term::floor_div(Term::from(0), Term::from(-2)), 0.into());
+
+        let halved = Term::floor_div(Term::var("x"), Term::from(2u32));
+        assert_eq!(halved.to_prefix_notation(), "(// x 2)");
+        assert_eq!(Term::from_prefix_notation("(// x 2)").unwrap(), halved);
+        assert_eq!(
+            Term::floor_div(Term::from(8u32).pow_rational(1, 2), Term::from(1)).calc_float::<f64>(),
+            2.0
+        );
+    }
+
+    #[test]
+    fn test_mul_add() {
+        assert_eq!(
+            Term::mul_add(Term::from(2), Term::from(3), Term::var("x")),
+            Term::from(6) + Term::var("x")
+        );
+        assert_eq!(
+            Term::mul_add(Term::var("x"), Term::from(0), Term::from(5)),
+            5.into()
+        );
+
+        let fma = Term::mul_add(Term::var("x"), Term::var("y"), Term::from(1u32));
+        assert_eq!(fma.to_prefix_notation(), "(fma x y 1)");
+        assert_eq!(Term::from_prefix_notation("(fma x y 1)"), Ok(fma.clone()));
+        assert_ne!(Term::from_prefix_notation("(+ (* x y) 1)"), Ok(fma.clone()));
+        assert_eq!(
+            Term::from_prefix_notation("(fma x y)"),
+            Err(TryFromStrError::UnexpectedCharacter(')'))
+        );
+        assert_eq!(fma.to_rust_expr("f64"), "x.mul_add(y, 1_f64)");
+        assert_eq!(fma.to_rust_expr("i64"), "(x * y + 1_i64)");
+        assert_eq!(fma.polynomial_degree("x"), Some(1));
+        assert_eq!(
+            fma.with_vars(&[("x", &Term::from(3)), ("y", &Term::from(4))]),
+            13.into()
+        );
+    }
+
+    #[test]
+    fn test_structural_hash() {
+        let built = Term::from(3u32) / Term::var("x") + Term::from(1);
+        let substituted = (Term::var("y") / Term::var("x") + Term::from(1))
+            .with_var("y", &(Term::from(6) / Term::from(2)));
+        assert_eq!(built, substituted);
+        assert_eq!(built.structural_hash(), substituted.structural_hash());
+        assert_eq!(built.structural_hash(), built.clone().structural_hash());
+
+        assert_ne!(
+            Term::<u32>::var("x").structural_hash(),
+            (-Term::<u32>::var("x")).structural_hash()
+        );
+        assert_ne!(
+            Term::from(2u32).pow_rational(1, 2).structural_hash(),
+            Term::from(2u32).pow_rational(1, 3).structural_hash()
+        );
+    }
+
+    #[test]
+    fn test_alpha_equivalent() {
+        let a = Term::<u32>::var("x") / Term::var("y");
+        let b = Term::<u32>::var("y") / Term::var("x");
+        assert!(Term::alpha_equivalent(&a, &b));
+        assert!(Term::alpha_equivalent(&a, &a));
+
+        let c = Term::<u32>::var("x") / Term::from(2);
+        assert!(!Term::alpha_equivalent(&a, &c));
+        assert!(!Term::alpha_equivalent(&c, &a));
+
+        let d = (Term::<u32>::var("x") + Term::from(1)) / Term::var("x");
+        let e = (Term::<u32>::var("x") + Term::from(1)) / Term::var("y");
+        assert!(!Term::alpha_equivalent(&d, &e));
+        assert!(!Term::alpha_equivalent(&e, &d));
+    }
+
+    #[test]
+    fn test_precedence() {
+        let mut nodes = Vec::new();
+        let mut term = -((Term::<u32>::var("x") + Term::from(1)) * Term::var("y"));
+        term.walk_mut(|op| nodes.push(op.clone()));
+
+        let [x, one, sum, y, product, negation] = &nodes[..] else {
+            panic!("unexpected structure: {nodes:?}");
+        };
+        assert!(sum.precedence() < product.precedence());
+        assert!(product.precedence() < negation.precedence());
+        assert!(negation.precedence() < x.precedence());
+
+        assert!(product.needs_parens_around_child(sum));
+        assert!(!product.needs_parens_around_child(y));
+        assert!(!sum.needs_parens_around_child(one));
+        assert!(negation.needs_parens_around_child(product));
+        assert!(negation.needs_parens_around_child(negation));
+        assert!(!x.needs_parens_around_child(sum));
+
+        assert_eq!(negation.depth(), 4);
+        assert_eq!(product.depth(), 3);
+        assert_eq!(y.depth(), 1);
+    }
+
+    #[test]
+    fn test_simplification_depth_limit() {
+        let build = || Term::div(1u32, 2) + Term::div(1, 3) + Term::from(1) / Term::from(6);
+
+        let limited = {
+            let _guard = Term::<u32>::with_config(Term::<u32>::simplification_depth_limit(1));
+            build()
+        };
+        assert_ne!(limited, Term::from(1));
+        assert_eq!(limited.calc::<f64>(), build().calc::<f64>());
+
+        let unlimited = {
+            let _guard = Term::<u32>::with_config(TermConfig::default());
+            build()
+        };
+        assert_eq!(unlimited, Term::from(1));
+        assert_eq!(build(), Term::from(1));
+    }
+
+    #[test]
+    fn test_zip() {
+        let a = Term::<u32>::var("x") / Term::var("y");
+        let b = Term::<u32>::var("y") / Term::var("x");
+        assert_eq!(
+            Term::zip(&a, &b, |first, second| first * second),
+            Term::var("x") * Term::var("y") / (Term::var("y") * Term::var("x"))
+        );
+
+        let c = Term::from(4u32);
+        assert_eq!(
+            Term::zip(&a, &c, |first, second| first + second),
+            a.clone() + c.clone()
+        );
+        assert_eq!(
+            Term::zip(&Term::from(2u32), &c, |first, second| first * second),
+            8.into()
+        );
+    }
+
+    #[test]
+    fn test_from_decimal() {
+        assert_eq!(Term::try_from("0.5").unwrap(), Term::div(1, 2));
+        assert_eq!(Term::try_from("1.0").unwrap(), Term::from(1));
+        assert_eq!(Term::try_from("3.14").unwrap(), Term::div(157, 50));
+        assert_eq!(Term::try_from("0.0").unwrap(), Term::from(0));
+
+        assert_eq!(Term::from_decimal(0, 0, 0), Term::from(0));
+        assert_eq!(Term::from_decimal(2, 25, 2), Term::div(9, 4));
+        assert_eq!(Term::from_decimal(1, 5, 3), Term::div(201, 200));
+    }
+
+    #[test]
+    fn test_sum_and_product() {
+        let vars = || (0..100).map(|i| Term::<u32>::var(format!("x{i}")));
+
+        let (variant, children) = Term::sum(vars()).into_parts();
+        assert_eq!(variant, OperationVariant::Addition);
+        assert_eq!(children.len(), 100);
+
+        let (variant, children) = Term::product(vars()).into_parts();
+        assert_eq!(variant, OperationVariant::Multiplication);
+        assert_eq!(children.len(), 100);
+
+        assert_eq!(
+            Term::sum([Term::div(1u32, 2), Term::var("x"), Term::div(1, 2)]),
+            Term::var("x") + Term::from(1)
+        );
+        assert_eq!(
+            Term::product([Term::div(1u32, 2), Term::from(2)]),
+            Term::from(1)
+        );
+    }
+
+    #[test]
+    fn test_diff() {
+        let x = || Term::<i32>::var("x");
+
+        // quotient rule: (x / (x + 1))' = 1 / (x + 1)^2
+        let quotient = x() / (x() + Term::from(1));
+        assert_eq!(
+            quotient.diff("x").with_var("x", &Term::from(1)),
+            Term::div(1, 4)
+        );
+
+        // power rule: (x^(3/2))' = 3/2 * x^(1/2)
+        let power = x().pow_rational(3, 2).diff("x");
+        assert_eq!(power.with_var("x", &Term::from(4)).calc_float::<f64>(), 3.0);
+
+        let root = x().pow_rational(1, 2).diff("x");
+        assert_eq!(root.with_var("x", &Term::from(4)).calc_float::<f64>(), 0.25);
+
+        assert_eq!(Term::floor_div(x(), Term::from(2)).diff("x"), Term::from(0));
+        assert_eq!(Term::div(1i32, 2) - Term::div(1, 3), Term::div(1, 6));
+    }
+
+    #[test]
+    fn test_taylor_approx() {
+        let x = || Term::<i64>::var("x");
+
+        let cubic = x() * x() * x() + Term::from(2) * x();
+        let approx = cubic.taylor_approx("x", 1, 3);
+        for value in [-2, 0, 3] {
+            assert_eq!(
+                approx.use_var::<i64>("x", &Term::from(value)),
+                cubic.use_var::<i64>("x", &Term::from(value))
+            );
+        }
+
+        let linear = cubic.taylor_approx("x", 1, 1);
+        assert_eq!(linear.with_var("x", &Term::from(2)), Term::from(8));
+
+        // 25! does not fit into an i64, but is never needed
+        let exact = cubic.taylor_approx("x", 1, 25);
+        assert_eq!(exact.with_var("x", &Term::from(2)), Term::from(12));
+    }
+
+    #[test]
+    fn test_bit_operations() {
+        assert_eq!(Term::from(12u32) & Term::from(10), Term::from(8));
+        assert_eq!(Term::from(12u32) | Term::from(10), Term::from(14));
+        assert_eq!(Term::from(12u32) ^ Term::from(10), Term::from(6));
+        assert_eq!(Term::from(3u32) << Term::from(2), Term::from(12));
+        assert_eq!(Term::from(12u32) >> Term::from(2), Term::from(3));
+
+        let masked = Term::<u32>::var("x") & Term::from(0xff);
+        assert_eq!(masked.with_var("x", &Term::from(0x1234)), Term::from(0x34));
+        assert_eq!(masked.to_prefix_notation(), "(& x 255)");
+        assert_eq!(Term::from_prefix_notation("(& x 255)").unwrap(), masked);
+        assert_eq!(
+            Term::from_prefix_notation("(xor 12 (<< 1 3))").unwrap(),
+            Term::from(4)
+        );
+
+        assert_eq!(Term::try_from("1 + 2 << 1 + 1").unwrap(), Term::from(12));
+        assert_eq!(Term::try_from("(1 | 2) * 3 & 7").unwrap(), Term::from(1));
+        assert_eq!(Term::try_from("~0 >> 28").unwrap(), Term::from(15));
+
+        // shifting by too many bits is left unevaluated
+        let shifted = Term::from(1u32) << Term::var("n");
+        assert_eq!(
+            shifted.with_var("n", &Term::from(40)).to_prefix_notation(),
+            "(<< 1 40)"
+        );
+        assert_eq!(shifted.with_var("n", &Term::from(4)), Term::from(16));
+
+        // the evaluators survive overflow checks and serialization
+        let sum = masked.clone().checked_add(Term::from(1)).unwrap();
+        assert_eq!(sum.with_var("x", &Term::from(0x1234)), Term::from(0x35));
+        let bytes = masked.serialize_to_bytes();
+        assert_eq!(
+            Term::deserialize_from_bytes(&bytes)
+                .unwrap()
+                .with_var("x", &Term::from(0x1234)),
+            Term::from(0x34)
+        );
+
+        assert_eq!(masked.try_diff("x"), None);
+        assert_eq!(masked.try_diff("y"), Some(Term::from(0)));
+        assert_eq!(
+            Term::try_from("1 << 32"),
+            Err(TryFromStrError::IntegerOverflow {
+                value: String::from("1 << 32")
+            })
+        );
+    }
+
+    #[test]
+    fn test_newton_solve() {
+        let cube_root = Term::<u32>::var("x").pow_rational(1, 3) - Term::from(2);
+        let root = cube_root.newton_solve("x", 5.0, 1e-12, 50).unwrap();
+        assert!((root - 8.0).abs() < 1e-9);
+
+        let linear = Term::from(3u32) * Term::var("x") - Term::from(6);
+        assert_eq!(linear.newton_solve("x", 0.0, 1e-12, 1), Some(2.0));
+        assert_eq!(linear.newton_solve("x", 0.0, 1e-12, 0), None);
+
+        let constant = Term::<u32>::var("y") + Term::from(1);
+        assert_eq!(constant.newton_solve("x", 0.0, 1e-12, 10), None);
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_json_schema() {
+        let schema = (Term::<u32>::var("x") + Term::var("y") * Term::var("x")).to_json_schema();
+        assert_eq!(schema["required"], serde_json::json!(["x", "y"]));
+        assert_eq!(schema["properties"].as_object().unwrap().len(), 2);
+
+        let constant = Term::from(3u32).to_json_schema();
+        assert_eq!(constant["properties"], serde_json::json!({}));
+        assert_eq!(constant["required"], serde_json::json!([]));
+    }
+
+    #[test]
+    fn test_checked_sqrt() {
+        assert_eq!(Term::from(0u32).checked_sqrt(), Some(Term::from(0)));
+        assert_eq!(
+            Term::from(1u64 << 40).checked_sqrt(),
+            Some(Term::from(1 << 20))
+        );
+        assert_eq!(Term::div(8u32, 18).checked_sqrt(), Some(Term::div(2, 3)));
+        assert_eq!(Term::div(1u32, 2).checked_sqrt(), None);
+        assert_eq!((-Term::from(4u32)).checked_sqrt(), None);
+        assert_eq!(Term::from(-4i32).checked_sqrt(), None);
+        assert_eq!(Term::div(16u32, 9).pow_rational(1, 2), Term::div(4, 3));
+    }
+
+    #[test]
+    fn test_from_str_i64() {
+        let term = Term::from_str_i64("3 - 5").unwrap();
+        assert_eq!(term, Term::from(3i64) - Term::from(5));
+        assert_eq!(term.calc::<i64>(), -2);
+        assert_eq!(Term::from_str_i64("-7").unwrap(), -Term::from(7i64));
+        assert_eq!(
+            Term::from_str_i64("0 - 4294967295 - 1")
+                .unwrap()
+                .calc::<i64>(),
+            -4294967296
+        );
+        assert_eq!(
+            Term::from_str_i64("2 * (1 - 4)").unwrap(),
+            Term::from(2i64) * (Term::from(1) - Term::from(4))
+        );
+        assert_eq!(
+            Term::from_str_i64("4294967296"),
+            Err(TryFromStrError::IntegerOverflow {
+                value: String::from("4294967296")
+            })
+        );
+    }
+
+    #[test]
+    fn test_division_cancels_common_factors() {
+        let a = || Term::<i32>::var("a");
+        let b = || Term::<i32>::var("b");
+        let c = || Term::<i32>::var("c");
+        let d = || Term::<i32>::var("d");
+
+        let quotient = (a() * b() * c()) / (b() * d());
+        assert_eq!(quotient, (a() * c()) / d());
+        assert_eq!(
+            quotient.use_vars::<f64>(&[
+                ("a", &Term::from(6)),
+                ("b", &Term::from(5)),
+                ("c", &Term::from(4)),
+                ("d", &Term::from(3)),
+            ]),
+            8.0
+        );
+
+        let x = || Term::<i32>::var("x");
+        let y = || Term::<i32>::var("y");
+        assert_eq!(
+            (Term::from(2) * x() * y()) / (Term::from(3) * x()),
+            (Term::from(2) * y()) / Term::from(3)
+        );
+
+        // everything cancels out in the divisor
+        assert_eq!((a() * b() * c()) / (c() * a()), b());
+    }
+
+    #[test]
+    fn test_calc_batch() {
+        let x = || Term::<u32>::var("x");
+        let values = [0.0, 0.5, 1.0, 2.0, 4.0, 8.0, 16.0, 32.0];
+
+        let polynomial = Term::from(3) * x() * x() + Term::from(2) * x() + Term::from(1);
+        let expected = values.map(|x| 3.0 * x * x + 2.0 * x + 1.0);
+        assert_eq!(polynomial.calc_batch("x", &values), expected);
+
+        let quotient = (x() + Term::from(1)) / Term::from(4);
+        let expected = values.map(|x: f64| (x + 1.0) / 4.0);
+        assert_eq!(quotient.calc_batch("x", &values), expected);
+
+        let root = x().pow_rational(1, 2) - Term::floor_div(x(), Term::from(3));
+        let expected = values.map(|x: f64| x.sqrt() - (x / 3.0).floor());
+        assert_eq!(root.calc_batch("x", &values), expected);
+
+        let negative = Term::<i32>::from(2) - Term::var("x") * Term::var("x");
+        let expected = values.map(|x: f64| 2.0 - x * x);
+        assert_eq!(negative.calc_batch("x", &values), expected);
+
+        assert_eq!(Term::from(7u16).calc_batch("x", &[0.0f32; 4]), [7.0; 4]);
+    }
+
+    #[test]
+    fn test_canonicalize_negations() {
+        let a = || Term::<i32>::var("a");
+        let b = || Term::<i32>::var("b");
+        let c = || Term::<i32>::var("c");
+
+        assert_eq!(
+            (-(a() / b())).canonicalize_negations().to_prefix_notation(),
+            "(/ (- a) b)"
+        );
+        assert_eq!(
+            (-(a() * b() * c()))
+                .canonicalize_negations()
+                .to_prefix_notation(),
+            "(* (- a) b c)"
+        );
+
+        // the negation travels through nested divisions and multiplications
+        assert_eq!(
+            (-((a() * b()) / c()))
+                .canonicalize_negations()
+                .to_prefix_notation(),
+            "(/ (* (- a) b) c)"
+        );
+
+        // double negations cancel out
+        let double = -((-(a() / b())) / c());
+        assert_eq!(
+            double.canonicalize_negations().to_prefix_notation(),
+            "(/ a (* b c))"
+        );
+        assert_eq!(
+            double.canonicalize_negations().use_vars::<f64>(&[
+                ("a", &Term::from(6)),
+                ("b", &Term::from(2)),
+                ("c", &Term::from(3)),
+            ]),
+            double.use_vars::<f64>(&[
+                ("a", &Term::from(6)),
+                ("b", &Term::from(2)),
+                ("c", &Term::from(3)),
+            ])
+        );
+
+        // negations of atoms are left alone
+        assert_eq!((-a()).canonicalize_negations(), -a());
+    }
+
+    #[test]
+    fn test_term_conversions() {
+        let signed: Term<i64> = Term::from(Term::from(3u32) * Term::var("x") + Term::from(1u32));
+        assert_eq!(signed.use_var::<i64>("x", &Term::from(-2)), -5);
+
+        let float: Term<f64> = Term::from(Term::div(1u32, 3u32));
+        assert_eq!(float.calc::<f64>(), 1.0 / 3.0);
+
+        let x = || Term::<i64>::var("x");
+        assert_eq!(
+            Term::<u32>::try_from(x() * Term::from(2i64) + Term::from(7i64)),
+            Ok(Term::var("x") * Term::from(2u32) + Term::from(7u32))
+        );
+        assert_eq!(
+            Term::<u32>::try_from(x() + Term::from(-4i64)),
+            Err(NumberOutOfRangeError { value: -4 })
+        );
+        assert_eq!(
+            Term::<u32>::try_from(x() * Term::from(1i64 << 40)),
+            Err(NumberOutOfRangeError { value: 1 << 40 })
+        );
+    }
+
+    #[test]
+    fn test_evaluate_symbolically_with_bounds() {
+        use std::collections::HashMap;
+
+        let x = || Term::<i32>::var("x");
+        let y = || Term::<i32>::var("y");
+        let bounds = HashMap::from([("x", (1, 3)), ("y", (2, 4))]);
+
+        assert_eq!(
+            (x() + y()).evaluate_symbolically_with_bounds(&bounds),
+            Some((Term::from(3), Term::from(7)))
+        );
+        assert_eq!(
+            (x() * y()).evaluate_symbolically_with_bounds(&bounds),
+            Some((Term::from(2), Term::from(12)))
+        );
+        assert_eq!(
+            (-x() * y()).evaluate_symbolically_with_bounds(&bounds),
+            Some((-Term::from(12), -Term::from(2)))
+        );
+        assert_eq!(
+            (x() / y()).evaluate_symbolically_with_bounds(&bounds),
+            Some((Term::div(1, 4), Term::div(3, 2)))
+        );
+
+        // the four-corner algorithm handles intervals with mixed signs
+        let mixed = HashMap::from([("x", (-2, 3)), ("y", (-5, 4))]);
+        let (low, high) = (x() * y())
+            .evaluate_symbolically_with_bounds(&mixed)
+            .unwrap();
+        assert_eq!((low.calc::<f64>(), high.calc::<f64>()), (-15.0, 12.0));
+
+        // unbound variables are kept
+        let (low, high) = (x() + Term::var("z"))
+            .evaluate_symbolically_with_bounds(&bounds)
+            .unwrap();
+        assert_eq!(low, Term::from(1) + Term::var("z"));
+        assert_eq!(high, Term::from(3) + Term::var("z"));
+
+        // divisors which may be zero make the term unbounded
+        let reciprocal = Term::from(1) / x();
+        assert_eq!(
+            reciprocal.evaluate_symbolically_with_bounds(&HashMap::from([("x", (-1, 1))])),
+            None
+        );
+        assert_eq!(
+            reciprocal.evaluate_symbolically_with_bounds(&HashMap::from([("x", (0, 1))])),
+            None
+        );
+        assert_eq!(
+            (y() + reciprocal).evaluate_symbolically_with_bounds(&mixed),
+            None
+        );
+    }
+
+    #[test]
+    fn test_to_dot_graph() {
+        let term = -(Term::<i32>::var("x") / Term::var("y")).pow_rational(1, 2);
+        let dot = term.to_dot_graph();
+
+        assert!(dot.starts_with("digraph term {\n"));
+        assert!(dot.ends_with("\n}"));
+        assert!(dot.contains("n0 [label=\"-\"];"));
+        assert!(dot.contains("[label=\"^ 1/2\"];"));
+        assert!(dot.contains("[label=\"/\"];"));
+        assert!(dot.contains("[label=\"x\"];"));
+        assert!(dot.contains("[label=\"y\"];"));
+
+        let nodes = dot.matches("[label=").count();
+        let edges = dot.matches(" -> ").count();
+        assert_eq!(nodes, 5);
+        assert_eq!(edges, nodes - 1);
+
+        // quotes within variable names are escaped
+        let quoted = Term::<u32>::var("a\"b").to_dot_graph();
+        assert!(quoted.contains("n0 [label=\"a\\\"b\"];"));
+    }
+
+    #[test]
+    fn test_is_distributively_expanded() {
+        let x = || Term::<i32>::var("x");
+        let y = || Term::<i32>::var("y");
+        let z = || Term::<i32>::var("z");
+
+        assert!(x().is_distributively_expanded());
+        assert!(Term::<i32>::from(3).is_distributively_expanded());
+        assert!((Term::from(2) * x() * y() + z()).is_distributively_expanded());
+        assert!((x() / (y() + z())).is_distributively_expanded());
+
+        assert!(!(x() * (y() + z())).is_distributively_expanded());
+        // nested products are checked as well
+        assert!(!((x() * (y() + z())) / z() + x()).is_distributively_expanded());
+    }
+
+    #[test]
+    fn test_reciprocal() {
+        for term in [
+            Term::from(7u32),
+            Term::div(2u32, 5),
+            Term::div(1u32, 9),
+            Term::from(3u32) + Term::div(1, 4),
+        ] {
+            assert_eq!(term.reciprocal().reciprocal(), term);
+            assert_eq!(term.reciprocal().calc::<f64>(), 1.0 / term.calc::<f64>());
+        }
+
+        assert_eq!(Term::from(6u32).reciprocal(), Term::div(1, 6));
+        assert_eq!(
+            (Term::<u32>::var("x") / Term::var("y")).reciprocal(),
+            Term::var("y") / Term::var("x")
+        );
+    }
+
+    #[test]
+    fn test_substitute_and_simplify() {
+        let x = || Term::<u32>::var("x");
+        let y = || Term::<u32>::var("y");
+
+        let term = x() * (Term::from(2) + Term::from(3));
+        assert_eq!(
+            term.substitute_and_simplify(&[("x", Term::from(0))]),
+            Term::from(0)
+        );
+
+        let huge = x() * (y() * y() + Term::from(3) * y() + Term::div(1, 2));
+        assert_eq!(
+            huge.substitute_and_simplify(&[("x", Term::from(0))]),
+            Term::from(0)
+        );
+
+        // substituting variables by other terms keeps the rest
+        let term = x() * y() + x();
+        assert_eq!(term.substitute_and_simplify(&[("x", y())]), y() * y() + y());
+        assert_eq!(
+            term.substitute_and_simplify(&[("x", Term::from(2)), ("y", Term::from(5))]),
+            Term::from(12)
+        );
+    }
+
+    #[test]
+    fn test_diff_nth() {
+        let x = || Term::<u32>::var("x");
+        let cubic =
+            Term::from(2) * x() * x() * x() + Term::from(5) * x() * x() + x() + Term::from(7);
+
+        assert_eq!(cubic.diff_nth("x", 0), cubic);
+        assert_eq!(
+            cubic.diff_nth("x", 1).with_var("x", &Term::from(3)),
+            cubic.diff("x").with_var("x", &Term::from(3))
+        );
+        assert_eq!(
+            cubic.diff_nth("x", 2).with_var("x", &Term::from(1)),
+            Term::from(22)
+        );
+        assert_eq!(cubic.diff_nth("x", 3), Term::from(12));
+        assert_eq!(cubic.diff_nth("x", 4), Term::from(0));
+        assert_eq!(cubic.diff_nth("x", 10), Term::from(0));
+        assert_eq!(cubic.diff_nth("y", 1), Term::from(0));
+    }
+
+    #[test]
+    fn test_horner_eval() {
+        let coefficients: Vec<Term<u32>> = (1..=6).map(Term::from).collect();
+        let horner = Term::horner_eval(&coefficients, "x");
+
+        let naive = Term::sum(coefficients.iter().enumerate().map(|(power, coefficient)| {
+            coefficient.clone() * Term::var("x").pow_u32(power as u32)
+        }));
+        for x in 0..5u32 {
+            assert_eq!(
+                horner.with_var("x", &Term::from(x)),
+                naive.with_var("x", &Term::from(x))
+            );
+        }
+
+        // every coefficient adds one addition and one multiplication
+        assert_eq!(horner.to_prefix_notation().matches('*').count(), 5);
+        assert_eq!(
+            horner.to_prefix_notation(),
+            "(+ 1 (* x (+ 2 (* x (+ 3 (* x (+ 4 (* x (+ 5 (* x 6))))))))))"
+        );
+
+        assert_eq!(Term::<u32>::horner_eval(&[], "x"), Term::from(0));
+        assert_eq!(Term::horner_eval(&[Term::from(4u32)], "x"), Term::from(4));
+    }
+
+    #[cfg(feature = "test-utils")]
+    #[test]
+    fn test_approx_eq() {
+        use crem::test_utils::{approx_eq, assert_approx_eq};
+
+        let x = || Term::<i32>::var("x");
+        let expanded = (x() + Term::from(1)) * (x() + Term::from(1));
+        let squared = x() * x() + Term::from(2) * x() + Term::from(1);
+        for value in -3..=3 {
+            assert_approx_eq(
+                &expanded.with_var("x", &Term::from(value)),
+                &squared.with_var("x", &Term::from(value)),
+                0.0,
+            );
+        }
+
+        assert!(approx_eq(&Term::div(1, 3), &Term::div(333, 1000), 1e-3));
+        assert!(!approx_eq(&Term::div(1, 3), &Term::div(333, 1000), 1e-4));
+
+        let result = std::panic::catch_unwind(|| {
+            assert_approx_eq(&Term::from(1), &Term::from(2), 0.5);
+        });
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parse_collecting_errors() {
+        let (term, errors) = Term::parse_collecting_errors("1 + 2 * 3");
+        assert_eq!(term, Term::from(7));
+        assert!(errors.is_empty());
+
+        // illegal characters are skipped
+        let (term, errors) = Term::parse_collecting_errors("1 + a2 * 3 #");
+        assert_eq!(term, Term::from(7));
+        assert_eq!(
+            errors,
+            vec![
+                TryFromStrError::UnexpectedCharacter('a'),
+                TryFromStrError::UnexpectedCharacter('#'),
+            ]
+        );
+
+        // numbers which are too large are skipped
+        let (term, errors) = Term::parse_collecting_errors("5 + 99999999999 + 1");
+        assert_eq!(term, Term::from(6));
+        assert_eq!(
+            errors,
+            vec![TryFromStrError::IntegerOverflow {
+                value: String::from("99999999999")
+            }]
+        );
+
+        // errors within brackets are collected as well
+        let (term, errors) = Term::parse_collecting_errors("2 * (3 / 0 + x) + 1 /");
+        assert_eq!(term, Term::from(7));
+        assert_eq!(
+            errors,
+            vec![
+                TryFromStrError::DivisionByZero,
+                TryFromStrError::UnexpectedCharacter('x'),
+                // the addition within the brackets is missing its summand
+                TryFromStrError::UnexpectedEof,
+                TryFromStrError::UnexpectedEof,
+            ]
+        );
+
+        // the first collected error is the one returned when parsing strictly
+        for value in ["1 + a2 * 3 #", "2 * (3 / 0 + x) + 1 /", "1 << 40 + y"] {
+            let (_, errors) = Term::parse_collecting_errors(value);
+            assert_eq!(Term::try_from(value), Err(errors[0].clone()));
+        }
+    }
+
+    #[test]
+    fn test_infix_notation() {
+        let x = || Term::<u32>::var("x");
+        let y = || Term::<u32>::var("y");
+
+        assert_eq!(x().to_string(), "x");
+        assert_eq!(Term::from(3u32).to_string(), "3");
+        assert_eq!((x() + y()).to_string(), "(x + y)");
+        assert_eq!((x() + y()).to_string_no_outer_parens(), "x + y");
+
+        assert_eq!(
+            (Term::from(2) * x() - y()).to_string_no_outer_parens(),
+            "2 * x - y"
+        );
+        assert_eq!(
+            (x() - (y() + Term::from(1))).to_string_no_outer_parens(),
+            "x - (y + 1)"
+        );
+        assert_eq!(
+            (x() / (y() * Term::from(2))).to_string_no_outer_parens(),
+            "x / (y * 2)"
+        );
+        assert_eq!(
+            (x() + Term::from(1))
+                .pow_rational(1, 2)
+                .to_string_no_outer_parens(),
+            "(x + 1)^(1/2)"
+        );
+        assert_eq!((-(x() * y())).to_string_no_outer_parens(), "-(x * y)");
+        assert_eq!(
+            Term::mul_add(x() + y(), x(), y()).to_string_no_outer_parens(),
+            "(x + y) * x + y"
+        );
+
+        // the display implementation can be embedded into other formulars
+        let term = x() + Term::from(1);
+        assert_eq!(format!("2 * {term}"), "2 * (x + 1)");
+        assert_eq!(
+            Term::try_from(format!("2 * {}", Term::from(3u32) + Term::from(4)).as_str()),
+            Ok(Term::from(14))
+        );
+    }
+
+    #[test]
+    fn test_to_fraction_components() {
+        let x = || Term::<i32>::var("x");
+
+        for term in [
+            x() / Term::from(3),
+            x() / Term::from(-3),
+            -(x() / Term::from(3)),
+            -(x() / Term::from(-3)),
+            (-x()) / Term::from(-3),
+            Term::from(-2) / Term::from(-6),
+            Term::from(2) / Term::from(-6),
+        ] {
+            let (numerator, denominator) = term.to_fraction_components().unwrap();
+            assert!(denominator.calc::<f64>() > 0.0);
+            assert_eq!(
+                (numerator.clone() / denominator.clone()).use_var::<f64>("x", &Term::from(6)),
+                term.use_var::<f64>("x", &Term::from(6))
+            );
+        }
+
+        assert_eq!(
+            (-(Term::<u32>::var("y") / Term::from(5u32))).to_fraction_components(),
+            Some((-Term::var("y"), Term::from(5)))
+        );
+        assert_eq!((x() / (x() + Term::from(1))).to_fraction_components(), None);
+        assert_eq!((x() * Term::from(2)).to_fraction_components(), None);
+        assert_eq!(Term::from(3).to_fraction_components(), None);
+    }
+
+    #[test]
+    fn test_evaluate_multi() {
+        let x = || Term::<i32>::var("x");
+        let y = || Term::<i32>::var("y");
+        let term = x() * x() - Term::from(3) * y() / x();
+
+        assert_eq!(term.evaluate_multi::<2, f64>([("x", 2), ("y", 4)]), -2.0);
+        assert_eq!(
+            term.evaluate_multi::<2, f64>([("x", 3), ("y", 1)]),
+            term.use_vars::<f64>(&[("x", &Term::from(3)), ("y", &Term::from(1))])
+        );
+        assert_eq!(Term::from(7).evaluate_multi::<0, i32>([]), 7);
+        assert_eq!(
+            (x() + Term::from(1)).evaluate_multi::<1, i32>([("x", -5)]),
+            -4
+        );
+    }
+
+    #[test]
+    fn test_degree_of_precision() {
+        let x = || Term::<i32>::var("x");
+
+        assert_eq!(Term::from(42).degree_of_precision(), 15);
+        assert_eq!(x().degree_of_precision(), 15);
+
+        // simplification removes operations, keeping the precision high
+        assert_eq!((Term::div(1, 3) * Term::from(3)).degree_of_precision(), 15);
+
+        // longer chains of operations lose more precision
+        let short = x() * x() + Term::from(1);
+        let long = short.clone() * short.clone() * x() + x() / (x() + Term::from(2));
+        assert!(long.degree_of_precision() < short.degree_of_precision());
+
+        // large divisors lose more precision than small ones
+        assert!(
+            Term::div(1, 7_000_000).degree_of_precision() < Term::div(1, 7).degree_of_precision()
+        );
+        assert_eq!(Term::div(1, i32::MAX).degree_of_precision(), 5);
+    }
+
+    #[test]
+    fn test_partial_fraction_decompose() {
+        let x = || Term::<i32>::var("x");
+        let linear = |root: i32| x() - Term::from(root);
+
+        let check = |term: &Term<i32>, parts: &[Term<i32>]| {
+            for value in [3, 5, 10] {
+                let expected = term.use_var::<f64>("x", &Term::from(value));
+                let actual: f64 = parts
+                    .iter()
+                    .map(|part| part.use_var::<f64>("x", &Term::from(value)))
+                    .sum();
+                assert!((expected - actual).abs() < 1e-12);
+            }
+        };
+
+        let term = (Term::from(3) * x() + Term::from(5)) / (linear(-1) * linear(-2));
+        let parts = term.partial_fraction_decompose("x").unwrap();
+        assert_eq!(parts.len(), 2);
+        check(&term, &parts);
+
+        // three factors and a constant factor in the denominator
+        let term = (x() * x() + Term::from(1))
+            / (Term::from(2) * linear(1) * linear(-1) * (Term::from(2) * x() + Term::from(1)));
+        let parts = term.partial_fraction_decompose("x").unwrap();
+        assert_eq!(parts.len(), 3);
+        check(&term, &parts);
+
+        // negated fractions are decomposed as well
+        let term = -(Term::from(1) / (linear(0) * linear(4)));
+        let parts = term.partial_fraction_decompose("x").unwrap();
+        check(&term, &parts);
+
+        // repeated factors
+        assert_eq!(
+            (Term::from(1) / (linear(2) * linear(2))).partial_fraction_decompose("x"),
+            None
+        );
+        // improper fractions
+        assert_eq!(
+            ((x() * x()) / (linear(1) * linear(2))).partial_fraction_decompose("x"),
+            None
+        );
+        // non-linear factors
+        assert_eq!(
+            (Term::from(1) / (x() * x() + Term::from(1))).partial_fraction_decompose("x"),
+            None
+        );
+        assert_eq!(x().partial_fraction_decompose("x"), None);
+    }
+
+    #[test]
+    fn test_from_tuple() {
+        assert_eq!(Term::from((1u32, 3u32)), Term::div(1, 3));
+        assert_eq!(Term::from((9u32, 3u32)), Term::from(3));
+        assert_eq!(Term::from((2i32, -4i32)).calc::<f64>(), -0.5);
+
+        let terms: Vec<Term<u32>> = vec![(1, 2), (1, 3), (1, 6)]
+            .into_iter()
+            .map(Term::from)
+            .collect();
+        assert_eq!(Term::sum(terms), Term::from(1));
+    }
+
+    #[test]
+    fn test_parallel_eval() {
+        let terms: Vec<Term<i32>> = (0..100)
+            .map(|i| Term::from(i) * Term::var("x") - Term::div(1, 2))
+            .collect();
+
+        let results: Vec<f64> = Term::parallel_eval(&terms, "x", 2);
+        let expected: Vec<f64> = (0..100).map(|i| f64::from(i) * 2.0 - 0.5).collect();
+        assert_eq!(results, expected);
+
+        assert!(Term::<i32>::parallel_eval::<f64>(&[], "x", 1).is_empty());
+    }
+
+    #[test]
+    fn test_compose() {
+        let x = || Term::<i32>::var("x");
+        let t = || Term::<i32>::var("t");
+
+        let f = x() * x() + Term::from(1);
+        let g = Term::from(2) * t() - Term::from(3);
+        let composed = Term::compose_two(&f, &g).unwrap();
+        for value in -2..=2 {
+            assert_eq!(
+                composed.use_var::<f64>("t", &Term::from(value)),
+                f.use_var::<f64>("x", &Term::from(2 * value - 3))
+            );
+        }
+
+        assert_eq!(
+            Term::compose_two(&Term::from(1), &g),
+            Err("f must have exactly one free variable")
+        );
+        assert_eq!(
+            Term::compose_two(&(x() + t()), &g),
+            Err("f must have exactly one free variable")
+        );
+
+        let h = x() / Term::var("y");
+        let composed = Term::compose(&h, &[("x", &(t() + Term::from(1))), ("y", &t())]);
+        assert_eq!(composed.use_var::<f64>("t", &Term::from(4)), 1.25);
+        // variables without a substitution are kept
+        assert_eq!(Term::compose(&h, &[("x", &t())]), t() / Term::var("y"));
+    }
+
+    #[test]
+    fn test_parse_with_implicit_multiplication() {
+        let x = || Term::<u32>::var("x");
+        let y = || Term::<u32>::var("y");
+
+        assert_eq!(
+            Term::parse_with_implicit_multiplication("2x"),
+            Ok(Term::from(2) * x())
+        );
+        assert_eq!(
+            Term::parse_with_implicit_multiplication("12x*y + 3"),
+            Ok(Term::from(12) * x() * y() + Term::from(3))
+        );
+        assert_eq!(
+            Term::parse_with_implicit_multiplication("(1 + 2)x - x"),
+            Ok(Term::from(3) * x() - x())
+        );
+        assert_eq!(
+            Term::parse_with_implicit_multiplication("2x(y + 1)"),
+            Ok(Term::from(2) * x() * (y() + Term::from(1)))
+        );
+        assert_eq!(
+            Term::parse_with_implicit_multiplication("x_1 + 2 | 1"),
+            Ok((Term::var("x_1") + Term::from(2)) | Term::from(1))
+        );
+        assert_eq!(
+            Term::parse_with_implicit_multiplication("2x$"),
+            Err(TryFromStrError::UnexpectedCharacter('$'))
+        );
+
+        // without the flag, identifiers are still rejected
+        assert_eq!(
+            Term::try_from("2x"),
+            Err(TryFromStrError::UnexpectedCharacter('x'))
+        );
+    }
+
+    #[test]
+    fn test_root_n() {
+        assert_eq!(Term::from(8u32).root_n(3), Term::from(2u32));
+        assert_eq!(Term::from(27u32).root_n(3), Term::from(3u32));
+        assert_eq!(Term::from(16u32).root_n(4), Term::from(2u32));
+        assert_eq!(Term::from(7u32).root_n(1), Term::from(7u32));
+        assert_eq!(Term::div(8u32, 27).root_n(3), Term::div(2, 3));
+
+        // imperfect powers and variables are kept as rational powers
+        let root = Term::from(10u32).root_n(3);
+        assert_eq!(root, Term::from(10u32).pow_rational(1, 3));
+        assert!((root.calc_float::<f64>() - 10f64.cbrt()).abs() < 1e-9);
+        let root = Term::<u32>::var("x").root_n(2);
+        assert_eq!(root.with_var("x", &Term::from(25)).calc_float::<f64>(), 5.0);
+    }
+
+    #[test]
+    fn test_symbolic_gcd() {
+        let x = || Term::<i32>::var("x");
+        let coefficients =
+            |term: Term<i32>| term.to_polynomial_coefficients("x").unwrap_or_default();
+
+        assert_eq!(
+            Term::symbolic_gcd(&Term::from(12), &Term::from(18)),
+            Term::from(6)
+        );
+        assert_eq!(
+            Term::symbolic_gcd(&Term::from(7), &Term::from(0)),
+            Term::from(7)
+        );
+
+        // (x - 2)(x + 3) and (x - 2)(x - 5)
+        let a = x() * x() + x() - Term::from(6);
+        let b = x() * x() - Term::from(7) * x() + Term::from(10);
+        assert_eq!(
+            coefficients(Term::symbolic_gcd(&a, &b)),
+            vec![-Term::from(2), Term::from(1)]
+        );
+
+        // 2(x + 1)^2 and 4(x + 1)
+        let a = Term::from(2) * x() * x() + Term::from(4) * x() + Term::from(2);
+        let b = Term::from(4) * x() + Term::from(4);
+        assert_eq!(
+            coefficients(Term::symbolic_gcd(&a, &b)),
+            vec![Term::from(1), Term::from(1)]
+        );
+
+        // coprime polynomials
+        assert_eq!(
+            Term::symbolic_gcd(&(x() + Term::from(1)), &(x() - Term::from(1))),
+            Term::from(1)
+        );
+        assert_eq!(
+            coefficients(Term::symbolic_gcd(&a, &Term::default())),
+            vec![Term::from(1), Term::from(2), Term::from(1)]
+        );
+        assert_eq!(Term::symbolic_gcd(&x(), &Term::var("y")), Term::from(1));
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn test_derive_into_term() {
+        #[derive(crem::IntoTerm)]
+        struct Quadratic {
+            #[term(coefficient_of = "x")]
+            linear: i32,
+            constant: i32,
+            #[term(coefficient_of = "y")]
+            other: i32,
+        }
+
+        let term = Term::from(Quadratic {
+            linear: 2,
+            constant: -3,
+            other: 5,
+        });
+        assert_eq!(
+            term,
+            Term::sum([
+                Term::from(2) * Term::var("x"),
+                Term::from(-3),
+                Term::from(5) * Term::var("y")
+            ])
+        );
+        assert_eq!(
+            term.use_vars::<f64>(&[("x", &Term::from(1)), ("y", &Term::from(2))]),
+            9.0
+        );
+    }
+
+    #[test]
+    fn test_pow_u32() {
+        assert_eq!(
+            Term::from(3u64).pow_u32(40),
+            Term::from(12157665459056928801u64)
+        );
+        assert_eq!(Term::div(1u32, 2).pow_u32(31), Term::div(1, 2147483648));
+
+        // large exponents of variables are kept as a power
+        let power = Term::<u32>::var("x").pow_u32(u32::MAX);
+        assert_eq!(power.to_prefix_notation(), "(^ x 4294967295 1)");
+        assert_eq!(Term::<u32>::var("x").pow_rational(u32::MAX, 1), power);
+        assert_eq!(power.use_var::<f64>("x", &Term::from(1)), 1.0);
+    }
+
+    #[test]
+    fn test_checked_pow_u32() {
+        assert_eq!(
+            Term::from(3u32).checked_pow_u32(20),
+            Some(Term::from(3486784401u32))
+        );
+        assert_eq!(Term::from(3u32).checked_pow_u32(21), None);
+        assert_eq!(Term::from(0u8).checked_pow_u32(0), Some(Term::from(1)));
+        assert_eq!(
+            Term::from(-2i16).checked_pow_u32(15),
+            Some(Term::from(-32768))
+        );
+        assert_eq!(Term::from(-2i16).checked_pow_u32(16), None);
+
+        // fractions overflow in their numerator or denominator
+        assert_eq!(Term::div(1u8, 4).checked_pow_u32(3), Some(Term::div(1, 64)));
+        assert_eq!(Term::div(1u8, 4).checked_pow_u32(4), None);
+
+        // terms containing variables are not multiplied out
+        let term = Term::from(16u8) * Term::var("x");
+        assert_eq!(term.checked_pow_u32(2), Some(term.clone() * term.clone()));
+
+        let term = Term::<u32>::var("x") + Term::from(1);
+        assert_eq!(
+            term.checked_pow_u32(3),
+            Some(term.clone() * term.clone() * term.clone())
+        );
+        assert_eq!(term.checked_pow_u32(1), Some(term.clone()));
+        assert_eq!(
+            Term::from(3u64).checked_pow_u32(40),
+            Some(Term::from(3u64.pow(40)))
+        );
+    }
+
+    #[test]
+    fn test_assert_simplified() {
+        let x = || Term::<i32>::var("x");
+        let terms = [
+            Term::from(3),
+            x(),
+            Term::from(2) * x() + Term::from(1),
+            (x() + Term::from(1)) / (x() - Term::from(2)),
+            -x() * Term::var("y"),
+            x().pow_rational(1, 3),
+            Term::div(6, 4) + Term::div(1, 3),
+        ];
+        for term in terms {
+            assert!(term.is_simplified(), "{term:?}");
+            term.assert_simplified();
+        }
+
+        let guard = Term::<i32>::with_config(Term::<i32>::simplification_depth_limit(0));
+        let unsimplified = Term::from(2) * Term::from(3) + x();
+        drop(guard);
+        assert!(!unsimplified.is_simplified());
+    }
+
+    #[test]
+    #[should_panic(expected = "Term is not simplified")]
+    fn test_assert_simplified_panics() {
+        let guard = Term::<i32>::with_config(Term::<i32>::simplification_depth_limit(0));
+        let unsimplified = Term::from(2) + Term::from(3);
+        drop(guard);
+        unsimplified.assert_simplified();
+    }
+
+    #[test]
+    fn test_from_ratio_parts() {
+        let x = || Term::<i32>::var("x");
+        let y = || Term::<i32>::var("y");
+
+        assert_eq!(
+            Term::from_ratio_parts(Term::from(6), Term::from(4)),
+            Term::div(3, 2)
+        );
+        assert_eq!(
+            Term::from_ratio_parts(Term::from(3) * x() * y(), Term::from(9) * y()),
+            x() / Term::from(3)
+        );
+        assert_eq!(
+            Term::from_ratio_parts(x() / y(), x() / Term::from(2)),
+            Term::from(2) / y()
+        );
+        assert_eq!(
+            Term::from_ratio_parts(x() + Term::from(1), x() + Term::from(1)),
+            Term::from(1)
+        );
+        assert_eq!(Term::from_ratio_parts(x(), Term::from(1)), x());
+    }
+
+    #[test]
+    fn test_diff_terms() {
+        let x = || Term::<u32>::var("x");
+        let y = || Term::<u32>::var("y");
+
+        let term = Term::from(2) * x() + y();
+        let diff = Term::diff_terms(&term, &term.clone());
+        assert!(!diff.has_changes());
+        assert_eq!(diff.to_string(), "no changes");
+
+        let diff = Term::diff_terms(&(x() + y()), &(x() + y() + Term::var("z")));
+        assert_eq!(
+            diff.changes(),
+            [TermChange::Added {
+                path: vec![2],
+                term: Term::var("z")
+            }]
+        );
+        assert_eq!(diff.to_string(), "added at [2]: z");
+
+        let diff = Term::diff_terms(&(x() * y() * Term::var("z")), &(x() * y()));
+        assert_eq!(diff.to_string(), "removed at [2]: z");
+
+        // different kinds of operations replace the whole sub-term
+        let diff = Term::diff_terms(&(x() / y()), &(x() / (y() + Term::from(1))));
+        assert_eq!(diff.to_string(), "changed at [1]: y -> (y + 1)");
+
+        // powers only differing in their exponent
+        let diff = Term::diff_terms(&x().pow_rational(1, 2), &x().pow_rational(1, 3));
+        assert_eq!(
+            diff.changes(),
+            [TermChange::Changed {
+                path: vec![],
+                old: x().pow_rational(1, 2),
+                new: x().pow_rational(1, 3)
+            }]
+        );
+
+        let diff = Term::diff_terms(
+            &(Term::from(2) * x() + Term::from(3) * y()),
+            &(Term::from(5) * x() + Term::from(3) * Term::var("z")),
+        );
+        assert_eq!(
+            diff.to_string(),
+            "changed at [0, 0]: 2 -> 5\nchanged at [1, 1]: y -> z"
+        );
+    }
+
+    #[test]
+    fn test_optimize_for_float() {
+        let x = || Term::<f64>::var("x");
+        let y = || Term::<f64>::var("y");
+
+        let term = x() * y() * x() + y() / Term::from(3.0) + x() + x() / Term::from(3.0);
+        let optimized = term.optimize_for_float();
+        assert_eq!(
+            optimized.to_prefix_notation(),
+            "(/ (+ y x (* x y x 3) (* x 3)) 3)"
+        );
+        for (x, y) in [(1.0, 2.0), (-3.5, 0.25), (1e10, 1e-10)] {
+            let vars = [("x", &Term::from(x)), ("y", &Term::from(y))];
+            let expected = term.use_vars::<f64>(&vars);
+            assert!((optimized.use_vars::<f64>(&vars) - expected).abs() <= expected.abs() * 1e-12);
+        }
+
+        // nested additions are reordered as well
+        let term = Term::from(2.0) * (x() * x() + x() + Term::from(1e-3));
+        assert_eq!(
+            term.optimize_for_float().to_prefix_notation(),
+            "(* 2 (+ 0.001 x (* x x)))"
+        );
+
+        let term = Term::from(1.0) + x();
+        assert_eq!(term.optimize_for_float(), term);
+    }
+
+    #[test]
+    fn test_complexity_score() {
+        let mut nodes = Vec::new();
+        let mut term = -((Term::<u32>::var("x") + Term::from(1)) * Term::var("y"));
+        term.walk_mut(|op| nodes.push(op.clone()));
+
+        let scores: Vec<u64> = nodes.iter().map(|op| op.complexity_score()).collect();
+        // x, 1, x + 1, y, (x + 1) * y, -((x + 1) * y)
+        assert_eq!(scores, vec![2, 1, 5, 2, 9, 10]);
+
+        let [x, one, sum, _, product, negation] = &nodes[..] else {
+            panic!("unexpected structure: {nodes:?}");
+        };
+        assert!(one.is_simpler_than(x));
+        assert!(sum.is_simpler_than(product));
+        assert!(product.is_simpler_than(negation));
+        assert!(!x.is_simpler_than(one));
+        assert!(!x.is_simpler_than(x));
+
+        let mut unsimplified = Vec::new();
+        let guard = Term::<u32>::with_config(Term::<u32>::simplification_depth_limit(0));
+        let mut sum = Term::from(1u32) + Term::from(2);
+        drop(guard);
+        sum.walk_mut(|op| unsimplified.push(op.clone()));
+
+        let mut simplified = Vec::new();
+        Term::from(3u32).walk_mut(|op| simplified.push(op.clone()));
+        assert!(simplified[0].is_simpler_than(unsimplified.last().unwrap()));
+    }
+
+    #[test]
+    fn test_approximate_with_epsilon() {
+        assert_eq!(Term::div(1, 3).approximate_with_epsilon(1e-3), "0.333");
+        assert_eq!(Term::div(1, 3).approximate_with_epsilon(1e-6), "0.333333");
+        assert_eq!((-Term::div(5, 7)).approximate_with_epsilon(1e-2), "-0.71");
+        assert_eq!(Term::div(2, 3).approximate_with_epsilon(1.0), "1");
+        assert_eq!(Term::div(7, 2).approximate_with_epsilon(1.0), "4e0");
+        assert_eq!(Term::from(42).approximate_with_epsilon(0.5), "4.2e1");
+        assert_eq!(Term::from(2000).approximate_with_epsilon(0.01), "2.00e3");
+        assert_eq!(
+            (Term::from(2).pow_rational(1, 2)).approximate_with_epsilon(1e-4),
+            "1.4142"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Epsilon has to be positive.")]
+    fn test_approximate_with_epsilon_panics() {
+        Term::div(1, 3).approximate_with_epsilon(0.0);
+    }
+
+    #[test]
+    fn test_continued_fraction() {
+        assert_eq!(
+            Term::div(415, 93).to_continued_fraction(10),
+            vec![4, 2, 6, 7]
+        );
+        assert_eq!(Term::from(5).to_continued_fraction(10), vec![5]);
+        assert_eq!(Term::div(1, 3).to_continued_fraction(10), vec![0, 3]);
+        assert_eq!(Term::div(-7, 3).to_continued_fraction(10), vec![-2, -3]);
+        assert_eq!((-Term::div(7, 3)).to_continued_fraction(10), vec![-2, -3]);
+        assert_eq!(
+            (-Term::<u32>::div(1, 3)).to_continued_fraction(5),
+            Vec::<u32>::new()
+        );
+        assert_eq!(
+            Term::div(415, 93).to_continued_fraction(0),
+            Vec::<i32>::new()
+        );
+        assert_eq!(
+            Term::<i32>::var("x").to_continued_fraction(10),
+            Vec::<i32>::new()
+        );
+
+        // the last convergent is the value itself
+        let convergents = Term::div(415i64, 93).to_continued_fraction_convergents(10);
+        assert_eq!(
+            convergents,
+            vec![
+                Term::from(4),
+                Term::div(9, 2),
+                Term::div(58, 13),
+                Term::div(415, 93)
+            ]
+        );
+
+        let convergents = Term::div(-7, 3).to_continued_fraction_convergents(10);
+        assert_eq!(convergents.len(), 2);
+        assert_eq!(convergents[0].calc::<f64>(), -2.0);
+        assert_eq!(convergents[1], Term::div(-7, 3));
+
+        // approximations of pi
+        let pi = Term::from(std::f64::consts::PI);
+        let convergents = pi.to_continued_fraction_convergents(4);
+        let expected = [3.0, 22.0 / 7.0, 333.0 / 106.0, 355.0 / 113.0];
+        assert_eq!(convergents.len(), expected.len());
+        for (convergent, expected) in convergents.iter().zip(expected) {
+            assert!((convergent.calc::<f64>() - expected).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_pow_mod_term() {
+        let pow_mod = |base: i64, exp: i64, modulus: i64| {
+            Term::pow_mod_term(Term::from(base), Term::from(exp), Term::from(modulus))
+        };
+        assert_eq!(pow_mod(4, 13, 497), Term::from(445));
+        assert_eq!(pow_mod(2, 0, 7), Term::from(1));
+        assert_eq!(pow_mod(5, 3, 1), Term::from(0));
+        assert_eq!(pow_mod(-2, 3, 5), Term::from(2));
+        assert_eq!(
+            pow_mod(123456789, 987654321, 1000000007),
+            Term::from(652541198)
+        );
+        assert_eq!(pow_mod(2, 1, -5), Term::from(-3));
+
+        // multiplying the remainders directly would overflow
+        let pow_mod_u32 = |base: u32, exp: u32, modulus: u32| {
+            Term::pow_mod_term(Term::from(base), Term::from(exp), Term::from(modulus))
+        };
+        assert_eq!(pow_mod_u32(70000, 2, 100000), Term::from(0));
+        assert_eq!(pow_mod_u32(99999, 2, 100000), Term::from(1));
+        assert_eq!(
+            pow_mod_u32(u32::MAX - 1, 3, u32::MAX),
+            Term::from(u32::MAX - 1)
+        );
+
+        // variables are kept until they are replaced
+        let term = Term::pow_mod_term(Term::var("x"), Term::var("e"), Term::from(13u32));
+        assert_eq!(term.to_prefix_notation(), "(powmod x e 13)");
+        assert_eq!(term.to_string(), "powmod(x, e, 13)");
+        assert_eq!(
+            term.with_vars(&[("x", &Term::from(3)), ("e", &Term::from(5))]),
+            Term::from(9)
+        );
+        assert_eq!(
+            term.with_var("x", &Term::from(3))
+                .use_var::<f64>("e", &Term::from(5)),
+            9.0
+        );
+        assert_eq!(
+            Term::from_prefix_notation("(powmod x e 13)"),
+            Ok(term.clone())
+        );
+        assert_eq!(
+            Term::from_prefix_notation("(powmod x e 0)"),
+            Err(TryFromStrError::DivisionByZero)
+        );
+        assert_eq!(
+            Term::from_prefix_notation("(powmod x e)"),
+            Err(TryFromStrError::UnexpectedCharacter(')'))
+        );
+        assert_eq!(term.clone().into_parts().0, OperationVariant::PowMod);
+        assert_eq!(term.diff("x"), Term::from(0));
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot divide by zero.")]
+    fn test_pow_mod_term_zero_modulus() {
+        Term::pow_mod_term(Term::var("x"), Term::from(2u32), Term::from(0));
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot raise to a non-integral power modulo a number.")]
+    fn test_pow_mod_term_non_integral_exponent() {
+        Term::pow_mod_term(Term::from(2.0), Term::from(0.5), Term::from(5.0));
+    }
+
+    #[test]
+    fn test_evaluate_with_fallback() {
+        let term = Term::<i32>::var("rate") * Term::var("hours") - Term::var("discount");
+
+        assert_eq!(
+            term.evaluate_with_fallback::<i32>(&[("rate", 30), ("hours", 4), ("discount", 20)], -1),
+            100
+        );
+        assert_eq!(
+            term.evaluate_with_fallback::<i32>(&[("rate", 30), ("hours", 4)], -1),
+            -1
+        );
+        assert_eq!(term.evaluate_with_fallback::<f64>(&[], 1.5), 1.5);
+
+        // unknown names are ignored
+        assert_eq!(
+            term.evaluate_with_fallback::<f64>(
+                &[("rate", 1), ("hours", 1), ("discount", 1), ("tax", 5)],
+                -1.0
+            ),
+            0.0
+        );
+
+        // variables multiplied by zero are gone, so they don't need a value
+        let term = Term::<i32>::var("x") * Term::var("y");
+        assert_eq!(term.evaluate_with_fallback::<i32>(&[("x", 0)], -1), 0);
+        assert_eq!(Term::from(7).evaluate_with_fallback::<i32>(&[], -1), 7);
+    }
+
+    #[test]
+    fn test_constant_propagation() {
+        let x = || Term::<u32>::var("x");
+        let y = || Term::<u32>::var("y");
+
+        // terms built by the operators are left unchanged
+        let term = Term::from(2) * x() + y() / Term::from(3);
+        assert_eq!(term.constant_propagation(), term);
+        assert_eq!(term.simplify(), term);
+
+        // constants which only become foldable after modifying the tree
+        let mut term = (x() + Term::from(2)) * (y() + Term::from(3));
+        term.walk_mut(|op| {
+            if let Operation::Number(num) = op {
+                num.value = 0;
+            }
+        });
+        assert_eq!(term.constant_propagation(), x() * y());
+
+        // identities and common factors
+        let mut term = Term::from(2) * x() / (Term::from(2) * y());
+        term.walk_mut(|op| {
+            if let Operation::Number(num) = op {
+                num.value = 3;
+            }
+        });
+        assert_eq!(term.constant_propagation(), x() / y());
+
+        let mut term = x() * y() / Term::from(2);
+        term.walk_mut(|op| {
+            if let Operation::Number(num) = op {
+                num.value = 1;
+            }
+        });
+        assert_eq!(term.simplify(), x() * y());
+    }
+
+    #[test]
+    fn test_binary_serialization() {
+        let x = || Term::<u32>::var("x");
+
+        let term = Term::from(2) * x() + Term::from(1);
+        assert_eq!(
+            term.serialize_to_bytes(),
+            vec![0x02, 0x02, 0x03, 0x02, 0x01, 2, 0, 0, 0, 0x06, 0x01, b'x', 0x01, 1, 0, 0, 0]
+        );
+
+        let terms = [
+            Term::from(u32::MAX),
+            Term::var("größe"),
+            -x() / (x() + Term::from(3)),
+            (x() * Term::var("y")).pow_rational(2, 3),
+            Term::floor_div(x(), Term::from(7)),
+            Term::mul_add(x(), Term::var("y"), Term::from(1)),
+            Term::pow_mod_term(x(), Term::from(3), Term::var("m")),
+            (x() & Term::from(6))
+                | (x() ^ Term::from(1))
+                | (x() << Term::from(2))
+                | (x() >> Term::var("y")),
+            Term::sum((0..200).map(|i| Term::var(format!("v{i}")))),
+        ];
+        for term in terms {
+            let bytes = term.serialize_to_bytes();
+            assert_eq!(Term::deserialize_from_bytes(&bytes), Ok(term));
+        }
+
+        // a count spanning two varint bytes
+        let long = Term::var("a".repeat(300)).serialize_to_bytes();
+        assert_eq!(&long[..3], &[0x06, 0xac, 0x02]);
+
+        assert_eq!(
+            Term::deserialize_from_bytes(&[]),
+            Err(SerdeError::UnexpectedEof)
+        );
+        assert_eq!(
+            Term::deserialize_from_bytes(&[0x06, 0x03, b'x']),
+            Err(SerdeError::UnexpectedEof)
+        );
+        assert_eq!(
+            Term::deserialize_from_bytes(&[0x10]),
+            Err(SerdeError::UnknownTag(0x10))
+        );
+        assert_eq!(
+            Term::deserialize_from_bytes(&[0x06, 0x01, 0xff]),
+            Err(SerdeError::InvalidUtf8)
+        );
+        assert_eq!(
+            Term::deserialize_from_bytes(&[
+                0x02, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01
+            ]),
+            Err(SerdeError::VarintOverflow)
+        );
+        assert_eq!(
+            Term::deserialize_from_bytes(&[0x03, 0x00]),
+            Err(SerdeError::MissingOperand)
+        );
+        assert_eq!(
+            Term::deserialize_from_bytes(&[0x04, 0x06, 0x01, b'x', 0x01, 0, 0, 0, 0]),
+            Err(SerdeError::DivisionByZero)
+        );
+        assert_eq!(
+            Term::deserialize_from_bytes(&[0x01, 1, 0, 0, 0, 0]),
+            Err(SerdeError::TrailingBytes)
+        );
+        assert_eq!(
+            Term::deserialize_from_bytes(&[0x0e, 0x01, 1, 0, 0, 0, 0x01, 40, 0, 0, 0]),
+            Err(SerdeError::Overflow)
+        );
+        assert_eq!(
+            Term::deserialize_from_bytes(&[0x02, 0x02, 0x01, 255, 255, 255, 255, 0x01, 1, 0, 0, 0]),
+            Err(SerdeError::Overflow)
+        );
+        // large exponents are neither multiplied out nor allowed to overflow
+        assert_eq!(
+            Term::deserialize_from_bytes(&[0x07, 0, 0, 0, 0x10, 1, 0, 0, 0, 0x06, 0x01, b'x']),
+            Ok(Term::var("x").pow_rational(268435456, 1))
+        );
+        assert_eq!(
+            Term::deserialize_from_bytes(&[0x07, 0, 0, 0, 0x10, 1, 0, 0, 0, 0x01, 2, 0, 0, 0]),
+            Err(SerdeError::Overflow)
+        );
+        let mut nested = vec![0x05; 10000];
+        nested.extend([0x01, 1, 0, 0, 0]);
+        assert_eq!(
+            Term::deserialize_from_bytes(&nested),
+            Err(SerdeError::NestingTooDeep)
+        );
+    }
+
+    #[test]
+    fn test_evaluate_with_precision() {
+        let term = Term::from(2u32) / Term::from(3) + Term::from(1);
+        assert_eq!(term.evaluate_with_precision::<Float64>(), 5.0 / 3.0);
+        assert_eq!(term.evaluate_with_precision::<Float32>(), 5.0f32 / 3.0);
+        assert_eq!(term.evaluate_with_precision::<Exact>(), Term::div(5, 3));
+
+        let root = Term::from(2u32).pow_rational(1, 2);
+        assert_eq!(root.evaluate_with_precision::<Exact>(), root);
+        assert_eq!(
+            root.evaluate_with_precision::<Float64>(),
+            std::f64::consts::SQRT_2
+        );
+    }
+
+    #[cfg(feature = "num-rational")]
+    #[test]
+    fn test_evaluate_with_rational_precision() {
+        use num_rational::Ratio;
+
+        let term = Term::from(2u32) / Term::from(3) + Term::from(1);
+        assert_eq!(term.evaluate_with_precision::<Rational>(), Ratio::new(5, 3));
+        assert_eq!(
+            Term::from(4u32).evaluate_with_precision::<Rational>(),
+            Ratio::from_integer(4)
+        );
+        assert_eq!(
+            (-Term::div(1i32, 2)).evaluate_with_precision::<Rational>(),
+            Ratio::new(-1, 2)
+        );
+    }
+
+    #[cfg(feature = "num-rational")]
+    #[test]
+    #[should_panic]
+    fn test_evaluate_with_rational_precision_of_variable() {
+        Term::<u32>::var("x").evaluate_with_precision::<Rational>();
+    }
+
+    #[cfg(feature = "num-rational")]
+    #[test]
+    #[should_panic(expected = "Cannot represent a negative term as a ratio of unsigned numbers.")]
+    fn test_evaluate_with_rational_precision_of_negative_unsigned() {
+        (-Term::<u32>::div(1, 3)).evaluate_with_precision::<Rational>();
+    }
+
+    #[test]
+    fn test_verify_equality() {
+        let x = || Term::<i32>::var("x");
+        let y = || Term::<i32>::var("y");
+
+        // (x - y)(x + y) = x^2 - y^2
+        let product = (x() - y()) * (x() + y());
+        let difference = x() * x() - y() * y();
+        assert!(Term::verify_equality(&product, &difference, 20));
+        assert!(!Term::verify_equality(
+            &product,
+            &(x() * x() + y() * y()),
+            20
+        ));
+
+        // a variable only one of the terms contains
+        assert!(!Term::verify_equality(&x(), &(x() + y() - y() + y()), 20));
+        assert!(Term::verify_equality(
+            &(x() / y() + Term::from(1)),
+            &((x() + y()) / y()),
+            20
+        ));
+
+        // powers are compared numerically
+        let root = x().pow_rational(1, 2);
+        assert!(Term::verify_equality(&(root.clone() * root), &x(), 20));
+
+        // without test points, nothing can disagree
+        assert!(Term::verify_equality(&x(), &y(), 0));
+        assert!(Term::verify_equality(&Term::from(3), &Term::from(3), 1));
+        assert!(!Term::verify_equality(&Term::from(3), &Term::from(4), 1));
+    }
+
+    #[test]
+    fn test_sum_and_product_of_vars() {
+        let names = ["a", "b", "c", "d"];
+
+        let product = Term::<u32>::product_of_vars(&names);
+        let (variant, children) = product.clone().into_parts();
+        assert_eq!(variant, OperationVariant::Multiplication);
+        assert_eq!(children.len(), 4);
+        assert_eq!(
+            product.with_vars(&[
+                ("a", &Term::from(2)),
+                ("b", &Term::from(3)),
+                ("c", &Term::from(4)),
+                ("d", &Term::from(5)),
+            ]),
+            Term::from(120)
+        );
+
+        let sum = Term::<u32>::sum_of_vars(&names);
+        let (variant, children) = sum.clone().into_parts();
+        assert_eq!(variant, OperationVariant::Addition);
+        assert_eq!(children, names.map(Term::var).to_vec());
+
+        assert_eq!(Term::<u32>::product_of_vars(&["x"]), Term::var("x"));
+        assert_eq!(Term::<u32>::sum_of_vars(&["x"]), Term::var("x"));
+    }
+
+    #[test]
+    fn test_eval_at_origin() {
+        let x = || Term::<i32>::var("x");
+        let y = || Term::<i32>::var("y");
+
+        assert_eq!(Term::from(7).eval_at_origin(), Some(7));
+        assert_eq!(x().eval_at_origin(), Some(0));
+        assert_eq!((x() * y() + Term::from(3)).eval_at_origin(), Some(3));
+        assert_eq!((x() - Term::from(3)).eval_at_origin(), Some(-3));
+        assert_eq!((Term::from(2) - y() * x()).eval_at_origin(), Some(2));
+        assert_eq!(
+            (Term::<u32>::var("x") - Term::from(3)).eval_at_origin(),
+            None
+        );
+        assert_eq!(
+            ((x() + Term::from(4)) / (y() + Term::from(2))).eval_at_origin(),
+            Some(2)
+        );
+
+        // zero divisors
+        assert_eq!((Term::from(1) / x()).eval_at_origin(), None);
+        assert_eq!(
+            (Term::from(1) / (x() * y() + Term::from(1)) / (y() - x())).eval_at_origin(),
+            None
+        );
+        assert_eq!(Term::floor_div(Term::from(3), x()).eval_at_origin(), None);
+        assert_eq!(
+            Term::pow_mod_term(Term::from(3), x(), y()).eval_at_origin(),
+            None
+        );
+        assert_eq!(
+            Term::pow_mod_term(Term::from(3), x(), y() + Term::from(2)).eval_at_origin(),
+            Some(1)
+        );
+
+        // fractions are not a single number
+        assert_eq!(
+            (Term::from(1) / (x() + Term::from(2))).eval_at_origin(),
+            None
+        );
+        assert_eq!(
+            (Term::var("x") * Term::from(2.0) + Term::from(0.25)).eval_at_origin(),
+            Some(0.25)
+        );
+    }
+
+    #[test]
+    fn test_from_pairs_add() {
+        assert_eq!(
+            Term::<u32>::from_pairs_add(Vec::<(u32, &str)>::new()),
+            Term::from(0)
+        );
+        assert_eq!(
+            Term::from_pairs_add([(5u32, "x")]),
+            Term::from(5) * Term::var("x")
+        );
+        assert_eq!(Term::from_pairs_add([(0u32, "x")]), Term::from(0));
+
+        let term = Term::from_pairs_add([
+            (3i32, String::from("x")),
+            (5, String::from("y")),
+            (-2, String::from("z")),
+        ]);
+        let (variant, children) = term.clone().into_parts();
+        assert_eq!(variant, OperationVariant::Addition);
+        assert_eq!(children.len(), 3);
+        assert_eq!(
+            term.with_vars(&[
+                ("x", &Term::from(1)),
+                ("y", &Term::from(2)),
+                ("z", &Term::from(3))
+            ]),
+            Term::from(7)
+        );
+
+        let many = Term::from_pairs_add((1..=1000u32).map(|i| (i, format!("x{i}"))));
+        assert_eq!(many.into_parts().1.len(), 1000);
+    }
+
+    #[test]
+    fn test_validate_syntax() {
+        for valid in [
+            "1",
+            "2 + 3 * (4 - 1) / 7",
+            "6 & 3 | 1 << 3",
+            "~0",
+            "1.5 * 2",
+        ] {
+            assert_eq!(Term::validate_syntax(valid), vec![], "{valid}");
+        }
+        assert_eq!(
+            Term::validate_syntax(""),
+            vec![TryFromStrError::UnexpectedEof]
+        );
+        assert_eq!(
+            Term::validate_syntax("1 + 99999999999"),
+            vec![TryFromStrError::IntegerOverflow {
+                value: String::from("99999999999")
+            }]
+        );
+        assert_eq!(
+            Term::validate_syntax("2 + 1 / 0"),
+            vec![TryFromStrError::DivisionByZero]
+        );
+
+        // the errors are the same as when parsing
+        let formular = "((2 + # / (3 - 3)";
+        assert_eq!(
+            Term::validate_syntax(formular),
+            Term::parse_collecting_errors(formular).1
+        );
+    }
+
+    #[test]
+    fn test_approximate_as_simple_fraction() {
+        let pi = Term::from(std::f64::consts::PI);
+        assert_eq!(pi.approximate_as_simple_fraction(1), Term::from(3u32));
+        assert_eq!(pi.approximate_as_simple_fraction(10), Term::div(22, 7));
+        assert_eq!(pi.approximate_as_simple_fraction(100), Term::div(311, 99));
+        assert_eq!(pi.approximate_as_simple_fraction(1000), Term::div(355, 113));
+
+        // exact fractions are found again
+        assert_eq!(
+            Term::div(415u32, 93).approximate_as_simple_fraction(100),
+            Term::div(415, 93)
+        );
+        assert_eq!(
+            Term::from(0.75).approximate_as_simple_fraction(u32::MAX),
+            Term::div(3, 4)
+        );
+        assert_eq!(
+            Term::from(7u32).approximate_as_simple_fraction(5),
+            Term::from(7)
+        );
+        assert_eq!(
+            Term::from(0u32).approximate_as_simple_fraction(5),
+            Term::from(0)
+        );
+        assert_eq!(
+            (-Term::from(0.2)).approximate_as_simple_fraction(10),
+            -Term::div(1, 5)
+        );
+
+        // measurements close to simple fractions
+        assert_eq!(
+            Term::from(0.33329).approximate_as_simple_fraction(100),
+            Term::div(1, 3)
+        );
+        assert_eq!(
+            Term::from(u32::MAX).approximate_as_simple_fraction(3),
+            Term::from(u32::MAX)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_approximate_as_simple_fraction_of_infinity() {
+        (Term::from(f64::MAX) * Term::from(2.0)).approximate_as_simple_fraction(10);
+    }
+
+    #[test]
+    fn test_reference_operators() {
+        let x = Term::<u32>::var("x");
+        let two = Term::<u32>::from(2);
+
+        assert_eq!(&x + &two, x.clone() + two.clone());
+        assert_eq!(&x - &two, x.clone() - two.clone());
+        assert_eq!(&x * &two, x.clone() * two.clone());
+        assert_eq!(&x / &two, x.clone() / two.clone());
+        assert_eq!(-&x, -x.clone());
+
+        let terms = [Term::from(2), Term::var("y"), Term::from(3)];
+        let mut product = Term::var("z");
+        for term in &terms {
+            product = &product * term;
+        }
+        assert_eq!(
+            product,
+            Term::var("z") * Term::from(2) * Term::var("y") * Term::from(3)
+        );
+
+        // the operands are still available
+        assert_eq!(x, Term::var("x"));
+        assert_eq!(two, Term::from(2));
+    }
+
+    #[test]
+    fn test_try_from_float() {
+        assert_eq!(Term::try_from_float(0.0, 10), Some(Term::from(0)));
+        assert_eq!(Term::try_from_float(42.0, 1), Some(Term::from(42)));
+        assert_eq!(Term::try_from_float(2.5, 1), Some(Term::from(2)));
+        assert_eq!(Term::try_from_float(0.1, u64::MAX), Some(Term::div(1, 10)));
+        assert_eq!(
+            Term::try_from_float(std::f64::consts::E, 1000),
+            Some(Term::div(1457, 536))
+        );
+        assert_eq!(Term::try_from_float(0.5, 0), Some(Term::from(0)));
+
+        // the counterpart to from_decimal
+        assert_eq!(
+            Term::try_from_float(2.75, 1000),
+            Some(Term::from_decimal(2, 75, 2))
+        );
+
+        assert_eq!(Term::try_from_float(f64::INFINITY, 10), None);
+        assert_eq!(Term::try_from_float(f64::NEG_INFINITY, 10), None);
+        assert_eq!(Term::try_from_float(f64::NAN, 10), None);
+        assert_eq!(Term::try_from_float(1e12, 10), None);
+    }
+
+    #[test]
+    fn test_tree_size_reduction_ratio() {
+        let x = || Term::<u32>::var("x");
+
+        assert_eq!(x().node_count(), 1);
+        assert_eq!((x() + Term::from(1)).node_count(), 3);
+        assert_eq!(x().pow_rational(1, 2).node_count(), 2);
+
+        let term = x() * x() + Term::from(1);
+        assert_eq!(Term::tree_size_reduction_ratio(&term, &term), 0.0);
+        assert_eq!(Term::tree_size_reduction_ratio(&term, &x()), 0.8);
+        assert_eq!(Term::tree_size_reduction_ratio(&x(), &term), -4.0);
+
+        Term::print_simplification_stats(&term, &x());
+    }
+
+    #[test]
+    fn test_collect_common_denominator() {
+        let x = || Term::<i32>::var("x");
+        let unsimplified = |build: &dyn Fn() -> Term<i32>| {
+            let _guard = Term::<i32>::with_config(Term::<i32>::simplification_depth_limit(0));
+            build()
+        };
+
+        // numeric denominators use their least common multiple
+        let term = unsimplified(&|| Term::div(1, 4) + Term::div(1, 6) + Term::div(1, 12));
+        assert_eq!(term.collect_common_denominator(), Term::div(1, 2));
+
+        let term = unsimplified(&|| Term::div(1, 2) - Term::div(1, 3));
+        assert_eq!(term.collect_common_denominator(), Term::div(1, 6));
+
+        // whole summands and symbolic denominators
+        let term = unsimplified(&|| x() + Term::from(1) / x());
+        let combined = term.collect_common_denominator();
+        assert_eq!(combined.to_prefix_notation(), "(/ (+ (* x x) 1) x)");
+        assert!(Term::verify_equality(&term, &combined, 20));
+
+        let term = unsimplified(&|| {
+            Term::from(3) / (x() + Term::from(1)) + Term::from(1) / Term::from(2) + x()
+        });
+        let combined = term.collect_common_denominator();
+        let (variant, _) = combined.clone().into_parts();
+        assert_eq!(variant, OperationVariant::Division);
+        assert!(Term::verify_equality(&term, &combined, 20));
+
+        // sums without fractions stay the same
+        let term = x() * x() + Term::from(2);
+        assert_eq!(term.collect_common_denominator(), term);
+    }
+
+    #[cfg(feature = "test-utils")]
+    #[test]
+    fn test_assert_calc() {
+        use crem::test_utils::{assert_eq_calc, assert_ne_calc};
+
+        let x = || Term::<i32>::var("x");
+        let expanded = (x() + Term::from(1)) * (x() - Term::from(1));
+        let squared = x() * x() - Term::from(1);
+        for value in -3..=3 {
+            assert_eq_calc::<i32, i64>(
+                &expanded.with_var("x", &Term::from(value)),
+                &squared.with_var("x", &Term::from(value)),
+            );
+        }
+        assert_ne_calc::<i32, f64>(&Term::div(1, 3), &Term::div(333, 1000));
+
+        let result = std::panic::catch_unwind(|| {
+            assert_eq_calc::<i32, f64>(&Term::from(1), &Term::from(2));
+        });
+        assert!(result.is_err());
+        let result = std::panic::catch_unwind(|| {
+            assert_ne_calc::<i32, f64>(&Term::div(2, 4), &Term::div(1, 2));
+        });
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_exponent_collect() {
+        let x = || Term::<u32>::var("x");
+        let y = || Term::<u32>::var("y");
+
+        // nothing to collect
+        assert_eq!(x().exponent_collect(), x());
+        assert_eq!((x() * y()).exponent_collect(), x() * y());
+
+        let cubed = (x() * x() * x()).exponent_collect();
+        assert_eq!(cubed.to_prefix_notation(), "(^ x 3 1)");
+        assert_eq!(cubed.with_var("x", &Term::from(2)).calc_float::<f64>(), 8.0);
+
+        // nested multiplications and equal compound multipliers
+        let sum = || x() + Term::from(1);
+        let term = sum() * y() * sum() + x() * x();
+        let collected = term.exponent_collect();
+        assert_eq!(collected.to_string(), "((x + 1)^2 * y + x^2)");
+        assert!(Term::verify_equality(&term, &collected, 20));
+    }
+
+    #[test]
+    fn test_partial_derivative_gradient() {
+        let x = || Term::<u32>::var("x");
+        let y = || Term::<u32>::var("y");
+
+        let term = x() * x() * y() + Term::from(3) * y();
+        let gradient = term.partial_derivative_gradient(&["x", "y", "z"]);
+        assert_eq!(gradient.len(), 3);
+        assert_eq!(gradient[0], term.diff("x"));
+        assert_eq!(gradient[1], term.diff("y"));
+        assert_eq!(gradient[2], Term::from(0));
+
+        assert!(term.partial_derivative_gradient(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_laplacian() {
+        let x = || Term::<u32>::var("x");
+        let y = || Term::<u32>::var("y");
+        let z = || Term::<u32>::var("z");
+
+        assert_eq!(
+            (x() * x() + y() * y()).laplacian(&["x", "y"]),
+            Term::from(4)
+        );
+        assert_eq!(
+            (x() * x() + y() * y() + z() * z()).laplacian(&["x", "y", "z"]),
+            Term::from(6)
+        );
+
+        // only the given variables are taken into account
+        assert_eq!((x() * x() + y() * y()).laplacian(&["x"]), Term::from(2));
+        assert_eq!((x() * x() * y()).laplacian(&[]), Term::from(0));
+
+        // harmonic functions have a laplacian of zero
+        let term = x() * y() + x() + Term::from(3);
+        assert_eq!(term.laplacian(&["x", "y"]), Term::from(0));
+
+        let term = x() * x() * x() * y();
+        assert_eq!(
+            term.laplacian(&["x", "y"])
+                .substitute_and_simplify(&[("x", Term::from(2)), ("y", Term::from(5))]),
+            Term::from(60)
+        );
+    }
+
+    #[test]
+    fn test_jacobian() {
+        let x = || Term::<u32>::var("x");
+        let y = || Term::<u32>::var("y");
+
+        let outputs = [x() * x() * y(), Term::from(5) * x() + y(), Term::from(7)];
+        let jacobian = Term::jacobian(&["x", "y"], &outputs);
+
+        assert_eq!(jacobian.len(), 3);
+        for (row, output) in jacobian.iter().zip(&outputs) {
+            assert_eq!(row.len(), 2);
+            assert_eq!(row[0], output.diff("x"));
+            assert_eq!(row[1], output.diff("y"));
+        }
+        assert_eq!(jacobian[1], vec![Term::from(5), Term::from(1)]);
+        assert_eq!(jacobian[2], vec![Term::from(0), Term::from(0)]);
+
+        // evaluating the jacobian at a point
+        let at = [("x", Term::from(3)), ("y", Term::from(2))];
+        assert_eq!(jacobian[0][0].substitute_and_simplify(&at), Term::from(12));
+        assert_eq!(jacobian[0][1].substitute_and_simplify(&at), Term::from(9));
+
+        assert!(Term::<u32>::jacobian(&["x"], &[]).is_empty());
+        assert_eq!(Term::jacobian(&[], &[x()]), vec![Vec::new()]);
+    }
+
+    #[test]
+    fn test_parse_fraction_string() {
+        assert_eq!(Term::parse_fraction_string("3/4"), Ok(Term::div(3, 4)));
+        assert_eq!(Term::parse_fraction_string("0/5"), Ok(Term::from(0)));
+        assert_eq!(Term::parse_fraction_string("8/2"), Ok(Term::from(4)));
+        assert_eq!(
+            Term::parse_fraction_string("4294967295/1"),
+            Ok(Term::from(u32::MAX))
+        );
+
+        // anything but the strict format is rejected
+        assert_eq!(
+            Term::parse_fraction_string("22"),
+            Err(TryFromStrError::UnexpectedEof)
+        );
+        assert_eq!(
+            Term::parse_fraction_string("22/"),
+            Err(TryFromStrError::UnexpectedEof)
+        );
+        assert_eq!(
+            Term::parse_fraction_string(""),
+            Err(TryFromStrError::UnexpectedEof)
+        );
+        assert_eq!(
+            Term::parse_fraction_string("/7"),
+            Err(TryFromStrError::UnexpectedCharacter('/'))
+        );
+        assert_eq!(
+            Term::parse_fraction_string("1/2/3"),
+            Err(TryFromStrError::UnexpectedCharacter('/'))
+        );
+        assert_eq!(
+            Term::parse_fraction_string("-1/2"),
+            Err(TryFromStrError::UnexpectedCharacter('-'))
+        );
+        assert_eq!(
+            Term::parse_fraction_string("1+1/2"),
+            Err(TryFromStrError::UnexpectedCharacter('+'))
+        );
+        assert_eq!(
+            Term::parse_fraction_string("x/2"),
+            Err(TryFromStrError::UnexpectedCharacter('x'))
+        );
+        assert_eq!(
+            Term::parse_fraction_string("3/4 "),
+            Err(TryFromStrError::UnexpectedCharacter(' '))
+        );
+        assert_eq!(
+            Term::parse_fraction_string("1/4294967296"),
+            Err(TryFromStrError::IntegerOverflow {
+                value: "4294967296".to_string()
+            })
+        );
+        assert_eq!(
+            Term::parse_fraction_string("5/0"),
+            Err(TryFromStrError::DivisionByZero)
+        );
+    }
+
+    #[test]
+    fn test_reduce_multiplication_by_division() {
+        let a = || Term::<u32>::var("a");
+        let b = || Term::<u32>::var("b");
+        let c = || Term::<u32>::var("c");
+
+        let guard = Term::<u32>::with_config(Term::<u32>::simplification_depth_limit(0));
+        let chain = (a() / b()) * (b() / c());
+        let inverse = (a() / b()) * (b() / a());
+        let numbers = (Term::from(4) * a() / (Term::from(3) * b()))
+            * (Term::from(9) * b() / (Term::from(2) * c()));
+        let nested = (a() / b()) * ((b() / c()) * (c() / a()));
+        drop(guard);
+
+        assert_eq!(chain.reduce_multiplication_by_division(), a() / c());
+        assert_eq!(inverse.reduce_multiplication_by_division(), Term::from(1));
+        assert_eq!(
+            numbers
+                .reduce_multiplication_by_division()
+                .to_prefix_notation(),
+            "(/ (* 6 a) c)"
+        );
+        assert_eq!(nested.reduce_multiplication_by_division(), Term::from(1));
+
+        // multiplications without fractions are left unchanged
+        assert_eq!((a() * b()).reduce_multiplication_by_division(), a() * b());
+        assert_eq!(
+            (a() + b() * c()).reduce_multiplication_by_division(),
+            a() + b() * c()
+        );
+
+        // the value is preserved
+        let values = [
+            ("a", &Term::from(6)),
+            ("b", &Term::from(3)),
+            ("c", &Term::from(2)),
+        ];
+        assert_eq!(
+            numbers
+                .reduce_multiplication_by_division()
+                .with_vars(&values)
+                .calc::<f64>(),
+            numbers.with_vars(&values).calc::<f64>()
+        );
+    }
+
+    #[test]
+    fn test_into_numeric_vec() {
+        let terms = || {
+            vec![
+                Term::from(4),
+                Term::div(3, 2),
+                -Term::from(7),
+                Term::from(2) * Term::from(5) + Term::from(1),
+            ]
+        };
+        assert_eq!(Term::into_f64_vec(terms()), vec![4.0, 1.5, -7.0, 11.0]);
+        assert_eq!(Term::into_i64_vec(terms()), vec![4, 1, -7, 11]);
+        assert_eq!(
+            Term::try_into_f64_vec(terms()),
+            Ok(vec![4.0, 1.5, -7.0, 11.0])
+        );
+
+        assert!(Term::into_f64_vec(Vec::new()).is_empty());
+        assert_eq!(Term::try_into_f64_vec(Vec::new()), Ok(Vec::new()));
+
+        // the error names the variables of the first term containing any
+        let terms = vec![
+            Term::from(1),
+            Term::var("y") + Term::var("x"),
+            Term::var("z"),
+        ];
+        assert_eq!(
+            Term::try_into_f64_vec(terms),
+            Err(UnboundVariableError {
+                variables: vec![String::from("y"), String::from("x")]
+            })
+        );
+
+        assert_eq!(Term::from(3u32).try_calc::<i64>(), Ok(3));
+        assert!(Term::<u32>::var("x").try_calc::<f64>().is_err());
+    }
+
+    #[test]
+    fn test_number_and_variable_count() {
+        let x = || Term::<u32>::var("x");
+        let y = || Term::<u32>::var("y");
+
+        assert_eq!(Term::from(5u32).number_count(), 1);
+        assert_eq!(Term::from(5u32).variable_count(), 0);
+        assert_eq!(x().number_count(), 0);
+        assert_eq!(x().variable_count(), 1);
+
+        // (3 * x * y * 2 + 1) / 2 - x
+        let term = Term::from(3) * x() * y() + Term::div(1, 2) - x();
+        assert_eq!(term.variable_count(), 3);
+        assert_eq!(term.number_count(), 4);
+
+        // every variable is counted, not every distinct one
+        assert_eq!((x() * x() * x()).variable_count(), 3);
+        assert!(term.number_count() + term.variable_count() < term.node_count());
+
+        let counts = term.count_operations();
+        assert_eq!(counts.numbers, term.number_count());
+        assert_eq!(counts.variables, term.variable_count());
+        assert_eq!(
+            counts.numbers + counts.variables + counts.operations,
+            term.node_count()
+        );
+    }
+
+    #[test]
+    fn test_from_expression_tree() {
+        let num = |value| Box::new(ExprNode::Num(value));
+        let var = |name: &str| Box::new(ExprNode::Var(name.to_string()));
+
+        assert_eq!(
+            Term::from_expression_tree(ExprNode::Num(4)),
+            Term::from(4u32)
+        );
+        assert_eq!(
+            Term::from_expression_tree(ExprNode::Var(String::from("x"))),
+            Term::var("x")
+        );
+
+        // 1 / 3 + 2 / 3 is simplified
+        let node = ExprNode::Add(
+            Box::new(ExprNode::Div(num(1), num(3))),
+            Box::new(ExprNode::Div(num(2), num(3))),
+        );
+        assert_eq!(Term::from_expression_tree(node), Term::from(1));
+
+        // -(x - 5) * y
+        let node = ExprNode::Mul(
+            Box::new(ExprNode::Neg(Box::new(ExprNode::Sub(var("x"), num(5))))),
+            var("y"),
+        );
+        let term = Term::from_expression_tree(node);
+        assert_eq!(term, -(Term::var("x") - Term::from(5)) * Term::var("y"));
+        assert_eq!(
+            term.with_vars(&[("x", &Term::from(7)), ("y", &Term::from(3))])
+                .calc::<i64>(),
+            -6
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_expression_tree_division_by_zero() {
+        Term::from_expression_tree(ExprNode::Div(
+            Box::new(ExprNode::Num(1)),
+            Box::new(ExprNode::Num(0)),
+        ));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_try_calc_irrational_power() {
+        let _ = Term::from(2u32).pow_rational(1, 2).try_calc::<f64>();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_try_calc_shift_overflow() {
+        let _ = (Term::from(1u32) << Term::from(40)).try_calc::<i64>();
+    }
+
+    #[test]
+    fn test_into_expression_tree() {
+        let x = || Term::<u32>::var("x");
+        let y = || Term::<u32>::var("y");
+
+        assert_eq!(
+            Term::from(7u32).into_expression_tree(),
+            Some(ExprNode::Num(7))
+        );
+        assert_eq!(
+            (-x()).into_expression_tree(),
+            Some(ExprNode::Neg(Box::new(ExprNode::Var(String::from("x")))))
+        );
+
+        let terms = [
+            Term::from(0),
+            x(),
+            x() + y() + Term::from(3),
+            Term::from(2) * x() * y(),
+            (x() + Term::from(1)) / (y() * Term::from(3)),
+            Term::div(5, 7) - x(),
+            -(x() * y()) + Term::from(4) / x(),
+            (x() - y()) * (x() + y()),
+        ];
+        for term in terms {
+            let node = term.clone().into_expression_tree().unwrap();
+            assert_eq!(Term::from_expression_tree(node.clone()), term);
+            assert_eq!(term.clone().into_expression_tree(), Some(node));
+        }
+
+        // unsimplified trees are simplified on the way
+        let product = ExprNode::Mul(
+            Box::new(ExprNode::Var(String::from("a"))),
+            Box::new(ExprNode::Num(1)),
+        );
+        let node = ExprNode::Add(Box::new(product.clone()), Box::new(ExprNode::Num(0)));
+        assert_eq!(
+            Term::from_expression_tree(node).into_expression_tree(),
+            Some(product)
+        );
+
+        assert_eq!(x().pow_rational(1, 2).into_expression_tree(), None);
+        assert_eq!(Term::floor_div(x(), y()).into_expression_tree(), None);
+        assert_eq!((Term::from(1) + (x() & y())).into_expression_tree(), None);
+    }
+
+    #[test]
+    fn test_to_rust_expr() {
+        let x = || Term::<u32>::var("x");
+        let y = || Term::<u32>::var("y");
+
+        assert_eq!(Term::from(5u32).to_rust_expr("u64"), "5_u64");
+        assert_eq!(x().to_rust_expr("f64"), "x");
+        assert_eq!(Term::from(-4i64).to_rust_expr("i64"), "(-4_i64)");
+
+        let term = x() * Term::from(2) + Term::div(1, 2);
+        let expr = term.to_rust_expr("f64");
+        assert_eq!(expr, "(((x * 2_f64 * 2_f64) + 1_f64) / 2_f64)");
+
+        // the generated expressions compute the same values
+        let x = 3.0_f64;
+        assert_eq!(
+            (((x * 2_f64 * 2_f64) + 1_f64) / 2_f64),
+            term.with_var("x", &Term::from(3)).calc::<f64>()
+        );
+
+        let term = Term::<u32>::var("x").pow_rational(1, 2) * y();
+        assert_eq!(term.to_rust_expr("f32"), "(x.powf(1_f32 / 2_f32) * y)");
+
+        let term = Term::<u32>::var("a") & Term::from(6);
+        assert_eq!(term.to_rust_expr("u32"), "(a & 6_u32)");
+    }
+
+    #[test]
+    fn test_diff_total() {
+        let x = || Term::<u32>::var("x");
+        let y = || Term::<u32>::var("y");
+
+        // d(x^2 + 3y) = 2x dx + 3 dy
+        let term = x() * x() + Term::from(3) * y();
+        let total = term.diff_total(&["x", "y"], &["dx", "dy"]);
+        assert_eq!(
+            total.substitute_and_simplify(&[
+                ("x", Term::from(5)),
+                ("dx", Term::from(2)),
+                ("dy", Term::from(4)),
+            ]),
+            Term::from(32)
+        );
+
+        // each summand is a partial derivative times its differential
+        let only_x = term.diff_total(&["x"], &["dx"]);
+        assert_eq!(only_x, term.diff("x") * Term::var("dx"));
+
+        // variables the term does not depend on vanish
+        assert_eq!(term.diff_total(&["z"], &["dz"]), Term::from(0));
+        assert_eq!(term.diff_total(&[], &[]), Term::from(0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_diff_total_mismatched_lengths() {
+        Term::<u32>::var("x").diff_total(&["x", "y"], &["dx"]);
+    }
+
+    #[test]
+    fn test_scale_all_numbers() {
+        let x = || Term::<u32>::var("x");
+        let y = || Term::<u32>::var("y");
+
+        assert_eq!(Term::from(7u32).scale_all_numbers(3), Term::from(21));
+        assert_eq!((x() * y()).scale_all_numbers(5), x() * y());
+        assert_eq!(
+            (Term::from(4) - x()).scale_all_numbers(2),
+            Term::from(8) - x()
+        );
+
+        // both parts of a fraction are scaled
+        assert_eq!(Term::div(3u32, 4).scale_all_numbers(2), Term::div(3, 4));
+        assert_eq!(
+            (Term::from(2) * x() / Term::from(3)).scale_all_numbers(6),
+            Term::from(2) * x() / Term::from(3)
+        );
+
+        // numbers within products of sums are scaled where they are
+        let a = || Term::<u32>::var("a");
+        let b = || Term::<u32>::var("b");
+        assert_eq!(
+            (a() * (b() + Term::from(2))).scale_all_numbers(3),
+            a() * (b() + Term::from(6))
+        );
+
+        // the result is the same as scaling each number by hand, then simplifying
+        let term = Term::from(3) * x() + Term::from(2) * x() * y() + Term::from(1);
+        let mut by_hand = term.clone();
+        by_hand.walk_mut(|operation| {
+            if let Operation::Number(num) = operation {
+                num.value *= 2;
+            }
+        });
+        assert_eq!(term.scale_all_numbers(2), by_hand.constant_propagation());
+
+        // numbers within powers are scaled as well
+        let power = (x() + Term::from(1)).pow_rational(1, 2);
+        assert_eq!(
+            power.scale_all_numbers(2),
+            (x() + Term::from(2)).pow_rational(1, 2)
+        );
+    }
+
+    #[test]
+    fn test_to_smtlib2() {
+        let x = || Term::<u32>::var("x");
+        let y = || Term::<u32>::var("y");
+
+        assert_eq!(Term::from(7u32).to_smtlib2("Int"), "7");
+        assert_eq!(Term::from(-3i64).to_smtlib2("Int"), "(- 3)");
+        assert_eq!(x().to_smtlib2("Int"), "(declare-fun x () Int)\nx");
+
+        // variables are declared once, in order of their first appearance
+        let term = y() * x() + x();
+        let smtlib2 = term.to_smtlib2("Real");
+        let lines: Vec<&str> = smtlib2.lines().collect();
+        assert_eq!(
+            lines[..2],
+            ["(declare-fun y () Real)", "(declare-fun x () Real)"]
+        );
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[2], "(+ (* y x) x)");
+
+        let term = Term::div(1, 2) + x();
+        assert_eq!(
+            term.to_smtlib2("Real").lines().last(),
+            Some(term.to_prefix_notation().as_str())
+        );
+
+        // names which are not simple symbols are quoted
+        assert_eq!(
+            Term::<u32>::var("x_1.b?").to_smtlib2("Int").lines().last(),
+            Some("x_1.b?")
+        );
+        assert_eq!(
+            Term::<u32>::var("1x").to_smtlib2("Int").lines().last(),
+            Some("|1x|")
+        );
+        assert_eq!(
+            Term::<u32>::var("let").to_smtlib2("Int").lines().last(),
+            Some("|let|")
+        );
+        assert_eq!(
+            (Term::<u32>::var("a b") * x()).to_smtlib2("Int"),
+            "(declare-fun |a b| () Int)\n(declare-fun x () Int)\n(* |a b| x)"
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_to_smtlib2_int_division() {
+        (Term::<u32>::var("x") / Term::from(2)).to_smtlib2("Int");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_to_smtlib2_unquotable_name() {
+        Term::<u32>::var("a|b").to_smtlib2("Real");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_to_smtlib2_power() {
+        Term::<u32>::var("x").pow_rational(1, 2).to_smtlib2("Real");
+    }
+
+    #[test]
+    fn test_checked_operations() {
+        assert_eq!(Term::div(1u8, 200).checked_add(Term::div(1, 3)), None);
+        assert_eq!(
+            Term::div(1u8, 2).checked_add(Term::div(1, 3)),
+            Some(Term::div(5, 6))
+        );
+        assert_eq!(
+            Term::var("x").checked_mul(Term::from(255u8)),
+            Some(Term::var("x") * Term::from(255))
+        );
+    }
+
+    #[test]
+    fn test_convert() {
+        assert_eq!(Term::from(3i64), Term::from(3u32).convert());
+        assert_ne!(Term::from(2i64), Term::from(3u32).convert());
+    }
+
+    #[test]
+    fn test_try_into_number() {
+        assert_eq!(u32::try_from(Term::from(7u32) * Term::from(6)), Ok(42));
+        assert_eq!(
+            f64::try_from(Term::from(1.0) / Term::var("x")),
+            Err(NotAConstantError {
+                had_variables: vec![String::from("x")]
+            })
+        );
+        assert_eq!(
+            i32::try_from(Term::div(1, 3)),
+            Err(NotAConstantError {
+                had_variables: Vec::new()
+            })
+        );
     }
 
     #[test]